@@ -7,14 +7,17 @@ use solana_sdk::{
     transaction::Transaction,
 };
 use spl_associated_token_account::{
-    get_associated_token_address, instruction::create_associated_token_account_idempotent,
+    get_associated_token_address, get_associated_token_address_with_program_id,
+    instruction::create_associated_token_account_idempotent,
 };
 use spl_token_2022::extension::{ExtensionType, StateWithExtensionsOwned};
 
-use crate::resolver::{DELETE_SLASH_PROPOSAL_DURATION, VETO_DURATION};
+use crate::resolver::{
+    DELETE_SLASH_PROPOSAL_DURATION, REQUIRE_RESOLVER_ASSIGNMENT, VETO_DURATION, VETO_THRESHOLD,
+};
 
 use super::{
-    resolver_client::{ResolverProgramClient, SlasherRoot},
+    resolver_client::{ResolverProgramClient, ResolverRoot, SlasherRoot},
     restaking_client::{NcnRoot, OperatorRoot, RestakingProgramClient},
     vault_client::{VaultProgramClient, VaultRoot},
     TestResult,
@@ -282,6 +285,45 @@ impl TestBuilder {
             .await
     }
 
+    /// Mints Token-2022 tokens to an ATA owned by the `to` address
+    pub async fn mint_token_2022_to(
+        &mut self,
+        mint: &Pubkey,
+        to: &Pubkey,
+        amount: u64,
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.context.banks_client.get_latest_blockhash().await?;
+        let mint_to_ix = vec![
+            create_associated_token_account_idempotent(
+                &self.context.payer.pubkey(),
+                to,
+                mint,
+                &spl_token_2022::id(),
+            ),
+            spl_token_2022::instruction::mint_to(
+                &spl_token_2022::id(),
+                mint,
+                &get_associated_token_address_with_program_id(to, mint, &spl_token_2022::id()),
+                &self.context.payer.pubkey(),
+                &[],
+                amount,
+            )
+            .map_err(|_e| BanksClientError::ClientError("failed to mint to"))?,
+        ];
+        self.context
+            .banks_client
+            .process_transaction_with_preflight_and_commitment(
+                Transaction::new_signed_with_payer(
+                    &mint_to_ix,
+                    Some(&self.context.payer.pubkey()),
+                    &[&self.context.payer],
+                    blockhash,
+                ),
+                CommitmentLevel::Processed,
+            )
+            .await
+    }
+
     pub async fn get_token_account(
         &mut self,
         token_account: &Pubkey,
@@ -300,6 +342,10 @@ impl TestBuilder {
         Ok(account_info.base)
     }
 
+    pub async fn get_balance(&mut self, pubkey: &Pubkey) -> Result<u64, BanksClientError> {
+        self.context.banks_client.get_balance(*pubkey).await
+    }
+
     pub async fn warp_slot_incremental(
         &mut self,
         incremental_slots: u64,
@@ -316,6 +362,33 @@ impl TestBuilder {
         Ok(clock.slot)
     }
 
+    /// Initializes a resolver for the NCN and waits for its NcnResolverTicket to activate
+    pub async fn setup_active_resolver(&mut self, ncn_root: &NcnRoot) -> TestResult<ResolverRoot> {
+        let mut resolver_program_client = self.resolver_program_client();
+
+        let resolver_root = resolver_program_client
+            .do_initialize_resolver(ncn_root)
+            .await?;
+        resolver_program_client
+            .do_initialize_ncn_resolver_ticket(ncn_root, &resolver_root.resolver_pubkey)
+            .await?;
+        self.warp_slot_incremental(1).await.unwrap();
+        resolver_program_client
+            .do_warmup_ncn_resolver_ticket(ncn_root, &resolver_root.resolver_pubkey)
+            .await?;
+
+        let config: resolver_core::config::Config = resolver_program_client
+            .get_account(
+                &resolver_core::config::Config::find_program_address(&resolver_program::id()).0,
+            )
+            .await?;
+        self.warp_slot_incremental(config.epoch_length())
+            .await
+            .unwrap();
+
+        Ok(resolver_root)
+    }
+
     /// Configures a vault with an NCN and operators fully configured
     pub async fn setup_vault_with_ncn_and_operators(
         &mut self,
@@ -324,6 +397,31 @@ impl TestBuilder {
         reward_fee_bps: u16,
         num_operators: u16,
         slasher_amounts: &[u64],
+    ) -> TestResult<ConfiguredVault> {
+        self.setup_vault_with_ncn_and_operators_and_resolver_policy(
+            deposit_fee_bps,
+            withdrawal_fee_bps,
+            reward_fee_bps,
+            num_operators,
+            slasher_amounts,
+            VETO_THRESHOLD,
+            REQUIRE_RESOLVER_ASSIGNMENT,
+        )
+        .await
+    }
+
+    /// Configures a vault with an NCN and operators fully configured, requiring
+    /// `veto_threshold` resolver vetoes to veto a slash proposal and, if
+    /// `require_resolver_assignment` is set, an assigned resolver to execute it
+    pub async fn setup_vault_with_ncn_and_operators_and_resolver_policy(
+        &mut self,
+        deposit_fee_bps: u16,
+        withdrawal_fee_bps: u16,
+        reward_fee_bps: u16,
+        num_operators: u16,
+        slasher_amounts: &[u64],
+        veto_threshold: u64,
+        require_resolver_assignment: bool,
     ) -> TestResult<ConfiguredVault> {
         let mut vault_program_client = self.vault_program_client();
         let mut restaking_program_client = self.restaking_program_client();
@@ -398,6 +496,8 @@ impl TestBuilder {
                 &ncn_root.ncn_admin,
                 VETO_DURATION,
                 DELETE_SLASH_PROPOSAL_DURATION,
+                veto_threshold,
+                require_resolver_assignment,
             )
             .await
             .unwrap();
@@ -450,6 +550,20 @@ impl TestBuilder {
             slashers_amounts.push((slasher_root, *amount));
         }
 
+        // Wait for the slasher tickets to activate so slashes can be proposed
+        let config: resolver_core::config::Config = resolver_program_client
+            .get_account(
+                &resolver_core::config::Config::find_program_address(&resolver_program::id()).0,
+            )
+            .await?;
+        self.warp_slot_incremental(config.epoch_length())
+            .await
+            .unwrap();
+        let operator_pubkeys: Vec<_> = operator_roots.iter().map(|r| r.operator_pubkey).collect();
+        vault_program_client
+            .do_full_vault_update(&vault_root.vault_pubkey, &operator_pubkeys)
+            .await?;
+
         Ok(ConfiguredVault {
             vault_program_client,
             restaking_program_client,
@@ -461,4 +575,88 @@ impl TestBuilder {
             slashers_amounts,
         })
     }
+
+    /// Configures another vault for the NCN, operators and slashers of a configured vault so a
+    /// slash can span several vaults
+    pub async fn setup_additional_vault(
+        &mut self,
+        ncn_root: &NcnRoot,
+        operator_roots: &[OperatorRoot],
+        slashers_amounts: &[(SlasherRoot, u64)],
+    ) -> TestResult<VaultRoot> {
+        let mut vault_program_client = self.vault_program_client();
+        let mut restaking_program_client = self.restaking_program_client();
+
+        let vault_root = vault_program_client.do_initialize_vault(0, 0, 0, 9).await?;
+
+        // vault <> ncn
+        restaking_program_client
+            .do_initialize_ncn_vault_ticket(ncn_root, &vault_root.vault_pubkey)
+            .await?;
+        self.warp_slot_incremental(1).await.unwrap();
+        restaking_program_client
+            .do_warmup_ncn_vault_ticket(ncn_root, &vault_root.vault_pubkey)
+            .await?;
+        vault_program_client
+            .do_initialize_vault_ncn_ticket(&vault_root, &ncn_root.ncn_pubkey)
+            .await?;
+        self.warp_slot_incremental(1).await.unwrap();
+        vault_program_client
+            .do_warmup_vault_ncn_ticket(&vault_root, &ncn_root.ncn_pubkey)
+            .await?;
+
+        // vault <> operator
+        for operator_root in operator_roots {
+            restaking_program_client
+                .do_initialize_operator_vault_ticket(operator_root, &vault_root.vault_pubkey)
+                .await?;
+            self.warp_slot_incremental(1).await.unwrap();
+            restaking_program_client
+                .do_warmup_operator_vault_ticket(operator_root, &vault_root.vault_pubkey)
+                .await?;
+            vault_program_client
+                .do_initialize_vault_operator_delegation(
+                    &vault_root,
+                    &operator_root.operator_pubkey,
+                )
+                .await?;
+        }
+
+        for (slasher_root, amount) in slashers_amounts {
+            restaking_program_client
+                .do_initialize_ncn_vault_slasher_ticket(
+                    ncn_root,
+                    &vault_root.vault_pubkey,
+                    &slasher_root.slasher_pubkey,
+                    *amount,
+                )
+                .await?;
+            self.warp_slot_incremental(1).await.unwrap();
+            restaking_program_client
+                .do_warmup_ncn_vault_slasher_ticket(
+                    ncn_root,
+                    &vault_root.vault_pubkey,
+                    &slasher_root.slasher_pubkey,
+                )
+                .await?;
+
+            vault_program_client
+                .do_initialize_vault_ncn_slasher_ticket(
+                    &vault_root,
+                    &ncn_root.ncn_pubkey,
+                    &slasher_root.slasher_pubkey,
+                )
+                .await?;
+            self.warp_slot_incremental(1).await.unwrap();
+            vault_program_client
+                .do_warmup_vault_ncn_slasher_ticket(
+                    &vault_root,
+                    &ncn_root.ncn_pubkey,
+                    &slasher_root.slasher_pubkey,
+                )
+                .await?;
+        }
+
+        Ok(vault_root)
+    }
 }
@@ -10,24 +10,38 @@ use jito_vault_core::{
 };
 use resolver_core::{
     config::Config, ncn_resolver_program_config::NcnResolverProgramConfig,
-    ncn_slash_proposal_ticket::NcnSlashProposalTicket, resolver::Resolver,
-    slash_proposal::SlashProposal, slasher::Slasher,
+    ncn_resolver_ticket::NcnResolverTicket, ncn_slash_proposal_ticket::NcnSlashProposalTicket,
+    resolver::Resolver, resolver_ncn_ticket::ResolverNcnTicket, resolver_vote::ResolverVote,
+    slash_proposal::SlashProposal, slash_proposal_appeal::SlashProposalAppeal, slasher::Slasher,
+};
+use resolver_sdk::{
+    error::ResolverError,
+    event::ResolverEvent,
+    instruction::{
+        ResolverAdminRole, ResolverAssignmentPolicy, SlashAllocation, SlashReason, SlasherAdminRole,
+    },
 };
-use resolver_sdk::{error::ResolverError, instruction::SlasherAdminRole};
 use solana_program::{
     clock::Clock, instruction::InstructionError, native_token::sol_to_lamports, pubkey::Pubkey,
     system_instruction::transfer,
 };
-use solana_program_test::BanksClient;
+use solana_program_test::{BanksClient, BanksClientError};
 use solana_sdk::{
     commitment_config::CommitmentLevel,
     signature::Keypair,
     signer::Signer,
     transaction::{Transaction, TransactionError},
 };
-use spl_associated_token_account::get_associated_token_address;
+use spl_associated_token_account::{
+    get_associated_token_address, get_associated_token_address_with_program_id,
+    instruction::create_associated_token_account_idempotent,
+};
 
-use super::{restaking_client::NcnRoot, vault_client::VaultRoot, TestError, TestResult};
+use super::{
+    restaking_client::{NcnRoot, OperatorRoot},
+    vault_client::VaultRoot,
+    TestError, TestResult,
+};
 
 #[derive(Debug)]
 pub struct ResolverRoot {
@@ -41,9 +55,26 @@ pub struct SlasherRoot {
     pub slasher_admin: Keypair,
 }
 
+#[derive(Debug)]
+pub struct SlashProposalRoot {
+    pub slash_proposal_pubkey: Pubkey,
+    pub ncn_slash_proposal_ticket_pubkey: Pubkey,
+}
+
+/// The accounts moving a slash proposal bond, the program id is passed for the ones left `None`
+#[derive(Debug, Default)]
+struct BondAccounts {
+    destination: Option<Pubkey>,
+    slash_proposal_token_account: Option<Pubkey>,
+    mint: Option<Pubkey>,
+    token_program: Option<Pubkey>,
+}
+
 pub struct ResolverProgramClient {
     banks_client: BanksClient,
     payer: Keypair,
+    /// The events emitted by the last processed transaction
+    events: Vec<ResolverEvent>,
 }
 
 impl ResolverProgramClient {
@@ -51,9 +82,15 @@ impl ResolverProgramClient {
         Self {
             banks_client,
             payer,
+            events: Vec::new(),
         }
     }
 
+    /// Returns the events emitted by the last processed transaction
+    pub fn events(&self) -> &[ResolverEvent] {
+        &self.events
+    }
+
     pub async fn _airdrop(&mut self, to: &Pubkey, sol: f64) -> TestResult<()> {
         let blockhash = self.banks_client.get_latest_blockhash().await?;
         self.banks_client
@@ -111,6 +148,55 @@ impl ResolverProgramClient {
         .await
     }
 
+    pub async fn config_set_admin(
+        &mut self,
+        config: &Pubkey,
+        old_admin: &Keypair,
+        new_admin: &Keypair,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[resolver_sdk::sdk::config_set_admin(
+                &resolver_program::id(),
+                config,
+                &old_admin.pubkey(),
+                &new_admin.pubkey(),
+            )],
+            Some(&old_admin.pubkey()),
+            &[old_admin, new_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn config_set_parameters(
+        &mut self,
+        config: &Pubkey,
+        admin: &Keypair,
+        jito_restaking_program: Option<Pubkey>,
+        jito_vault_program: Option<Pubkey>,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[resolver_sdk::sdk::config_set_parameters(
+                &resolver_program::id(),
+                config,
+                &admin.pubkey(),
+                &jito_restaking_core::config::Config::find_program_address(
+                    &jito_restaking_program::id(),
+                )
+                .0,
+                &jito_vault_core::config::Config::find_program_address(&jito_vault_program::id()).0,
+                jito_restaking_program,
+                jito_vault_program,
+            )],
+            Some(&admin.pubkey()),
+            &[admin],
+            blockhash,
+        ))
+        .await
+    }
+
     pub async fn do_initialize_ncn_resolver_program_config(
         &mut self,
         config: &Pubkey,
@@ -118,6 +204,8 @@ impl ResolverProgramClient {
         admin: &Keypair,
         veto_duration: u64,
         delete_slash_proposal_duration: u64,
+        veto_threshold: u64,
+        require_resolver_assignment: bool,
     ) -> TestResult<()> {
         let ncn_resolver_program_config =
             NcnResolverProgramConfig::find_program_address(&resolver_program::id(), ncn).0;
@@ -129,6 +217,8 @@ impl ResolverProgramClient {
             admin,
             veto_duration,
             delete_slash_proposal_duration,
+            veto_threshold,
+            require_resolver_assignment,
         )
         .await?;
 
@@ -143,6 +233,8 @@ impl ResolverProgramClient {
         admin: &Keypair,
         veto_duration: u64,
         delete_slash_proposal_duration: u64,
+        veto_threshold: u64,
+        require_resolver_assignment: bool,
     ) -> TestResult<()> {
         let blockhash = self.banks_client.get_latest_blockhash().await?;
         self.process_transaction(&Transaction::new_signed_with_payer(
@@ -154,6 +246,8 @@ impl ResolverProgramClient {
                 &admin.pubkey(),
                 veto_duration,
                 delete_slash_proposal_duration,
+                veto_threshold,
+                require_resolver_assignment,
             )],
             Some(&admin.pubkey()),
             &[admin],
@@ -162,6 +256,148 @@ impl ResolverProgramClient {
         .await
     }
 
+    pub async fn ncn_resolver_program_config_set_admin(
+        &mut self,
+        ncn: &Pubkey,
+        old_admin: &Keypair,
+        new_admin: &Keypair,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[resolver_sdk::sdk::ncn_resolver_program_config_set_admin(
+                &resolver_program::id(),
+                &Config::find_program_address(&resolver_program::id()).0,
+                ncn,
+                &NcnResolverProgramConfig::find_program_address(&resolver_program::id(), ncn).0,
+                &old_admin.pubkey(),
+                &new_admin.pubkey(),
+            )],
+            Some(&old_admin.pubkey()),
+            &[old_admin, new_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn ncn_resolver_program_config_set_parameters(
+        &mut self,
+        ncn: &Pubkey,
+        resolver_admin: &Keypair,
+        veto_duration: Option<u64>,
+        delete_slash_proposal_duration: Option<u64>,
+        resolver_penalty_bps: Option<u16>,
+        appeal_extension_slots: Option<u64>,
+        veto_threshold: Option<u64>,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[
+                resolver_sdk::sdk::ncn_resolver_program_config_set_parameters(
+                    &resolver_program::id(),
+                    &Config::find_program_address(&resolver_program::id()).0,
+                    ncn,
+                    &NcnResolverProgramConfig::find_program_address(&resolver_program::id(), ncn).0,
+                    &resolver_admin.pubkey(),
+                    veto_duration,
+                    delete_slash_proposal_duration,
+                    resolver_penalty_bps,
+                    appeal_extension_slots,
+                    veto_threshold,
+                ),
+            ],
+            Some(&resolver_admin.pubkey()),
+            &[resolver_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn ncn_resolver_program_config_set_assignment_policy(
+        &mut self,
+        ncn: &Pubkey,
+        resolver_admin: &Keypair,
+        default_resolver: Option<&Pubkey>,
+        assignment_policy: ResolverAssignmentPolicy,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[
+                resolver_sdk::sdk::ncn_resolver_program_config_set_assignment_policy(
+                    &resolver_program::id(),
+                    &Config::find_program_address(&resolver_program::id()).0,
+                    ncn,
+                    &NcnResolverProgramConfig::find_program_address(&resolver_program::id(), ncn).0,
+                    &resolver_admin.pubkey(),
+                    default_resolver,
+                    assignment_policy,
+                ),
+            ],
+            Some(&resolver_admin.pubkey()),
+            &[resolver_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn ncn_resolver_program_config_set_bond(
+        &mut self,
+        ncn: &Pubkey,
+        resolver_admin: &Keypair,
+        bond_mint: Option<&Pubkey>,
+        bond_amount: u64,
+        bond_sink: Pubkey,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[resolver_sdk::sdk::ncn_resolver_program_config_set_bond(
+                &resolver_program::id(),
+                &Config::find_program_address(&resolver_program::id()).0,
+                ncn,
+                &NcnResolverProgramConfig::find_program_address(&resolver_program::id(), ncn).0,
+                &resolver_admin.pubkey(),
+                bond_mint,
+                bond_amount,
+                bond_sink,
+            )],
+            Some(&self.payer.pubkey()),
+            &[&self.payer, resolver_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn ncn_resolver_program_config_set_proceeds(
+        &mut self,
+        ncn: &Pubkey,
+        resolver_admin: &Keypair,
+        burn_bps: u16,
+        treasury: Pubkey,
+        treasury_bps: u16,
+        insurance_fund: Pubkey,
+        insurance_fund_bps: u16,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[resolver_sdk::sdk::ncn_resolver_program_config_set_proceeds(
+                &resolver_program::id(),
+                &Config::find_program_address(&resolver_program::id()).0,
+                ncn,
+                &NcnResolverProgramConfig::find_program_address(&resolver_program::id(), ncn).0,
+                &resolver_admin.pubkey(),
+                burn_bps,
+                treasury,
+                treasury_bps,
+                insurance_fund,
+                insurance_fund_bps,
+            )],
+            Some(&self.payer.pubkey()),
+            &[&self.payer, resolver_admin],
+            blockhash,
+        ))
+        .await
+    }
+
     pub async fn do_initialize_resolver(&mut self, ncn_root: &NcnRoot) -> TestResult<ResolverRoot> {
         // create resolver + add operator vault
         let resolver_base = Keypair::new();
@@ -211,168 +447,939 @@ impl ResolverProgramClient {
         .await
     }
 
-    pub async fn do_initialize_slasher(&mut self, ncn_root: &NcnRoot) -> TestResult<SlasherRoot> {
-        // create resolver + add operator vault
-        let slasher_base = Keypair::new();
-        let slasher_pubkey =
-            Slasher::find_program_address(&resolver_program::id(), &slasher_base.pubkey()).0;
-
-        let slasher_admin = Keypair::new();
-        self._airdrop(&slasher_admin.pubkey(), 1.0).await?;
+    /// Deposits into the resolver bond from the resolver admin ATA
+    pub async fn resolver_deposit_bond(
+        &mut self,
+        resolver_root: &ResolverRoot,
+        bond_mint: &Pubkey,
+        amount: u64,
+    ) -> TestResult<()> {
+        let token_program = self.get_token_program(bond_mint).await?;
 
-        self.initialize_slasher(
-            &ncn_root.ncn_pubkey,
-            &slasher_pubkey,
-            &slasher_admin,
-            &slasher_base,
-        )
+        // The bond is held in an ATA owned by the resolver
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[create_associated_token_account_idempotent(
+                &self.payer.pubkey(),
+                &resolver_root.resolver_pubkey,
+                bond_mint,
+                &token_program,
+            )],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
         .await?;
 
-        Ok(SlasherRoot {
-            slasher_pubkey,
-            slasher_admin,
-        })
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[resolver_sdk::sdk::resolver_deposit_bond(
+                &resolver_program::id(),
+                &resolver_root.resolver_pubkey,
+                &resolver_root.resolver_admin.pubkey(),
+                bond_mint,
+                &get_associated_token_address_with_program_id(
+                    &resolver_root.resolver_admin.pubkey(),
+                    bond_mint,
+                    &token_program,
+                ),
+                &get_associated_token_address_with_program_id(
+                    &resolver_root.resolver_pubkey,
+                    bond_mint,
+                    &token_program,
+                ),
+                &token_program,
+                amount,
+            )],
+            Some(&self.payer.pubkey()),
+            &[&self.payer, &resolver_root.resolver_admin],
+            blockhash,
+        ))
+        .await
     }
 
-    async fn initialize_slasher(
+    /// Deregisters the resolver, cooling down its tickets with the active `ncns`
+    pub async fn resolver_deregister(
         &mut self,
-        ncn: &Pubkey,
-        slasher: &Pubkey,
-        admin: &Keypair,
-        base: &Keypair,
+        resolver_root: &ResolverRoot,
+        ncns: &[Pubkey],
     ) -> TestResult<()> {
+        let ncn_resolver_tickets: Vec<(Pubkey, Pubkey, Pubkey)> = ncns
+            .iter()
+            .map(|ncn| {
+                (
+                    *ncn,
+                    NcnResolverProgramConfig::find_program_address(&resolver_program::id(), ncn).0,
+                    NcnResolverTicket::find_program_address(
+                        &resolver_program::id(),
+                        ncn,
+                        &resolver_root.resolver_pubkey,
+                    )
+                    .0,
+                )
+            })
+            .collect();
+
         let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[resolver_sdk::sdk::resolver_deregister(
+                &resolver_program::id(),
+                &Config::find_program_address(&resolver_program::id()).0,
+                &resolver_root.resolver_pubkey,
+                &resolver_root.resolver_admin.pubkey(),
+                &ncn_resolver_tickets,
+            )],
+            Some(&self.payer.pubkey()),
+            &[&self.payer, &resolver_root.resolver_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    /// Withdraws the resolver bond to the resolver admin ATA
+    pub async fn resolver_withdraw_bond(&mut self, resolver_root: &ResolverRoot) -> TestResult<()> {
+        let resolver: Resolver = self.get_account(&resolver_root.resolver_pubkey).await?;
+        let token_program = self.get_token_program(&resolver.bond_mint).await?;
 
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
         self.process_transaction(&Transaction::new_signed_with_payer(
-            &[resolver_sdk::sdk::initialize_slasher(
+            &[resolver_sdk::sdk::resolver_withdraw_bond(
                 &resolver_program::id(),
                 &Config::find_program_address(&resolver_program::id()).0,
-                ncn,
-                slasher,
-                &admin.pubkey(),
-                &base.pubkey(),
+                &resolver_root.resolver_pubkey,
+                &resolver_root.resolver_admin.pubkey(),
+                &resolver.bond_mint,
+                &get_associated_token_address_with_program_id(
+                    &resolver_root.resolver_pubkey,
+                    &resolver.bond_mint,
+                    &token_program,
+                ),
+                &get_associated_token_address_with_program_id(
+                    &resolver_root.resolver_admin.pubkey(),
+                    &resolver.bond_mint,
+                    &token_program,
+                ),
+                &token_program,
             )],
-            Some(&admin.pubkey()),
-            &[admin, base],
+            Some(&self.payer.pubkey()),
+            &[&self.payer, &resolver_root.resolver_admin],
             blockhash,
         ))
         .await
     }
 
-    pub async fn do_propose_slash(
+    pub async fn do_initialize_ncn_resolver_ticket(
         &mut self,
-        ncn: &Pubkey,
-        operator: &Pubkey,
-        slasher_root: &SlasherRoot,
-        slash_amount: u64,
+        ncn_root: &NcnRoot,
+        resolver: &Pubkey,
     ) -> TestResult<()> {
-        // create resolver + add operator vault
-        let slash_proposal = SlashProposal::find_program_address(
-            &resolver_program::id(),
-            &ncn,
-            &operator,
-            &slasher_root.slasher_pubkey,
-        )
-        .0;
-        let ncn_slash_proposal_ticket = NcnSlashProposalTicket::find_program_address(
-            &resolver_program::id(),
-            ncn,
-            &slash_proposal,
-        )
-        .0;
+        self.initialize_ncn_resolver_ticket(ncn_root, resolver, None)
+            .await
+    }
 
-        self.propose_slash(
-            ncn,
-            operator,
-            &slasher_root.slasher_pubkey,
-            &slash_proposal,
-            &ncn_slash_proposal_ticket,
-            &slasher_root.slasher_admin,
-            slash_amount,
-        )
+    pub async fn initialize_ncn_resolver_ticket(
+        &mut self,
+        ncn_root: &NcnRoot,
+        resolver: &Pubkey,
+        resolver_ncn_ticket: Option<&Pubkey>,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[resolver_sdk::sdk::initialize_ncn_resolver_ticket(
+                &resolver_program::id(),
+                &Config::find_program_address(&resolver_program::id()).0,
+                &NcnResolverProgramConfig::find_program_address(
+                    &resolver_program::id(),
+                    &ncn_root.ncn_pubkey,
+                )
+                .0,
+                &ncn_root.ncn_pubkey,
+                resolver,
+                &NcnResolverTicket::find_program_address(
+                    &resolver_program::id(),
+                    &ncn_root.ncn_pubkey,
+                    resolver,
+                )
+                .0,
+                &ncn_root.ncn_admin.pubkey(),
+                resolver_ncn_ticket,
+            )],
+            Some(&ncn_root.ncn_admin.pubkey()),
+            &[&ncn_root.ncn_admin],
+            blockhash,
+        ))
         .await
     }
 
-    async fn propose_slash(
+    pub async fn do_initialize_resolver_ncn_ticket(
         &mut self,
+        resolver_root: &ResolverRoot,
         ncn: &Pubkey,
-        operator: &Pubkey,
-        slasher: &Pubkey,
-        slash_proposal: &Pubkey,
-        ncn_slash_proposal_ticket: &Pubkey,
-        slasher_admin: &Keypair,
-        slash_amount: u64,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[resolver_sdk::sdk::initialize_resolver_ncn_ticket(
+                &resolver_program::id(),
+                &Config::find_program_address(&resolver_program::id()).0,
+                &NcnResolverProgramConfig::find_program_address(&resolver_program::id(), ncn).0,
+                ncn,
+                &resolver_root.resolver_pubkey,
+                &ResolverNcnTicket::find_program_address(
+                    &resolver_program::id(),
+                    &resolver_root.resolver_pubkey,
+                    ncn,
+                )
+                .0,
+                &resolver_root.resolver_admin.pubkey(),
+            )],
+            Some(&resolver_root.resolver_admin.pubkey()),
+            &[&resolver_root.resolver_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_warmup_ncn_resolver_ticket(
+        &mut self,
+        ncn_root: &NcnRoot,
+        resolver: &Pubkey,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[resolver_sdk::sdk::warmup_ncn_resolver_ticket(
+                &resolver_program::id(),
+                &Config::find_program_address(&resolver_program::id()).0,
+                &NcnResolverProgramConfig::find_program_address(
+                    &resolver_program::id(),
+                    &ncn_root.ncn_pubkey,
+                )
+                .0,
+                &ncn_root.ncn_pubkey,
+                resolver,
+                &NcnResolverTicket::find_program_address(
+                    &resolver_program::id(),
+                    &ncn_root.ncn_pubkey,
+                    resolver,
+                )
+                .0,
+                &ncn_root.ncn_admin.pubkey(),
+            )],
+            Some(&ncn_root.ncn_admin.pubkey()),
+            &[&ncn_root.ncn_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_cooldown_ncn_resolver_ticket(
+        &mut self,
+        ncn_root: &NcnRoot,
+        resolver: &Pubkey,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[resolver_sdk::sdk::cooldown_ncn_resolver_ticket(
+                &resolver_program::id(),
+                &Config::find_program_address(&resolver_program::id()).0,
+                &NcnResolverProgramConfig::find_program_address(
+                    &resolver_program::id(),
+                    &ncn_root.ncn_pubkey,
+                )
+                .0,
+                &ncn_root.ncn_pubkey,
+                resolver,
+                &NcnResolverTicket::find_program_address(
+                    &resolver_program::id(),
+                    &ncn_root.ncn_pubkey,
+                    resolver,
+                )
+                .0,
+                &ncn_root.ncn_admin.pubkey(),
+            )],
+            Some(&ncn_root.ncn_admin.pubkey()),
+            &[&ncn_root.ncn_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    /// `last_resolver` is the resolver holding the last index of the NCN registry, `None` when it
+    /// is the removed resolver
+    pub async fn do_remove_ncn_resolver_ticket(
+        &mut self,
+        ncn_root: &NcnRoot,
+        resolver: &Pubkey,
+        last_resolver: Option<&Pubkey>,
+    ) -> TestResult<()> {
+        let last_ncn_resolver_ticket = last_resolver.map(|last_resolver| {
+            NcnResolverTicket::find_program_address(
+                &resolver_program::id(),
+                &ncn_root.ncn_pubkey,
+                last_resolver,
+            )
+            .0
+        });
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[resolver_sdk::sdk::remove_ncn_resolver_ticket(
+                &resolver_program::id(),
+                &Config::find_program_address(&resolver_program::id()).0,
+                &NcnResolverProgramConfig::find_program_address(
+                    &resolver_program::id(),
+                    &ncn_root.ncn_pubkey,
+                )
+                .0,
+                &ncn_root.ncn_pubkey,
+                resolver,
+                &NcnResolverTicket::find_program_address(
+                    &resolver_program::id(),
+                    &ncn_root.ncn_pubkey,
+                    resolver,
+                )
+                .0,
+                &ncn_root.ncn_admin.pubkey(),
+                last_resolver,
+                last_ncn_resolver_ticket.as_ref(),
+            )],
+            Some(&ncn_root.ncn_admin.pubkey()),
+            &[&ncn_root.ncn_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_initialize_slasher(&mut self, ncn_root: &NcnRoot) -> TestResult<SlasherRoot> {
+        // create resolver + add operator vault
+        let slasher_base = Keypair::new();
+        let slasher_pubkey =
+            Slasher::find_program_address(&resolver_program::id(), &slasher_base.pubkey()).0;
+
+        let slasher_admin = Keypair::new();
+        self._airdrop(&slasher_admin.pubkey(), 1.0).await?;
+
+        self.initialize_slasher(
+            &ncn_root.ncn_pubkey,
+            &slasher_pubkey,
+            &slasher_admin,
+            &slasher_base,
+        )
+        .await?;
+
+        Ok(SlasherRoot {
+            slasher_pubkey,
+            slasher_admin,
+        })
+    }
+
+    async fn initialize_slasher(
+        &mut self,
+        ncn: &Pubkey,
+        slasher: &Pubkey,
+        admin: &Keypair,
+        base: &Keypair,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[resolver_sdk::sdk::initialize_slasher(
+                &resolver_program::id(),
+                &Config::find_program_address(&resolver_program::id()).0,
+                ncn,
+                slasher,
+                &admin.pubkey(),
+                &base.pubkey(),
+            )],
+            Some(&admin.pubkey()),
+            &[admin, base],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_propose_slash(
+        &mut self,
+        ncn: &Pubkey,
+        operator: &Pubkey,
+        slasher_root: &SlasherRoot,
+        vault: &Pubkey,
+        slash_amount: u64,
+    ) -> TestResult<SlashProposalRoot> {
+        self.do_propose_slash_with_evidence(
+            ncn,
+            operator,
+            slasher_root,
+            vault,
+            slash_amount,
+            SlashReason::Downtime,
+            [0; 32],
+            String::new(),
+        )
+        .await
+    }
+
+    pub async fn do_propose_slash_with_evidence(
+        &mut self,
+        ncn: &Pubkey,
+        operator: &Pubkey,
+        slasher_root: &SlasherRoot,
+        vault: &Pubkey,
+        slash_amount: u64,
+        reason: SlashReason,
+        evidence_hash: [u8; 32],
+        metadata_uri: String,
+    ) -> TestResult<SlashProposalRoot> {
+        let slash_proposal_root = self
+            .next_slash_proposal_root(ncn, operator, &slasher_root.slasher_pubkey)
+            .await?;
+
+        self.propose_slash(
+            ncn,
+            operator,
+            &slasher_root.slasher_pubkey,
+            vault,
+            &slash_proposal_root.slash_proposal_pubkey,
+            &slash_proposal_root.ncn_slash_proposal_ticket_pubkey,
+            &slasher_root.slasher_admin,
+            None,
+            None,
+            slash_amount,
+            reason,
+            evidence_hash,
+            metadata_uri,
+            SlashAllocation::ProRata,
+            &[],
+        )
+        .await?;
+
+        Ok(slash_proposal_root)
+    }
+
+    /// Proposes a slash, checking the amount against the operator's delegated stake
+    pub async fn do_propose_slash_with_delegation(
+        &mut self,
+        ncn: &Pubkey,
+        operator: &Pubkey,
+        slasher_root: &SlasherRoot,
+        vault: &Pubkey,
+        slash_amount: u64,
+    ) -> TestResult<SlashProposalRoot> {
+        let slash_proposal_root = self
+            .next_slash_proposal_root(ncn, operator, &slasher_root.slasher_pubkey)
+            .await?;
+        let vault_operator_delegation = VaultOperatorDelegation::find_program_address(
+            &jito_vault_program::id(),
+            vault,
+            operator,
+        )
+        .0;
+
+        self.propose_slash(
+            ncn,
+            operator,
+            &slasher_root.slasher_pubkey,
+            vault,
+            &slash_proposal_root.slash_proposal_pubkey,
+            &slash_proposal_root.ncn_slash_proposal_ticket_pubkey,
+            &slasher_root.slasher_admin,
+            Some(&vault_operator_delegation),
+            None,
+            slash_amount,
+            SlashReason::Downtime,
+            [0; 32],
+            String::new(),
+            SlashAllocation::ProRata,
+            &[],
+        )
+        .await?;
+
+        Ok(slash_proposal_root)
+    }
+
+    /// Proposes a slash, passing the resolver picked by the NCN assignment policy
+    pub async fn do_propose_slash_with_resolver(
+        &mut self,
+        ncn: &Pubkey,
+        operator: &Pubkey,
+        slasher_root: &SlasherRoot,
+        vault: &Pubkey,
+        slash_amount: u64,
+        resolver: &Pubkey,
+    ) -> TestResult<SlashProposalRoot> {
+        let slash_proposal_root = self
+            .next_slash_proposal_root(ncn, operator, &slasher_root.slasher_pubkey)
+            .await?;
+
+        self.propose_slash(
+            ncn,
+            operator,
+            &slasher_root.slasher_pubkey,
+            vault,
+            &slash_proposal_root.slash_proposal_pubkey,
+            &slash_proposal_root.ncn_slash_proposal_ticket_pubkey,
+            &slasher_root.slasher_admin,
+            None,
+            Some(resolver),
+            slash_amount,
+            SlashReason::Downtime,
+            [0; 32],
+            String::new(),
+            SlashAllocation::ProRata,
+            &[],
+        )
+        .await?;
+
+        Ok(slash_proposal_root)
+    }
+
+    /// Proposes a slash across several vaults delegating to the operator, the first one is passed
+    /// as the primary vault
+    pub async fn do_propose_multi_vault_slash(
+        &mut self,
+        ncn: &Pubkey,
+        operator: &Pubkey,
+        slasher_root: &SlasherRoot,
+        vaults: &[Pubkey],
+        slash_amount: u64,
+        allocation: SlashAllocation,
+    ) -> TestResult<SlashProposalRoot> {
+        let slash_proposal_root = self
+            .next_slash_proposal_root(ncn, operator, &slasher_root.slasher_pubkey)
+            .await?;
+        let vault_operator_delegation = VaultOperatorDelegation::find_program_address(
+            &jito_vault_program::id(),
+            &vaults[0],
+            operator,
+        )
+        .0;
+
+        self.propose_slash(
+            ncn,
+            operator,
+            &slasher_root.slasher_pubkey,
+            &vaults[0],
+            &slash_proposal_root.slash_proposal_pubkey,
+            &slash_proposal_root.ncn_slash_proposal_ticket_pubkey,
+            &slasher_root.slasher_admin,
+            Some(&vault_operator_delegation),
+            None,
+            slash_amount,
+            SlashReason::Downtime,
+            [0; 32],
+            String::new(),
+            allocation,
+            &vaults[1..],
+        )
+        .await?;
+
+        Ok(slash_proposal_root)
+    }
+
+    /// Derives the accounts of the next slash proposal of the slasher
+    async fn next_slash_proposal_root(
+        &mut self,
+        ncn: &Pubkey,
+        operator: &Pubkey,
+        slasher: &Pubkey,
+    ) -> TestResult<SlashProposalRoot> {
+        let slasher_account: Slasher = self.get_account(slasher).await?;
+        let slash_proposal = SlashProposal::find_program_address(
+            &resolver_program::id(),
+            ncn,
+            operator,
+            slasher,
+            slasher_account.proposal_count(),
+        )
+        .0;
+        let ncn_slash_proposal_ticket = NcnSlashProposalTicket::find_program_address(
+            &resolver_program::id(),
+            ncn,
+            &slash_proposal,
+        )
+        .0;
+
+        Ok(SlashProposalRoot {
+            slash_proposal_pubkey: slash_proposal,
+            ncn_slash_proposal_ticket_pubkey: ncn_slash_proposal_ticket,
+        })
+    }
+
+    async fn propose_slash(
+        &mut self,
+        ncn: &Pubkey,
+        operator: &Pubkey,
+        slasher: &Pubkey,
+        vault: &Pubkey,
+        slash_proposal: &Pubkey,
+        ncn_slash_proposal_ticket: &Pubkey,
+        slasher_admin: &Keypair,
+        vault_operator_delegation: Option<&Pubkey>,
+        resolver: Option<&Pubkey>,
+        slash_amount: u64,
+        reason: SlashReason,
+        evidence_hash: [u8; 32],
+        metadata_uri: String,
+        allocation: SlashAllocation,
+        other_vaults: &[Pubkey],
+    ) -> TestResult<()> {
+        let other_vaults: Vec<(Pubkey, Pubkey, Pubkey)> = other_vaults
+            .iter()
+            .map(|vault| {
+                (
+                    *vault,
+                    NcnVaultSlasherTicket::find_program_address(
+                        &jito_restaking_program::id(),
+                        ncn,
+                        vault,
+                        slasher,
+                    )
+                    .0,
+                    VaultOperatorDelegation::find_program_address(
+                        &jito_vault_program::id(),
+                        vault,
+                        operator,
+                    )
+                    .0,
+                )
+            })
+            .collect();
+
+        let ncn_resolver_ticket = resolver.map(|resolver| {
+            NcnResolverTicket::find_program_address(&resolver_program::id(), ncn, resolver).0
+        });
+
+        // SPL bonds are moved from the slasher admin ATA into an ATA owned by the proposal
+        let ncn_resolver_program_config: NcnResolverProgramConfig = self
+            .get_account(
+                &NcnResolverProgramConfig::find_program_address(&resolver_program::id(), ncn).0,
+            )
+            .await?;
+        let bond_mint = ncn_resolver_program_config.bond_mint;
+        let bond_accounts =
+            if ncn_resolver_program_config.bond_amount() > 0 && bond_mint.ne(&Pubkey::default()) {
+                let token_program = self.get_token_program(&bond_mint).await?;
+                let slash_proposal_token_account = get_associated_token_address_with_program_id(
+                    slash_proposal,
+                    &bond_mint,
+                    &token_program,
+                );
+                let blockhash = self.banks_client.get_latest_blockhash().await?;
+                self.process_transaction(&Transaction::new_signed_with_payer(
+                    &[create_associated_token_account_idempotent(
+                        &self.payer.pubkey(),
+                        slash_proposal,
+                        &bond_mint,
+                        &token_program,
+                    )],
+                    Some(&self.payer.pubkey()),
+                    &[&self.payer],
+                    blockhash,
+                ))
+                .await?;
+
+                BondAccounts {
+                    destination: Some(get_associated_token_address_with_program_id(
+                        &slasher_admin.pubkey(),
+                        &bond_mint,
+                        &token_program,
+                    )),
+                    slash_proposal_token_account: Some(slash_proposal_token_account),
+                    mint: Some(bond_mint),
+                    token_program: Some(token_program),
+                }
+            } else {
+                BondAccounts::default()
+            };
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[resolver_sdk::sdk::propose_slash(
+                &resolver_program::id(),
+                &Config::find_program_address(&resolver_program::id()).0,
+                &NcnResolverProgramConfig::find_program_address(&resolver_program::id(), ncn).0,
+                ncn,
+                operator,
+                slasher,
+                vault,
+                &NcnVaultSlasherTicket::find_program_address(
+                    &jito_restaking_program::id(),
+                    ncn,
+                    vault,
+                    slasher,
+                )
+                .0,
+                slash_proposal,
+                ncn_slash_proposal_ticket,
+                &slasher_admin.pubkey(),
+                vault_operator_delegation,
+                resolver,
+                ncn_resolver_ticket.as_ref(),
+                bond_accounts.destination.as_ref(),
+                bond_accounts.slash_proposal_token_account.as_ref(),
+                bond_accounts.mint.as_ref(),
+                bond_accounts.token_program.as_ref(),
+                slash_amount,
+                reason,
+                evidence_hash,
+                metadata_uri,
+                allocation,
+                &other_vaults,
+            )],
+            Some(&slasher_admin.pubkey()),
+            &[slasher_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_set_resolver(
+        &mut self,
+        ncn_root: &NcnRoot,
+        operator: &Pubkey,
+        slasher_root: &SlasherRoot,
+        slash_proposal_root: &SlashProposalRoot,
+        new_resolver_admin: &Pubkey,
+    ) -> TestResult<()> {
+        self.set_resolver(
+            &ncn_root.ncn_pubkey,
+            operator,
+            &slasher_root.slasher_pubkey,
+            &slash_proposal_root.slash_proposal_pubkey,
+            &slash_proposal_root.ncn_slash_proposal_ticket_pubkey,
+            &ncn_root.ncn_admin,
+            new_resolver_admin,
+        )
+        .await
+    }
+
+    async fn set_resolver(
+        &mut self,
+        ncn: &Pubkey,
+        operator: &Pubkey,
+        slasher: &Pubkey,
+        slash_proposal: &Pubkey,
+        ncn_slash_proposal_ticket: &Pubkey,
+        ncn_resolver_admin: &Keypair,
+        new_resolver_admin: &Pubkey,
+    ) -> TestResult<()> {
+        let old_resolver = self.assigned_resolver(ncn_slash_proposal_ticket).await;
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[resolver_sdk::sdk::set_resolver(
+                &resolver_program::id(),
+                &Config::find_program_address(&resolver_program::id()).0,
+                &NcnResolverProgramConfig::find_program_address(&resolver_program::id(), ncn).0,
+                ncn,
+                operator,
+                slasher,
+                slash_proposal,
+                ncn_slash_proposal_ticket,
+                &ncn_resolver_admin.pubkey(),
+                new_resolver_admin,
+                &NcnResolverTicket::find_program_address(
+                    &resolver_program::id(),
+                    ncn,
+                    new_resolver_admin,
+                )
+                .0,
+                old_resolver.as_ref(),
+            )],
+            Some(&ncn_resolver_admin.pubkey()),
+            &[ncn_resolver_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_veto_slash(
+        &mut self,
+        ncn: &Pubkey,
+        operator: &Pubkey,
+        slasher_root: &SlasherRoot,
+        slash_proposal_root: &SlashProposalRoot,
+        resolver_root: &ResolverRoot,
+    ) -> TestResult<()> {
+        self.veto_slash(
+            ncn,
+            operator,
+            &slasher_root.slasher_pubkey,
+            &resolver_root.resolver_pubkey,
+            &slash_proposal_root.slash_proposal_pubkey,
+            &slash_proposal_root.ncn_slash_proposal_ticket_pubkey,
+            &resolver_root.resolver_admin,
+        )
+        .await
+    }
+
+    pub async fn veto_slash(
+        &mut self,
+        ncn: &Pubkey,
+        operator: &Pubkey,
+        slasher: &Pubkey,
+        resolver: &Pubkey,
+        slash_proposal: &Pubkey,
+        ncn_slash_proposal_ticket: &Pubkey,
+        resolver_veto_admin: &Keypair,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        let resolver_vote =
+            ResolverVote::find_program_address(&resolver_program::id(), slash_proposal, resolver).0;
+        let bond_accounts = self
+            .slash_proposal_bond_accounts(slash_proposal, true)
+            .await?;
+
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[resolver_sdk::sdk::veto_slash(
+                &resolver_program::id(),
+                &Config::find_program_address(&resolver_program::id()).0,
+                &NcnResolverProgramConfig::find_program_address(&resolver_program::id(), ncn).0,
+                ncn,
+                operator,
+                slasher,
+                resolver,
+                slash_proposal,
+                ncn_slash_proposal_ticket,
+                &resolver_vote,
+                &resolver_veto_admin.pubkey(),
+                &NcnResolverTicket::find_program_address(&resolver_program::id(), ncn, resolver).0,
+                bond_accounts.destination.as_ref(),
+                bond_accounts.slash_proposal_token_account.as_ref(),
+                bond_accounts.mint.as_ref(),
+                bond_accounts.token_program.as_ref(),
+            )],
+            Some(&resolver_veto_admin.pubkey()),
+            &[resolver_veto_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_approve_slash(
+        &mut self,
+        ncn: &Pubkey,
+        operator: &Pubkey,
+        slasher_root: &SlasherRoot,
+        slash_proposal_root: &SlashProposalRoot,
+        resolver_root: &ResolverRoot,
+    ) -> TestResult<()> {
+        self.approve_slash(
+            ncn,
+            operator,
+            &slasher_root.slasher_pubkey,
+            &resolver_root.resolver_pubkey,
+            &slash_proposal_root.slash_proposal_pubkey,
+            &slash_proposal_root.ncn_slash_proposal_ticket_pubkey,
+            &resolver_root.resolver_admin,
+        )
+        .await
+    }
+
+    async fn approve_slash(
+        &mut self,
+        ncn: &Pubkey,
+        operator: &Pubkey,
+        slasher: &Pubkey,
+        resolver: &Pubkey,
+        slash_proposal: &Pubkey,
+        ncn_slash_proposal_ticket: &Pubkey,
+        resolver_admin: &Keypair,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[resolver_sdk::sdk::approve_slash(
+                &resolver_program::id(),
+                &Config::find_program_address(&resolver_program::id()).0,
+                &NcnResolverProgramConfig::find_program_address(&resolver_program::id(), ncn).0,
+                ncn,
+                operator,
+                slasher,
+                resolver,
+                slash_proposal,
+                ncn_slash_proposal_ticket,
+                &resolver_admin.pubkey(),
+                &NcnResolverTicket::find_program_address(&resolver_program::id(), ncn, resolver).0,
+            )],
+            Some(&resolver_admin.pubkey()),
+            &[resolver_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_amend_slash_amount(
+        &mut self,
+        ncn: &Pubkey,
+        operator: &Pubkey,
+        slasher_root: &SlasherRoot,
+        slash_proposal_root: &SlashProposalRoot,
+        resolver_root: &ResolverRoot,
+        amount: u64,
     ) -> TestResult<()> {
         let blockhash = self.banks_client.get_latest_blockhash().await?;
 
         self.process_transaction(&Transaction::new_signed_with_payer(
-            &[resolver_sdk::sdk::propose_slash(
+            &[resolver_sdk::sdk::amend_slash_amount(
                 &resolver_program::id(),
                 &Config::find_program_address(&resolver_program::id()).0,
                 &NcnResolverProgramConfig::find_program_address(&resolver_program::id(), ncn).0,
                 ncn,
                 operator,
-                slasher,
-                slash_proposal,
-                ncn_slash_proposal_ticket,
-                &slasher_admin.pubkey(),
-                slash_amount,
+                &slasher_root.slasher_pubkey,
+                &resolver_root.resolver_pubkey,
+                &slash_proposal_root.slash_proposal_pubkey,
+                &slash_proposal_root.ncn_slash_proposal_ticket_pubkey,
+                &resolver_root.resolver_admin.pubkey(),
+                &NcnResolverTicket::find_program_address(
+                    &resolver_program::id(),
+                    ncn,
+                    &resolver_root.resolver_pubkey,
+                )
+                .0,
+                amount,
             )],
-            Some(&slasher_admin.pubkey()),
-            &[slasher_admin],
+            Some(&resolver_root.resolver_admin.pubkey()),
+            &[&resolver_root.resolver_admin],
             blockhash,
         ))
         .await
     }
 
-    pub async fn do_set_resolver(
+    pub async fn do_submit_appeal(
         &mut self,
-        ncn_root: &NcnRoot,
-        operator: &Pubkey,
+        ncn: &Pubkey,
+        operator_root: &OperatorRoot,
         slasher_root: &SlasherRoot,
-        new_resolver_admin: &Pubkey,
+        slash_proposal_root: &SlashProposalRoot,
     ) -> TestResult<()> {
-        // create resolver + add operator vault
-        let slash_proposal = SlashProposal::find_program_address(
-            &resolver_program::id(),
-            &ncn_root.ncn_pubkey,
-            &operator,
-            &slasher_root.slasher_pubkey,
-        )
-        .0;
-        let ncn_slash_proposal_ticket = NcnSlashProposalTicket::find_program_address(
-            &resolver_program::id(),
-            &ncn_root.ncn_pubkey,
-            &slash_proposal,
-        )
-        .0;
-
-        self.set_resolver(
-            &ncn_root.ncn_pubkey,
-            operator,
+        self.submit_appeal(
+            ncn,
+            &operator_root.operator_pubkey,
             &slasher_root.slasher_pubkey,
-            &slash_proposal,
-            &ncn_slash_proposal_ticket,
-            &ncn_root.ncn_admin,
-            new_resolver_admin,
+            &slash_proposal_root.slash_proposal_pubkey,
+            &operator_root.operator_admin,
+            [2; 32],
+            "https://example.com/appeal".to_string(),
         )
         .await
     }
 
-    async fn set_resolver(
+    pub async fn submit_appeal(
         &mut self,
         ncn: &Pubkey,
         operator: &Pubkey,
         slasher: &Pubkey,
         slash_proposal: &Pubkey,
-        ncn_slash_proposal_ticket: &Pubkey,
-        ncn_resolver_admin: &Keypair,
-        new_resolver_admin: &Pubkey,
+        operator_admin: &Keypair,
+        evidence_hash: [u8; 32],
+        metadata_uri: String,
     ) -> TestResult<()> {
         let blockhash = self.banks_client.get_latest_blockhash().await?;
 
         self.process_transaction(&Transaction::new_signed_with_payer(
-            &[resolver_sdk::sdk::set_resolver(
+            &[resolver_sdk::sdk::submit_appeal(
                 &resolver_program::id(),
                 &Config::find_program_address(&resolver_program::id()).0,
                 &NcnResolverProgramConfig::find_program_address(&resolver_program::id(), ncn).0,
@@ -380,77 +1387,96 @@ impl ResolverProgramClient {
                 operator,
                 slasher,
                 slash_proposal,
-                ncn_slash_proposal_ticket,
-                &ncn_resolver_admin.pubkey(),
-                new_resolver_admin,
+                &SlashProposalAppeal::find_program_address(&resolver_program::id(), slash_proposal)
+                    .0,
+                &operator_admin.pubkey(),
+                evidence_hash,
+                metadata_uri,
             )],
-            Some(&ncn_resolver_admin.pubkey()),
-            &[ncn_resolver_admin],
+            Some(&operator_admin.pubkey()),
+            &[operator_admin],
             blockhash,
         ))
         .await
     }
 
-    pub async fn do_veto_slash(
+    pub async fn require_resolver_decision(
         &mut self,
-        ncn: &Pubkey,
+        ncn_root: &NcnRoot,
         operator: &Pubkey,
         slasher_root: &SlasherRoot,
-        resolver_root: &ResolverRoot,
+        slash_proposal_root: &SlashProposalRoot,
     ) -> TestResult<()> {
-        let slash_proposal = SlashProposal::find_program_address(
-            &resolver_program::id(),
-            &ncn,
-            &operator,
-            &slasher_root.slasher_pubkey,
-        )
-        .0;
-        let ncn_slash_proposal_ticket = NcnSlashProposalTicket::find_program_address(
-            &resolver_program::id(),
-            ncn,
-            &slash_proposal,
-        )
-        .0;
-
-        self.veto_slash(
-            ncn,
-            operator,
-            &slasher_root.slasher_pubkey,
-            &resolver_root.resolver_pubkey,
-            &slash_proposal,
-            &ncn_slash_proposal_ticket,
-            &resolver_root.resolver_admin,
-        )
+        let ncn = ncn_root.ncn_pubkey;
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[resolver_sdk::sdk::require_resolver_decision(
+                &resolver_program::id(),
+                &Config::find_program_address(&resolver_program::id()).0,
+                &NcnResolverProgramConfig::find_program_address(&resolver_program::id(), &ncn).0,
+                &ncn,
+                operator,
+                &slasher_root.slasher_pubkey,
+                &slash_proposal_root.slash_proposal_pubkey,
+                &slash_proposal_root.ncn_slash_proposal_ticket_pubkey,
+                &ncn_root.ncn_admin.pubkey(),
+            )],
+            Some(&self.payer.pubkey()),
+            &[&self.payer, &ncn_root.ncn_admin],
+            blockhash,
+        ))
         .await
     }
 
-    async fn veto_slash(
+    /// Penalizes the resolver, paying the penalty to the ATA of the NCN bond recipient
+    pub async fn penalize_resolver(
         &mut self,
-        ncn: &Pubkey,
+        ncn_root: &NcnRoot,
         operator: &Pubkey,
-        slasher: &Pubkey,
+        slasher_root: &SlasherRoot,
+        slash_proposal_root: &SlashProposalRoot,
         resolver: &Pubkey,
-        slash_proposal: &Pubkey,
-        ncn_slash_proposal_ticket: &Pubkey,
-        resolver_admin: &Keypair,
     ) -> TestResult<()> {
-        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        let ncn = ncn_root.ncn_pubkey;
+        let ncn_resolver_program_config_pubkey =
+            NcnResolverProgramConfig::find_program_address(&resolver_program::id(), &ncn).0;
+        let ncn_resolver_program_config: NcnResolverProgramConfig = self
+            .get_account(&ncn_resolver_program_config_pubkey)
+            .await?;
+        let resolver_account: Resolver = self.get_account(resolver).await?;
+        let bond_mint = resolver_account.bond_mint;
+        let token_program = self.get_token_program(&bond_mint).await?;
 
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
         self.process_transaction(&Transaction::new_signed_with_payer(
-            &[resolver_sdk::sdk::veto_slash(
+            &[resolver_sdk::sdk::penalize_resolver(
                 &resolver_program::id(),
                 &Config::find_program_address(&resolver_program::id()).0,
-                &NcnResolverProgramConfig::find_program_address(&resolver_program::id(), ncn).0,
-                ncn,
+                &ncn_resolver_program_config_pubkey,
+                &ncn,
                 operator,
-                slasher,
+                &slasher_root.slasher_pubkey,
+                &slash_proposal_root.slash_proposal_pubkey,
+                &slash_proposal_root.ncn_slash_proposal_ticket_pubkey,
                 resolver,
-                slash_proposal,
-                ncn_slash_proposal_ticket,
-                &resolver_admin.pubkey(),
+                &ResolverVote::find_program_address(
+                    &resolver_program::id(),
+                    &slash_proposal_root.slash_proposal_pubkey,
+                    resolver,
+                )
+                .0,
+                &bond_mint,
+                &get_associated_token_address_with_program_id(resolver, &bond_mint, &token_program),
+                &get_associated_token_address_with_program_id(
+                    &ncn_resolver_program_config.bond_recipient(operator),
+                    &bond_mint,
+                    &token_program,
+                ),
+                &token_program,
+                &ncn_root.ncn_admin.pubkey(),
             )],
-            Some(&resolver_admin.pubkey()),
-            &[resolver_admin],
+            Some(&self.payer.pubkey()),
+            &[&self.payer, &ncn_root.ncn_admin],
             blockhash,
         ))
         .await
@@ -462,6 +1488,7 @@ impl ResolverProgramClient {
         operator_pubkey: &Pubkey,
         slasher_root: &SlasherRoot,
         vault_root: &VaultRoot,
+        slash_proposal_root: &SlashProposalRoot,
         resolver: &Pubkey,
     ) -> TestResult<()> {
         let ncn_operator_state_pubkey = NcnOperatorState::find_program_address(
@@ -533,20 +1560,6 @@ impl ResolverProgramClient {
         let slasher_token_account =
             get_associated_token_address(&slasher_root.slasher_pubkey, &vault.supported_mint);
 
-        let slash_proposal = SlashProposal::find_program_address(
-            &resolver_program::id(),
-            &ncn_pubkey,
-            &operator_pubkey,
-            &slasher_root.slasher_pubkey,
-        )
-        .0;
-        let ncn_slash_proposal_ticket = NcnSlashProposalTicket::find_program_address(
-            &resolver_program::id(),
-            ncn_pubkey,
-            &slash_proposal,
-        )
-        .0;
-
         self.execute_slash(
             ncn_pubkey,
             operator_pubkey,
@@ -563,8 +1576,8 @@ impl ResolverProgramClient {
             &vault_token_account,
             &slasher_token_account,
             resolver,
-            &slash_proposal,
-            &ncn_slash_proposal_ticket,
+            &slash_proposal_root.slash_proposal_pubkey,
+            &slash_proposal_root.ncn_slash_proposal_ticket_pubkey,
         )
         .await
     }
@@ -589,6 +1602,34 @@ impl ResolverProgramClient {
         slash_proposal: &Pubkey,
         ncn_slash_proposal_ticket: &Pubkey,
     ) -> TestResult<()> {
+        let bond_accounts = self
+            .slash_proposal_bond_accounts(slash_proposal, false)
+            .await?;
+
+        // Slashed funds routed by the NCN go to the ATAs of the treasury and insurance fund
+        let ncn_resolver_program_config: NcnResolverProgramConfig = self
+            .get_account(
+                &NcnResolverProgramConfig::find_program_address(&resolver_program::id(), ncn).0,
+            )
+            .await?;
+        let vault_account: Vault = self.get_account(vault).await?;
+        let supported_mint = (ncn_resolver_program_config.proceeds_burn_bps() > 0)
+            .then_some(vault_account.supported_mint);
+        let treasury_token_account = (ncn_resolver_program_config.proceeds_treasury_bps() > 0)
+            .then(|| {
+                get_associated_token_address(
+                    &ncn_resolver_program_config.proceeds_treasury,
+                    &vault_account.supported_mint,
+                )
+            });
+        let insurance_fund_token_account =
+            (ncn_resolver_program_config.proceeds_insurance_fund_bps() > 0).then(|| {
+                get_associated_token_address(
+                    &ncn_resolver_program_config.proceeds_insurance_fund,
+                    &vault_account.supported_mint,
+                )
+            });
+
         let blockhash = self.banks_client.get_latest_blockhash().await?;
 
         self.process_transaction(&Transaction::new_signed_with_payer(
@@ -615,6 +1656,13 @@ impl ResolverProgramClient {
                 resolver,
                 slash_proposal,
                 ncn_slash_proposal_ticket,
+                bond_accounts.destination.as_ref(),
+                bond_accounts.slash_proposal_token_account.as_ref(),
+                bond_accounts.mint.as_ref(),
+                bond_accounts.token_program.as_ref(),
+                supported_mint.as_ref(),
+                treasury_token_account.as_ref(),
+                insurance_fund_token_account.as_ref(),
             )],
             Some(&slasher_root.slasher_admin.pubkey()),
             &[&slasher_root.slasher_admin],
@@ -693,6 +1741,50 @@ impl ResolverProgramClient {
         .await
     }
 
+    pub async fn resolver_set_admin(
+        &mut self,
+        resolver_pubkey: &Pubkey,
+        old_admin: &Keypair,
+        new_admin: &Keypair,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[resolver_sdk::sdk::resolver_set_admin(
+                &resolver_program::id(),
+                resolver_pubkey,
+                &old_admin.pubkey(),
+                &new_admin.pubkey(),
+            )],
+            Some(&old_admin.pubkey()),
+            &[old_admin, new_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn resolver_set_secondary_admin(
+        &mut self,
+        resolver_pubkey: &Pubkey,
+        admin: &Keypair,
+        new_admin: &Keypair,
+        resolver_admin_role: ResolverAdminRole,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[resolver_sdk::sdk::resolver_set_secondary_admin(
+                &resolver_program::id(),
+                resolver_pubkey,
+                &admin.pubkey(),
+                &new_admin.pubkey(),
+                resolver_admin_role,
+            )],
+            Some(&admin.pubkey()),
+            &[admin],
+            blockhash,
+        ))
+        .await
+    }
+
     pub async fn delete_slash_proposal(
         &mut self,
         ncn: &Pubkey,
@@ -700,7 +1792,15 @@ impl ResolverProgramClient {
         slasher: &Pubkey,
         slash_proposal: &Pubkey,
         ncn_slash_proposal_ticket: &Pubkey,
+        resolvers: &[Pubkey],
     ) -> TestResult<()> {
+        let bond_accounts = self
+            .slash_proposal_bond_accounts(slash_proposal, false)
+            .await?;
+        let closed_accounts = self
+            .slash_proposal_closed_accounts(slash_proposal, resolvers)
+            .await?;
+        let resolver = self.assigned_resolver(ncn_slash_proposal_ticket).await;
         let blockhash = self.banks_client.get_latest_blockhash().await?;
         self.process_transaction(&Transaction::new_signed_with_payer(
             &[resolver_sdk::sdk::delete_slash_proposal(
@@ -712,6 +1812,12 @@ impl ResolverProgramClient {
                 slash_proposal,
                 ncn_slash_proposal_ticket,
                 &self.payer.pubkey(),
+                bond_accounts.destination.as_ref(),
+                bond_accounts.slash_proposal_token_account.as_ref(),
+                bond_accounts.mint.as_ref(),
+                bond_accounts.token_program.as_ref(),
+                resolver.as_ref(),
+                &closed_accounts,
             )],
             Some(&self.payer.pubkey()),
             &[&self.payer],
@@ -720,13 +1826,205 @@ impl ResolverProgramClient {
         .await
     }
 
-    pub async fn process_transaction(&mut self, tx: &Transaction) -> TestResult<()> {
-        self.banks_client
-            .process_transaction_with_preflight_and_commitment(
-                tx.clone(),
-                CommitmentLevel::Processed,
+    pub async fn do_cancel_slash_proposal(
+        &mut self,
+        ncn: &Pubkey,
+        operator: &Pubkey,
+        slasher_root: &SlasherRoot,
+        slash_proposal_root: &SlashProposalRoot,
+    ) -> TestResult<()> {
+        self.cancel_slash_proposal(
+            ncn,
+            operator,
+            &slasher_root.slasher_pubkey,
+            &slash_proposal_root.slash_proposal_pubkey,
+            &slash_proposal_root.ncn_slash_proposal_ticket_pubkey,
+            &slasher_root.slasher_admin,
+            &[],
+        )
+        .await
+    }
+
+    pub async fn cancel_slash_proposal(
+        &mut self,
+        ncn: &Pubkey,
+        operator: &Pubkey,
+        slasher: &Pubkey,
+        slash_proposal: &Pubkey,
+        ncn_slash_proposal_ticket: &Pubkey,
+        slasher_admin: &Keypair,
+        resolvers: &[Pubkey],
+    ) -> TestResult<()> {
+        let closed_accounts = self
+            .slash_proposal_closed_accounts(slash_proposal, resolvers)
+            .await?;
+        self.cancel_slash_proposal_closing(
+            ncn,
+            operator,
+            slasher,
+            slash_proposal,
+            ncn_slash_proposal_ticket,
+            slasher_admin,
+            &closed_accounts,
+        )
+        .await
+    }
+
+    /// Cancels a slash proposal closing only `closed_accounts` along with it
+    #[allow(clippy::too_many_arguments)]
+    pub async fn cancel_slash_proposal_closing(
+        &mut self,
+        ncn: &Pubkey,
+        operator: &Pubkey,
+        slasher: &Pubkey,
+        slash_proposal: &Pubkey,
+        ncn_slash_proposal_ticket: &Pubkey,
+        slasher_admin: &Keypair,
+        closed_accounts: &[(Pubkey, Pubkey)],
+    ) -> TestResult<()> {
+        let bond_accounts = self
+            .slash_proposal_bond_accounts(slash_proposal, false)
+            .await?;
+        let resolver = self.assigned_resolver(ncn_slash_proposal_ticket).await;
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[resolver_sdk::sdk::cancel_slash_proposal(
+                &resolver_program::id(),
+                &Config::find_program_address(&resolver_program::id()).0,
+                ncn,
+                operator,
+                slasher,
+                slash_proposal,
+                ncn_slash_proposal_ticket,
+                &slasher_admin.pubkey(),
+                bond_accounts.destination.as_ref(),
+                bond_accounts.slash_proposal_token_account.as_ref(),
+                bond_accounts.mint.as_ref(),
+                bond_accounts.token_program.as_ref(),
+                resolver.as_ref(),
+                closed_accounts,
+            )],
+            Some(&self.payer.pubkey()),
+            &[&self.payer, slasher_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    /// Returns the resolver assigned to a slash proposal, if any
+    async fn assigned_resolver(&mut self, ncn_slash_proposal_ticket: &Pubkey) -> Option<Pubkey> {
+        self.get_account::<NcnSlashProposalTicket>(ncn_slash_proposal_ticket)
+            .await
+            .ok()
+            .filter(|ncn_slash_proposal_ticket| ncn_slash_proposal_ticket.resolver_assigned())
+            .map(|ncn_slash_proposal_ticket| ncn_slash_proposal_ticket.resolver)
+    }
+
+    /// Collects the votes `resolvers` cast on a slash proposal and its appeal, if any, with the
+    /// admin that paid for them, to close along with the proposal
+    async fn slash_proposal_closed_accounts(
+        &mut self,
+        slash_proposal: &Pubkey,
+        resolvers: &[Pubkey],
+    ) -> TestResult<Vec<(Pubkey, Pubkey)>> {
+        let mut closed_accounts = Vec::new();
+        for resolver in resolvers {
+            let resolver_vote_pubkey = ResolverVote::find_program_address(
+                &resolver_program::id(),
+                slash_proposal,
+                resolver,
             )
+            .0;
+            if let Ok(resolver_vote) = self
+                .get_account::<ResolverVote>(&resolver_vote_pubkey)
+                .await
+            {
+                closed_accounts.push((resolver_vote_pubkey, resolver_vote.payer));
+            }
+        }
+
+        let slash_proposal_appeal_pubkey =
+            SlashProposalAppeal::find_program_address(&resolver_program::id(), slash_proposal).0;
+        if let Ok(slash_proposal_appeal) = self
+            .get_account::<SlashProposalAppeal>(&slash_proposal_appeal_pubkey)
+            .await
+        {
+            closed_accounts.push((slash_proposal_appeal_pubkey, slash_proposal_appeal.payer));
+        }
+
+        Ok(closed_accounts)
+    }
+
+    /// Derives the accounts paying out the bond held by a slash proposal, to the bond recipient
+    /// when `forfeit` is set and back to the depositor otherwise
+    async fn slash_proposal_bond_accounts(
+        &mut self,
+        slash_proposal: &Pubkey,
+        forfeit: bool,
+    ) -> TestResult<BondAccounts> {
+        // Closed or never created proposals have no bond to move
+        let Ok(slash_proposal_account) = self.get_account::<SlashProposal>(slash_proposal).await
+        else {
+            return Ok(BondAccounts::default());
+        };
+        // The token account of an SPL bond is passed even once paid out, to close it
+        let bond_mint = slash_proposal_account.bond_mint;
+        if slash_proposal_account.bond_amount() == 0 && bond_mint.eq(&Pubkey::default()) {
+            return Ok(BondAccounts::default());
+        }
+
+        let owner = if forfeit {
+            slash_proposal_account.bond_recipient
+        } else {
+            slash_proposal_account.bond_depositor
+        };
+        if bond_mint.eq(&Pubkey::default()) {
+            return Ok(BondAccounts {
+                destination: Some(owner),
+                ..BondAccounts::default()
+            });
+        }
+
+        let token_program = self.get_token_program(&bond_mint).await?;
+        Ok(BondAccounts {
+            destination: Some(get_associated_token_address_with_program_id(
+                &owner,
+                &bond_mint,
+                &token_program,
+            )),
+            slash_proposal_token_account: Some(get_associated_token_address_with_program_id(
+                slash_proposal,
+                &bond_mint,
+                &token_program,
+            )),
+            mint: Some(bond_mint),
+            token_program: Some(token_program),
+        })
+    }
+
+    /// Returns the token program owning the mint
+    async fn get_token_program(&mut self, mint: &Pubkey) -> TestResult<Pubkey> {
+        Ok(self
+            .banks_client
+            .get_account(*mint)
+            .await?
+            .ok_or(TestError::AccountNotFound)?
+            .owner)
+    }
+
+    pub async fn process_transaction(&mut self, tx: &Transaction) -> TestResult<()> {
+        let result = self
+            .banks_client
+            .process_transaction_with_metadata(tx.clone())
             .await?;
+        result.result.map_err(BanksClientError::TransactionError)?;
+
+        let logs = result
+            .metadata
+            .map(|metadata| metadata.log_messages)
+            .unwrap_or_default();
+        self.events = ResolverEvent::from_logs(&logs);
+
         Ok(())
     }
 }
@@ -0,0 +1,258 @@
+#[cfg(test)]
+mod tests {
+    use resolver_core::slash_proposal::{SlashProposal, SlashProposalStatus};
+    use resolver_sdk::error::ResolverError;
+
+    use crate::{
+        fixtures::{
+            fixture::{ConfiguredVault, TestBuilder},
+            resolver_client::assert_resolver_error,
+        },
+        resolver::{MAX_SLASH_AMOUNT, REQUIRE_RESOLVER_ASSIGNMENT},
+    };
+
+    #[tokio::test]
+    async fn test_approve_slash_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let deposit_fee_bps = 0;
+        let withdrawal_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![MAX_SLASH_AMOUNT];
+
+        let ConfiguredVault {
+            vault_program_client: _,
+            restaking_program_client: _,
+            vault_config_admin: _,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+
+        let resolver_root = fixture.setup_active_resolver(&ncn_root).await.unwrap();
+
+        let slash_proposal_root = resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+            )
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .do_set_resolver(
+                &ncn_root,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &slash_proposal_root,
+                &resolver_root.resolver_pubkey,
+            )
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .do_approve_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &slash_proposal_root,
+                &resolver_root,
+            )
+            .await
+            .unwrap();
+
+        let slash_proposal: SlashProposal = resolver_program_client
+            .get_account(&slash_proposal_root.slash_proposal_pubkey)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            slash_proposal.status().unwrap(),
+            SlashProposalStatus::Approved
+        );
+
+        // An approved proposal can no longer be vetoed
+        let test_result = resolver_program_client
+            .do_veto_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &slash_proposal_root,
+                &resolver_root,
+            )
+            .await;
+
+        assert_resolver_error(test_result, ResolverError::SlashProposalCannotVeto);
+    }
+
+    #[tokio::test]
+    async fn test_approve_slash_resolver_not_assigned_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let deposit_fee_bps = 0;
+        let withdrawal_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![MAX_SLASH_AMOUNT];
+
+        let ConfiguredVault {
+            vault_program_client: _,
+            restaking_program_client: _,
+            vault_config_admin: _,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+
+        let resolver_root = fixture.setup_active_resolver(&ncn_root).await.unwrap();
+
+        let slash_proposal_root = resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+            )
+            .await
+            .unwrap();
+
+        let test_result = resolver_program_client
+            .do_approve_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &slash_proposal_root,
+                &resolver_root,
+            )
+            .await;
+
+        assert_resolver_error(test_result, ResolverError::SlashProposalResolverInvalid);
+    }
+    #[tokio::test]
+    async fn test_approve_slash_vetoed_below_threshold_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let deposit_fee_bps = 0;
+        let withdrawal_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![MAX_SLASH_AMOUNT];
+        let veto_threshold = 2;
+
+        let ConfiguredVault {
+            vault_program_client: _,
+            restaking_program_client: _,
+            vault_config_admin: _,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators_and_resolver_policy(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+                veto_threshold,
+                REQUIRE_RESOLVER_ASSIGNMENT,
+            )
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+
+        let assigned_resolver_root = fixture.setup_active_resolver(&ncn_root).await.unwrap();
+        let vetoing_resolver_root = fixture.setup_active_resolver(&ncn_root).await.unwrap();
+
+        let slash_proposal_root = resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+            )
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .do_set_resolver(
+                &ncn_root,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &slash_proposal_root,
+                &assigned_resolver_root.resolver_pubkey,
+            )
+            .await
+            .unwrap();
+
+        // A single veto doesn't reach the threshold, but still blocks an early approval
+        resolver_program_client
+            .do_veto_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &slash_proposal_root,
+                &vetoing_resolver_root,
+            )
+            .await
+            .unwrap();
+
+        let test_result = resolver_program_client
+            .do_approve_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &slash_proposal_root,
+                &assigned_resolver_root,
+            )
+            .await;
+
+        assert_resolver_error(test_result, ResolverError::SlashProposalHasVetoes);
+
+        let slash_proposal: SlashProposal = resolver_program_client
+            .get_account(&slash_proposal_root.slash_proposal_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(
+            slash_proposal.status().unwrap(),
+            SlashProposalStatus::ResolverAssigned
+        );
+    }
+}
@@ -0,0 +1,252 @@
+#[cfg(test)]
+mod tests {
+    use resolver_core::{
+        ncn_slash_proposal_ticket::NcnSlashProposalTicket, resolver_vote::ResolverVote,
+        slash_proposal::SlashProposal,
+    };
+    use resolver_sdk::error::ResolverError;
+    use solana_sdk::signer::Signer;
+
+    use crate::{
+        fixtures::{
+            fixture::{ConfiguredVault, TestBuilder},
+            resolver_client::assert_resolver_error,
+        },
+        resolver::{MAX_SLASH_AMOUNT, REQUIRE_RESOLVER_ASSIGNMENT},
+    };
+
+    #[tokio::test]
+    async fn test_cancel_slash_proposal_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let deposit_fee_bps = 0;
+        let withdrawal_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![MAX_SLASH_AMOUNT];
+
+        let ConfiguredVault {
+            vault_program_client: _,
+            restaking_program_client: _,
+            vault_config_admin: _,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+
+        let slash_proposal_root = resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+            )
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .do_cancel_slash_proposal(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &slash_proposal_root,
+            )
+            .await
+            .unwrap();
+
+        assert!(resolver_program_client
+            .get_account::<SlashProposal>(&slash_proposal_root.slash_proposal_pubkey)
+            .await
+            .is_err());
+        assert!(resolver_program_client
+            .get_account::<NcnSlashProposalTicket>(
+                &slash_proposal_root.ncn_slash_proposal_ticket_pubkey
+            )
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_cancel_slash_proposal_vetoed_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let deposit_fee_bps = 0;
+        let withdrawal_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![MAX_SLASH_AMOUNT];
+
+        let ConfiguredVault {
+            vault_program_client: _,
+            restaking_program_client: _,
+            vault_config_admin: _,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+
+        let resolver_root = fixture.setup_active_resolver(&ncn_root).await.unwrap();
+
+        let slash_proposal_root = resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+            )
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .do_veto_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &slash_proposal_root,
+                &resolver_root,
+            )
+            .await
+            .unwrap();
+
+        let test_result = resolver_program_client
+            .do_cancel_slash_proposal(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &slash_proposal_root,
+            )
+            .await;
+
+        assert_resolver_error(test_result, ResolverError::SlashProposalCannotCancel);
+    }
+    #[tokio::test]
+    async fn test_cancel_slash_proposal_closes_resolver_votes_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let deposit_fee_bps = 0;
+        let withdrawal_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![MAX_SLASH_AMOUNT];
+        let veto_threshold = 2;
+
+        let ConfiguredVault {
+            vault_program_client: _,
+            restaking_program_client: _,
+            vault_config_admin: _,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators_and_resolver_policy(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+                veto_threshold,
+                REQUIRE_RESOLVER_ASSIGNMENT,
+            )
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+
+        let resolver_root = fixture.setup_active_resolver(&ncn_root).await.unwrap();
+        // A second active resolver keeps the threshold from being lowered to a single veto
+        fixture.setup_active_resolver(&ncn_root).await.unwrap();
+
+        let slash_proposal_root = resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+            )
+            .await
+            .unwrap();
+
+        // A single veto is below the threshold, so the proposal can still be cancelled
+        resolver_program_client
+            .do_veto_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &slash_proposal_root,
+                &resolver_root,
+            )
+            .await
+            .unwrap();
+
+        let resolver_vote_pubkey = ResolverVote::find_program_address(
+            &resolver_program::id(),
+            &slash_proposal_root.slash_proposal_pubkey,
+            &resolver_root.resolver_pubkey,
+        )
+        .0;
+        let resolver_vote_rent = fixture.get_balance(&resolver_vote_pubkey).await.unwrap();
+        let veto_admin_balance = fixture
+            .get_balance(&resolver_root.resolver_admin.pubkey())
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .cancel_slash_proposal(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root.slasher_pubkey,
+                &slash_proposal_root.slash_proposal_pubkey,
+                &slash_proposal_root.ncn_slash_proposal_ticket_pubkey,
+                &slasher_root.slasher_admin,
+                &[resolver_root.resolver_pubkey],
+            )
+            .await
+            .unwrap();
+
+        assert!(resolver_program_client
+            .get_account::<ResolverVote>(&resolver_vote_pubkey)
+            .await
+            .is_err());
+        assert_eq!(
+            fixture
+                .get_balance(&resolver_root.resolver_admin.pubkey())
+                .await
+                .unwrap(),
+            veto_admin_balance + resolver_vote_rent
+        );
+    }
+}
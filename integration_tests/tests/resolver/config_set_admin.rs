@@ -0,0 +1,70 @@
+#[cfg(test)]
+mod tests {
+    use resolver_core::config::Config;
+    use resolver_sdk::{
+        error::ResolverError,
+        event::{AdminRole, ResolverEvent},
+    };
+    use solana_sdk::{signature::Keypair, signer::Signer};
+
+    use crate::fixtures::{fixture::TestBuilder, resolver_client::assert_resolver_error};
+
+    #[tokio::test]
+    async fn test_config_set_admin_ok() {
+        let fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let config_admin = resolver_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let config_pubkey = Config::find_program_address(&resolver_program::id()).0;
+
+        let new_admin = Keypair::new();
+        resolver_program_client
+            .config_set_admin(&config_pubkey, &config_admin, &new_admin)
+            .await
+            .unwrap();
+
+        let config: Config = resolver_program_client
+            .get_account(&config_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(config.admin, new_admin.pubkey());
+
+        assert_eq!(
+            resolver_program_client.events(),
+            &[ResolverEvent::AdminChanged {
+                account: config_pubkey,
+                role: AdminRole::Admin,
+                old_admin: config_admin.pubkey(),
+                new_admin: new_admin.pubkey(),
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_config_set_admin_wrong_admin_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        resolver_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let config_pubkey = Config::find_program_address(&resolver_program::id()).0;
+
+        let wrong_admin = Keypair::new();
+        resolver_program_client
+            ._airdrop(&wrong_admin.pubkey(), 1.0)
+            .await
+            .unwrap();
+
+        let new_admin = Keypair::new();
+        let result = resolver_program_client
+            .config_set_admin(&config_pubkey, &wrong_admin, &new_admin)
+            .await;
+
+        assert_resolver_error(result, ResolverError::ConfigAdminInvalid);
+    }
+}
@@ -0,0 +1,116 @@
+#[cfg(test)]
+mod tests {
+    use resolver_core::config::Config;
+    use resolver_sdk::error::ResolverError;
+    use solana_program::epoch_schedule::DEFAULT_SLOTS_PER_EPOCH;
+    use solana_sdk::{
+        instruction::InstructionError, signature::Keypair, signer::Signer,
+        transaction::TransactionError,
+    };
+
+    use crate::fixtures::{fixture::TestBuilder, resolver_client::assert_resolver_error};
+
+    /// Initializes the restaking, vault and resolver configs, returning the resolver config admin
+    async fn setup_configs(fixture: &TestBuilder) -> Keypair {
+        fixture
+            .restaking_program_client()
+            .do_initialize_config()
+            .await
+            .unwrap();
+        fixture
+            .vault_program_client()
+            .do_initialize_config()
+            .await
+            .unwrap();
+        fixture
+            .resolver_program_client()
+            .do_initialize_config()
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_config_set_parameters_ok() {
+        let fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let config_admin = setup_configs(&fixture).await;
+        let config_pubkey = Config::find_program_address(&resolver_program::id()).0;
+
+        resolver_program_client
+            .config_set_parameters(
+                &config_pubkey,
+                &config_admin,
+                Some(jito_restaking_program::id()),
+                Some(jito_vault_program::id()),
+            )
+            .await
+            .unwrap();
+
+        let jito_vault_config = fixture
+            .vault_program_client()
+            .get_config(
+                &jito_vault_core::config::Config::find_program_address(&jito_vault_program::id()).0,
+            )
+            .await
+            .unwrap();
+        let config: Config = resolver_program_client
+            .get_account(&config_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(config.epoch_length(), jito_vault_config.epoch_length());
+        assert_eq!(config.jito_restaking_program, jito_restaking_program::id());
+        assert_eq!(config.jito_vault_program, jito_vault_program::id());
+    }
+
+    #[tokio::test]
+    async fn test_config_set_parameters_wrong_admin_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        setup_configs(&fixture).await;
+        let config_pubkey = Config::find_program_address(&resolver_program::id()).0;
+
+        let wrong_admin = Keypair::new();
+        resolver_program_client
+            ._airdrop(&wrong_admin.pubkey(), 1.0)
+            .await
+            .unwrap();
+
+        let result = resolver_program_client
+            .config_set_parameters(&config_pubkey, &wrong_admin, None, None)
+            .await;
+
+        assert_resolver_error(result, ResolverError::ConfigAdminInvalid);
+
+        let config: Config = resolver_program_client
+            .get_account(&config_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(config.epoch_length(), DEFAULT_SLOTS_PER_EPOCH);
+    }
+
+    #[tokio::test]
+    async fn test_config_set_parameters_program_without_config_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let config_admin = setup_configs(&fixture).await;
+        let config_pubkey = Config::find_program_address(&resolver_program::id()).0;
+
+        // The vault config isn't owned by the restaking program
+        let result = resolver_program_client
+            .config_set_parameters(
+                &config_pubkey,
+                &config_admin,
+                None,
+                Some(jito_restaking_program::id()),
+            )
+            .await;
+
+        assert_eq!(
+            result.unwrap_err().to_transaction_error().unwrap(),
+            TransactionError::InstructionError(0, InstructionError::InvalidAccountOwner)
+        );
+    }
+}
@@ -4,9 +4,14 @@ mod tests {
         ncn_slash_proposal_ticket::NcnSlashProposalTicket, slash_proposal::SlashProposal,
     };
 
+    use resolver_sdk::{error::ResolverError, event::ResolverEvent};
+
     use crate::{
-        fixtures::fixture::{ConfiguredVault, TestBuilder},
-        resolver::MAX_SLASH_AMOUNT,
+        fixtures::{
+            fixture::{ConfiguredVault, TestBuilder},
+            resolver_client::assert_resolver_error,
+        },
+        resolver::{DELETE_SLASH_PROPOSAL_DURATION, MAX_SLASH_AMOUNT, VETO_DURATION},
     };
 
     #[tokio::test]
@@ -24,7 +29,7 @@ mod tests {
             vault_program_client: _,
             restaking_program_client: _,
             vault_config_admin: _,
-            vault_root: _,
+            vault_root,
             ncn_root,
             operator_roots,
             slashers_amounts,
@@ -42,41 +47,27 @@ mod tests {
 
         let slasher_root = &slashers_amounts[0].0;
 
-        let resolver_root = resolver_program_client
-            .do_initialize_resolver(&ncn_root)
-            .await
-            .unwrap();
+        let resolver_root = fixture.setup_active_resolver(&ncn_root).await.unwrap();
 
-        resolver_program_client
+        let slash_proposal_root = resolver_program_client
             .do_propose_slash(
                 &ncn_root.ncn_pubkey,
                 &operator_roots[0].operator_pubkey,
                 &slasher_root,
+                &vault_root.vault_pubkey,
                 100,
             )
             .await
             .unwrap();
-
-        let slash_proposal_pubkey = SlashProposal::find_program_address(
-            &resolver_program::id(),
-            &ncn_root.ncn_pubkey,
-            &operator_roots[0].operator_pubkey,
-            &slasher_root.slasher_pubkey,
-        )
-        .0;
-
-        let ncn_slash_proposal_ticket_pubkey = NcnSlashProposalTicket::find_program_address(
-            &resolver_program::id(),
-            &ncn_root.ncn_pubkey,
-            &slash_proposal_pubkey,
-        )
-        .0;
+        let slash_proposal_pubkey = slash_proposal_root.slash_proposal_pubkey;
+        let ncn_slash_proposal_ticket_pubkey = slash_proposal_root.ncn_slash_proposal_ticket_pubkey;
 
         resolver_program_client
             .do_set_resolver(
                 &ncn_root,
                 &operator_roots[0].operator_pubkey,
                 &slasher_root,
+                &slash_proposal_root,
                 &resolver_root.resolver_pubkey,
             )
             .await
@@ -87,6 +78,7 @@ mod tests {
                 &ncn_root.ncn_pubkey,
                 &operator_roots[0].operator_pubkey,
                 &slasher_root,
+                &slash_proposal_root,
                 &resolver_root,
             )
             .await
@@ -101,6 +93,107 @@ mod tests {
                 &slasher_root.slasher_pubkey,
                 &slash_proposal_pubkey,
                 &ncn_slash_proposal_ticket_pubkey,
+                &[resolver_root.resolver_pubkey],
+            )
+            .await
+            .unwrap();
+
+        assert!(resolver_program_client
+            .get_account::<SlashProposal>(&slash_proposal_pubkey)
+            .await
+            .is_err());
+        assert!(resolver_program_client
+            .get_account::<NcnSlashProposalTicket>(&ncn_slash_proposal_ticket_pubkey)
+            .await
+            .is_err());
+
+        assert_eq!(
+            resolver_program_client.events(),
+            &[ResolverEvent::ProposalDeleted {
+                slash_proposal: slash_proposal_pubkey,
+                expired: false,
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_delete_slash_proposal_expired_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let deposit_fee_bps = 0;
+        let withdrawal_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![MAX_SLASH_AMOUNT];
+
+        let ConfiguredVault {
+            vault_program_client: _,
+            restaking_program_client: _,
+            vault_config_admin: _,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+
+        let slash_proposal_root = resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+            )
+            .await
+            .unwrap();
+        let slash_proposal_pubkey = slash_proposal_root.slash_proposal_pubkey;
+        let ncn_slash_proposal_ticket_pubkey = slash_proposal_root.ncn_slash_proposal_ticket_pubkey;
+
+        // A pending proposal can only be deleted once it expired
+        fixture.warp_slot_incremental(VETO_DURATION).await.unwrap();
+
+        let test_result = resolver_program_client
+            .delete_slash_proposal(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root.slasher_pubkey,
+                &slash_proposal_pubkey,
+                &ncn_slash_proposal_ticket_pubkey,
+                &[],
+            )
+            .await;
+
+        assert_resolver_error(
+            test_result,
+            ResolverError::SlashProposalDeletePeriodNotEnded,
+        );
+
+        fixture
+            .warp_slot_incremental(DELETE_SLASH_PROPOSAL_DURATION)
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .delete_slash_proposal(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root.slasher_pubkey,
+                &slash_proposal_pubkey,
+                &ncn_slash_proposal_ticket_pubkey,
+                &[],
             )
             .await
             .unwrap();
@@ -113,5 +206,74 @@ mod tests {
             .get_account::<NcnSlashProposalTicket>(&ncn_slash_proposal_ticket_pubkey)
             .await
             .is_err());
+
+        assert_eq!(
+            resolver_program_client.events(),
+            &[ResolverEvent::ProposalDeleted {
+                slash_proposal: slash_proposal_pubkey,
+                expired: true,
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_delete_slash_proposal_vote_missing_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let ConfiguredVault {
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+        let resolver_root = fixture.setup_active_resolver(&ncn_root).await.unwrap();
+
+        let slash_proposal_root = resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+            )
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .do_veto_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                slasher_root,
+                &slash_proposal_root,
+                &resolver_root,
+            )
+            .await
+            .unwrap();
+
+        fixture
+            .warp_slot_incremental(DELETE_SLASH_PROPOSAL_DURATION)
+            .await
+            .unwrap();
+
+        // The ResolverVote would be left behind without a proposal to close it with
+        let result = resolver_program_client
+            .delete_slash_proposal(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root.slasher_pubkey,
+                &slash_proposal_root.slash_proposal_pubkey,
+                &slash_proposal_root.ncn_slash_proposal_ticket_pubkey,
+                &[],
+            )
+            .await;
+
+        assert_resolver_error(result, ResolverError::SlashProposalAccountsMissing);
     }
 }
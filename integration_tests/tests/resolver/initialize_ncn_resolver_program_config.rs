@@ -1,11 +1,19 @@
 #[cfg(test)]
 mod tests {
-    use resolver_core::ncn_resolver_program_config::NcnResolverProgramConfig;
-    use solana_sdk::signer::Signer;
+    use jito_restaking_sdk::instruction::NcnAdminRole;
+    use resolver_core::{config::Config, ncn_resolver_program_config::NcnResolverProgramConfig};
+    use resolver_sdk::error::ResolverError;
+    use solana_sdk::{signature::Keypair, signer::Signer};
 
     use crate::{
-        fixtures::fixture::{ConfiguredVault, TestBuilder},
-        resolver::{DELETE_SLASH_PROPOSAL_DURATION, MAX_SLASH_AMOUNT, VETO_DURATION},
+        fixtures::{
+            fixture::{ConfiguredVault, TestBuilder},
+            resolver_client::assert_resolver_error,
+        },
+        resolver::{
+            DELETE_SLASH_PROPOSAL_DURATION, MAX_SLASH_AMOUNT, REQUIRE_RESOLVER_ASSIGNMENT,
+            VETO_DURATION, VETO_THRESHOLD,
+        },
     };
 
     #[tokio::test]
@@ -59,5 +67,110 @@ mod tests {
             DELETE_SLASH_PROPOSAL_DURATION
         );
         assert_eq!(ncn_resolver_program_config.resolver_count(), 0);
+        assert_eq!(ncn_resolver_program_config.veto_threshold(), VETO_THRESHOLD);
+        assert_eq!(
+            ncn_resolver_program_config.require_resolver_assignment(),
+            REQUIRE_RESOLVER_ASSIGNMENT
+        );
+    }
+
+    #[tokio::test]
+    async fn test_initialize_ncn_resolver_program_config_slasher_admin_ok() {
+        let fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        resolver_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+
+        let slasher_admin = Keypair::new();
+        restaking_program_client
+            ._airdrop(&slasher_admin.pubkey(), 1.0)
+            .await
+            .unwrap();
+        restaking_program_client
+            .ncn_set_secondary_admin(
+                &ncn_root.ncn_pubkey,
+                &ncn_root.ncn_admin,
+                &slasher_admin,
+                NcnAdminRole::SlasherAdmin,
+            )
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .do_initialize_ncn_resolver_program_config(
+                &Config::find_program_address(&resolver_program::id()).0,
+                &ncn_root.ncn_pubkey,
+                &slasher_admin,
+                VETO_DURATION,
+                DELETE_SLASH_PROPOSAL_DURATION,
+                VETO_THRESHOLD,
+                REQUIRE_RESOLVER_ASSIGNMENT,
+            )
+            .await
+            .unwrap();
+
+        let ncn_resolver_program_config: NcnResolverProgramConfig = resolver_program_client
+            .get_account(
+                &NcnResolverProgramConfig::find_program_address(
+                    &resolver_program::id(),
+                    &ncn_root.ncn_pubkey,
+                )
+                .0,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            ncn_resolver_program_config.resolver_admin,
+            slasher_admin.pubkey(),
+        );
+    }
+
+    #[tokio::test]
+    async fn test_initialize_ncn_resolver_program_config_not_ncn_admin_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        resolver_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+
+        let attacker = Keypair::new();
+        resolver_program_client
+            ._airdrop(&attacker.pubkey(), 1.0)
+            .await
+            .unwrap();
+
+        let result = resolver_program_client
+            .do_initialize_ncn_resolver_program_config(
+                &Config::find_program_address(&resolver_program::id()).0,
+                &ncn_root.ncn_pubkey,
+                &attacker,
+                VETO_DURATION,
+                DELETE_SLASH_PROPOSAL_DURATION,
+                VETO_THRESHOLD,
+                REQUIRE_RESOLVER_ASSIGNMENT,
+            )
+            .await;
+
+        assert_resolver_error(result, ResolverError::NcnAdminInvalid);
     }
 }
@@ -7,7 +7,10 @@ mod tests {
 
     use crate::{
         fixtures::fixture::TestBuilder,
-        resolver::{DELETE_SLASH_PROPOSAL_DURATION, VETO_DURATION},
+        resolver::{
+            DELETE_SLASH_PROPOSAL_DURATION, REQUIRE_RESOLVER_ASSIGNMENT, VETO_DURATION,
+            VETO_THRESHOLD,
+        },
     };
 
     #[tokio::test]
@@ -87,6 +90,8 @@ mod tests {
                 &ncn_root.ncn_admin,
                 VETO_DURATION,
                 DELETE_SLASH_PROPOSAL_DURATION,
+                VETO_THRESHOLD,
+                REQUIRE_RESOLVER_ASSIGNMENT,
             )
             .await
             .unwrap();
@@ -104,7 +109,7 @@ mod tests {
             .unwrap();
 
         assert_eq!(resolver.admin, resolver_root.resolver_admin.pubkey());
-        assert_eq!(resolver.index(), 0);
+        assert_eq!(resolver.ncn, ncn_root.ncn_pubkey);
 
         let ncn_resolver_program_config: NcnResolverProgramConfig = resolver_program_client
             .get_account(
@@ -117,6 +122,7 @@ mod tests {
             .await
             .unwrap();
 
-        assert_eq!(ncn_resolver_program_config.resolver_count(), 1);
+        // Initializing a resolver needs no NCN approval, so it isn't added to the NCN registry
+        assert_eq!(ncn_resolver_program_config.resolver_count(), 0);
     }
 }
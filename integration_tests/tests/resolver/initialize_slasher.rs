@@ -49,6 +49,7 @@ mod tests {
             .await
             .unwrap();
 
+        assert_eq!(slasher.ncn, ncn_root.ncn_pubkey);
         assert_eq!(slasher.admin, slasher_root.slasher_admin.pubkey());
         assert_eq!(slasher.index(), ncn.slasher_count());
     }
@@ -1,14 +1,31 @@
+mod amend_slash_amount;
+mod approve_slash;
+mod cancel_slash_proposal;
+mod config_set_admin;
+mod config_set_parameters;
 mod delete_slash_proposal;
 mod execute_slash;
 mod initialize_config;
 mod initialize_ncn_resolver_program_config;
 mod initialize_resolver;
 mod initialize_slasher;
+mod multi_vault_slash;
+mod ncn_resolver_program_config_set_admin;
+mod ncn_resolver_program_config_set_assignment_policy;
+mod ncn_resolver_program_config_set_bond;
+mod ncn_resolver_program_config_set_parameters;
+mod ncn_resolver_program_config_set_proceeds;
+mod ncn_resolver_ticket;
 mod propose_slash;
+mod resolver_bond;
+mod resolver_ncn_ticket;
+mod resolver_set_admin;
+mod resolver_set_secondary_admin;
 mod set_resolver;
 mod slasher_delegate_token_account;
 mod slasher_set_admin;
 mod slasher_set_secondary_admin;
+mod submit_appeal;
 mod veto_slash;
 
 pub(crate) const MINT_AMOUNT: u64 = 100_000;
@@ -16,3 +33,5 @@ pub(crate) const DELEGATION_AMOUNT: u64 = 10_000;
 pub(crate) const MAX_SLASH_AMOUNT: u64 = 100;
 pub(crate) const VETO_DURATION: u64 = 100;
 pub(crate) const DELETE_SLASH_PROPOSAL_DURATION: u64 = 100;
+pub(crate) const VETO_THRESHOLD: u64 = 1;
+pub(crate) const REQUIRE_RESOLVER_ASSIGNMENT: bool = false;
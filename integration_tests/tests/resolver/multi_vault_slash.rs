@@ -0,0 +1,467 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_core::{
+        vault::Vault, vault_ncn_slasher_operator_ticket::VaultNcnSlasherOperatorTicket,
+        vault_ncn_slasher_ticket::VaultNcnSlasherTicket,
+    };
+    use resolver_core::slash_proposal::{SlashProposal, SlashProposalStatus};
+    use resolver_sdk::{error::ResolverError, event::ResolverEvent, instruction::SlashAllocation};
+    use solana_sdk::{signature::Keypair, signer::Signer};
+    use spl_associated_token_account::get_associated_token_address;
+
+    use crate::{
+        fixtures::{
+            fixture::{ConfiguredVault, TestBuilder},
+            resolver_client::{
+                assert_resolver_error, ResolverProgramClient, ResolverRoot, SlashProposalRoot,
+                SlasherRoot,
+            },
+            restaking_client::{NcnRoot, OperatorRoot},
+            vault_client::{VaultProgramClient, VaultRoot},
+        },
+        resolver::{
+            DELEGATION_AMOUNT, DELETE_SLASH_PROPOSAL_DURATION, MAX_SLASH_AMOUNT, MINT_AMOUNT,
+            VETO_DURATION,
+        },
+    };
+
+    struct MultiVaultSlash {
+        fixture: TestBuilder,
+        resolver_program_client: ResolverProgramClient,
+        vault_roots: Vec<VaultRoot>,
+        ncn_root: NcnRoot,
+        operator_root: OperatorRoot,
+        slasher_root: SlasherRoot,
+        resolver_root: ResolverRoot,
+    }
+
+    /// Sets up two vaults delegating `delegation_amounts` to the same operator, ready to be
+    /// slashed by the slasher
+    async fn setup_multi_vault_slash(delegation_amounts: [u64; 2]) -> MultiVaultSlash {
+        let mut fixture = TestBuilder::new().await;
+        let resolver_program_client = fixture.resolver_program_client();
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_config_admin,
+            vault_root,
+            ncn_root,
+            mut operator_roots,
+            mut slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+        let other_vault_root = fixture
+            .setup_additional_vault(&ncn_root, &operator_roots, &slashers_amounts)
+            .await
+            .unwrap();
+        let operator_root = operator_roots.remove(0);
+        let (slasher_root, _) = slashers_amounts.remove(0);
+        let vault_roots = vec![vault_root, other_vault_root];
+
+        for (vault_root, delegation_amount) in vault_roots.iter().zip(delegation_amounts) {
+            let depositor = Keypair::new();
+            vault_program_client
+                .configure_depositor(vault_root, &depositor.pubkey(), MINT_AMOUNT)
+                .await
+                .unwrap();
+            vault_program_client
+                .do_mint_to(vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+                .await
+                .unwrap();
+            if delegation_amount > 0 {
+                vault_program_client
+                    .do_add_delegation(
+                        vault_root,
+                        &operator_root.operator_pubkey,
+                        delegation_amount,
+                    )
+                    .await
+                    .unwrap();
+            }
+        }
+
+        // Activate the resolver before the vault epoch warp so the vaults stay up to date
+        let resolver_root = fixture.setup_active_resolver(&ncn_root).await.unwrap();
+
+        let config = vault_program_client
+            .get_config(
+                &jito_vault_core::config::Config::find_program_address(&jito_vault_program::id()).0,
+            )
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(2 * config.epoch_length())
+            .await
+            .unwrap();
+        let epoch = fixture.get_current_slot().await.unwrap() / config.epoch_length();
+
+        for vault_root in vault_roots.iter() {
+            vault_program_client
+                .do_full_vault_update(&vault_root.vault_pubkey, &[operator_root.operator_pubkey])
+                .await
+                .unwrap();
+            setup_vault_slash(
+                &mut fixture,
+                &mut vault_program_client,
+                &vault_config_admin,
+                vault_root,
+                &ncn_root,
+                &operator_root,
+                &slasher_root,
+                epoch,
+            )
+            .await;
+        }
+
+        MultiVaultSlash {
+            fixture,
+            resolver_program_client,
+            vault_roots,
+            ncn_root,
+            operator_root,
+            slasher_root,
+            resolver_root,
+        }
+    }
+
+    /// Creates the slasher token account and the epoch slasher ticket of the vault
+    #[allow(clippy::too_many_arguments)]
+    async fn setup_vault_slash(
+        fixture: &mut TestBuilder,
+        vault_program_client: &mut VaultProgramClient,
+        vault_config_admin: &Keypair,
+        vault_root: &VaultRoot,
+        ncn_root: &NcnRoot,
+        operator_root: &OperatorRoot,
+        slasher_root: &SlasherRoot,
+        epoch: u64,
+    ) {
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        fixture
+            .create_ata(&vault.supported_mint, &slasher_root.slasher_pubkey)
+            .await
+            .unwrap();
+
+        vault_program_client
+            .initialize_vault_ncn_slasher_operator_ticket(
+                &jito_vault_core::config::Config::find_program_address(&jito_vault_program::id()).0,
+                &vault_root.vault_pubkey,
+                &ncn_root.ncn_pubkey,
+                &slasher_root.slasher_pubkey,
+                &operator_root.operator_pubkey,
+                &VaultNcnSlasherTicket::find_program_address(
+                    &jito_vault_program::id(),
+                    &vault_root.vault_pubkey,
+                    &ncn_root.ncn_pubkey,
+                    &slasher_root.slasher_pubkey,
+                )
+                .0,
+                &VaultNcnSlasherOperatorTicket::find_program_address(
+                    &jito_vault_program::id(),
+                    &vault_root.vault_pubkey,
+                    &ncn_root.ncn_pubkey,
+                    &slasher_root.slasher_pubkey,
+                    &operator_root.operator_pubkey,
+                    epoch,
+                )
+                .0,
+                vault_config_admin,
+            )
+            .await
+            .unwrap();
+    }
+
+    impl MultiVaultSlash {
+        async fn propose_and_approve(
+            &mut self,
+            slash_amount: u64,
+            allocation: SlashAllocation,
+        ) -> SlashProposalRoot {
+            let vaults: Vec<_> = self.vault_roots.iter().map(|r| r.vault_pubkey).collect();
+            let slash_proposal_root = self
+                .resolver_program_client
+                .do_propose_multi_vault_slash(
+                    &self.ncn_root.ncn_pubkey,
+                    &self.operator_root.operator_pubkey,
+                    &self.slasher_root,
+                    &vaults,
+                    slash_amount,
+                    allocation,
+                )
+                .await
+                .unwrap();
+            self.resolver_program_client
+                .do_set_resolver(
+                    &self.ncn_root,
+                    &self.operator_root.operator_pubkey,
+                    &self.slasher_root,
+                    &slash_proposal_root,
+                    &self.resolver_root.resolver_pubkey,
+                )
+                .await
+                .unwrap();
+            self.resolver_program_client
+                .do_approve_slash(
+                    &self.ncn_root.ncn_pubkey,
+                    &self.operator_root.operator_pubkey,
+                    &self.slasher_root,
+                    &slash_proposal_root,
+                    &self.resolver_root,
+                )
+                .await
+                .unwrap();
+
+            slash_proposal_root
+        }
+
+        async fn slasher_balance(&mut self, vault_root: usize) -> u64 {
+            let vault: Vault = self
+                .resolver_program_client
+                .get_account(&self.vault_roots[vault_root].vault_pubkey)
+                .await
+                .unwrap();
+            let ata = get_associated_token_address(
+                &self.slasher_root.slasher_pubkey,
+                &vault.supported_mint,
+            );
+            self.fixture.get_token_account(&ata).await.unwrap().amount
+        }
+    }
+
+    #[tokio::test]
+    async fn test_multi_vault_slash_pro_rata_ok() {
+        let mut multi_vault_slash =
+            setup_multi_vault_slash([DELEGATION_AMOUNT, DELEGATION_AMOUNT / 4]).await;
+
+        let slash_proposal_root = multi_vault_slash
+            .propose_and_approve(100, SlashAllocation::ProRata)
+            .await;
+
+        let slash_proposal: SlashProposal = multi_vault_slash
+            .resolver_program_client
+            .get_account(&slash_proposal_root.slash_proposal_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(
+            slash_proposal.allocation().unwrap(),
+            SlashAllocation::ProRata
+        );
+        let amounts: Vec<_> = slash_proposal.vaults().iter().map(|v| v.amount()).collect();
+        assert_eq!(amounts, vec![80, 20]);
+
+        // Each vault is slashed by its own execution
+        let MultiVaultSlash {
+            resolver_program_client,
+            vault_roots,
+            ncn_root,
+            operator_root,
+            slasher_root,
+            resolver_root,
+            ..
+        } = &mut multi_vault_slash;
+        resolver_program_client
+            .do_execute_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_root.operator_pubkey,
+                slasher_root,
+                &vault_roots[0],
+                &slash_proposal_root,
+                &resolver_root.resolver_pubkey,
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            resolver_program_client.events().last(),
+            Some(&ResolverEvent::SlashExecuted {
+                slash_proposal: slash_proposal_root.slash_proposal_pubkey,
+                vault: vault_roots[0].vault_pubkey,
+                amount: 80,
+                remaining_amount: 20,
+            })
+        );
+        let slash_proposal: SlashProposal = resolver_program_client
+            .get_account(&slash_proposal_root.slash_proposal_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(
+            slash_proposal.status().unwrap(),
+            SlashProposalStatus::Approved
+        );
+        assert_eq!(slash_proposal.executed_amount(), 80);
+
+        let result = resolver_program_client
+            .do_execute_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_root.operator_pubkey,
+                slasher_root,
+                &vault_roots[0],
+                &slash_proposal_root,
+                &resolver_root.resolver_pubkey,
+            )
+            .await;
+        assert_resolver_error(result, ResolverError::SlashProposalVaultAlreadySlashed);
+
+        resolver_program_client
+            .do_execute_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_root.operator_pubkey,
+                slasher_root,
+                &vault_roots[1],
+                &slash_proposal_root,
+                &resolver_root.resolver_pubkey,
+            )
+            .await
+            .unwrap();
+        let slash_proposal: SlashProposal = resolver_program_client
+            .get_account(&slash_proposal_root.slash_proposal_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(
+            slash_proposal.status().unwrap(),
+            SlashProposalStatus::Executed
+        );
+        assert_eq!(slash_proposal.remaining_amount(), 0);
+
+        assert_eq!(multi_vault_slash.slasher_balance(0).await, 80);
+        assert_eq!(multi_vault_slash.slasher_balance(1).await, 20);
+    }
+
+    #[tokio::test]
+    async fn test_multi_vault_slash_ordered_ok() {
+        let mut multi_vault_slash = setup_multi_vault_slash([60, DELEGATION_AMOUNT]).await;
+
+        let slash_proposal_root = multi_vault_slash
+            .propose_and_approve(100, SlashAllocation::Ordered)
+            .await;
+
+        let slash_proposal: SlashProposal = multi_vault_slash
+            .resolver_program_client
+            .get_account(&slash_proposal_root.slash_proposal_pubkey)
+            .await
+            .unwrap();
+        let amounts: Vec<_> = slash_proposal.vaults().iter().map(|v| v.amount()).collect();
+        assert_eq!(amounts, vec![60, 40]);
+    }
+
+    #[tokio::test]
+    async fn test_multi_vault_slash_duplicate_vault_fails() {
+        let mut multi_vault_slash =
+            setup_multi_vault_slash([DELEGATION_AMOUNT, DELEGATION_AMOUNT]).await;
+
+        let vault = multi_vault_slash.vault_roots[0].vault_pubkey;
+        let result = multi_vault_slash
+            .resolver_program_client
+            .do_propose_multi_vault_slash(
+                &multi_vault_slash.ncn_root.ncn_pubkey,
+                &multi_vault_slash.operator_root.operator_pubkey,
+                &multi_vault_slash.slasher_root,
+                &[vault, vault],
+                100,
+                SlashAllocation::ProRata,
+            )
+            .await;
+
+        assert_resolver_error(result, ResolverError::SlashProposalVaultInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_multi_vault_slash_partial_execution_expires_ok() {
+        let mut multi_vault_slash =
+            setup_multi_vault_slash([DELEGATION_AMOUNT, DELEGATION_AMOUNT / 4]).await;
+
+        let slash_proposal_root = multi_vault_slash
+            .propose_and_approve(100, SlashAllocation::ProRata)
+            .await;
+
+        let MultiVaultSlash {
+            fixture,
+            resolver_program_client,
+            vault_roots,
+            ncn_root,
+            operator_root,
+            slasher_root,
+            resolver_root,
+        } = &mut multi_vault_slash;
+        resolver_program_client
+            .do_execute_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_root.operator_pubkey,
+                slasher_root,
+                &vault_roots[0],
+                &slash_proposal_root,
+                &resolver_root.resolver_pubkey,
+            )
+            .await
+            .unwrap();
+
+        // The slasher can't withdraw a partly executed proposal
+        let result = resolver_program_client
+            .do_cancel_slash_proposal(
+                &ncn_root.ncn_pubkey,
+                &operator_root.operator_pubkey,
+                slasher_root,
+                &slash_proposal_root,
+            )
+            .await;
+        assert_resolver_error(result, ResolverError::SlashProposalCannotCancel);
+
+        // The second vault is never slashed, the proposal expires at its delete deadline
+        fixture
+            .warp_slot_incremental(VETO_DURATION + DELETE_SLASH_PROPOSAL_DURATION)
+            .await
+            .unwrap();
+        resolver_program_client
+            .delete_slash_proposal(
+                &ncn_root.ncn_pubkey,
+                &operator_root.operator_pubkey,
+                &slasher_root.slasher_pubkey,
+                &slash_proposal_root.slash_proposal_pubkey,
+                &slash_proposal_root.ncn_slash_proposal_ticket_pubkey,
+                &[],
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            resolver_program_client.events().last(),
+            Some(&ResolverEvent::ProposalDeleted {
+                slash_proposal: slash_proposal_root.slash_proposal_pubkey,
+                expired: true,
+            })
+        );
+        assert!(resolver_program_client
+            .get_account::<SlashProposal>(&slash_proposal_root.slash_proposal_pubkey)
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_multi_vault_slash_vault_without_delegation_fails() {
+        let mut multi_vault_slash = setup_multi_vault_slash([DELEGATION_AMOUNT, 0]).await;
+
+        let vaults: Vec<_> = multi_vault_slash
+            .vault_roots
+            .iter()
+            .map(|r| r.vault_pubkey)
+            .collect();
+        let result = multi_vault_slash
+            .resolver_program_client
+            .do_propose_multi_vault_slash(
+                &multi_vault_slash.ncn_root.ncn_pubkey,
+                &multi_vault_slash.operator_root.operator_pubkey,
+                &multi_vault_slash.slasher_root,
+                &vaults,
+                100,
+                SlashAllocation::ProRata,
+            )
+            .await;
+
+        assert_resolver_error(result, ResolverError::SlashProposalVaultDelegationEmpty);
+    }
+}
@@ -0,0 +1,158 @@
+#[cfg(test)]
+mod tests {
+    use resolver_core::ncn_resolver_program_config::NcnResolverProgramConfig;
+    use resolver_sdk::{
+        error::ResolverError,
+        event::{AdminRole, ResolverEvent},
+    };
+    use solana_sdk::{signature::Keypair, signer::Signer};
+
+    use crate::{
+        fixtures::{
+            fixture::{ConfiguredVault, TestBuilder},
+            resolver_client::assert_resolver_error,
+        },
+        resolver::MAX_SLASH_AMOUNT,
+    };
+
+    #[tokio::test]
+    async fn test_ncn_resolver_program_config_set_admin_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let deposit_fee_bps = 0;
+        let withdrawal_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![MAX_SLASH_AMOUNT];
+
+        let ConfiguredVault {
+            vault_program_client: _,
+            restaking_program_client: _,
+            vault_config_admin: _,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+        let ncn_resolver_program_config_pubkey = NcnResolverProgramConfig::find_program_address(
+            &resolver_program::id(),
+            &ncn_root.ncn_pubkey,
+        )
+        .0;
+
+        let new_admin = Keypair::new();
+        resolver_program_client
+            .ncn_resolver_program_config_set_admin(
+                &ncn_root.ncn_pubkey,
+                &ncn_root.ncn_admin,
+                &new_admin,
+            )
+            .await
+            .unwrap();
+
+        let ncn_resolver_program_config: NcnResolverProgramConfig = resolver_program_client
+            .get_account(&ncn_resolver_program_config_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(
+            ncn_resolver_program_config.resolver_admin,
+            new_admin.pubkey()
+        );
+
+        assert_eq!(
+            resolver_program_client.events(),
+            &[ResolverEvent::AdminChanged {
+                account: ncn_resolver_program_config_pubkey,
+                role: AdminRole::Admin,
+                old_admin: ncn_root.ncn_admin.pubkey(),
+                new_admin: new_admin.pubkey(),
+            }]
+        );
+
+        // The previous admin can no longer assign resolvers
+        let resolver_root = resolver_program_client
+            .do_initialize_resolver(&ncn_root)
+            .await
+            .unwrap();
+        let slash_proposal_root = resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+            )
+            .await
+            .unwrap();
+
+        let result = resolver_program_client
+            .do_set_resolver(
+                &ncn_root,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &slash_proposal_root,
+                &resolver_root.resolver_pubkey,
+            )
+            .await;
+
+        assert_resolver_error(result, ResolverError::NcnResolverProgramConfigAdminInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_ncn_resolver_program_config_set_admin_wrong_admin_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let deposit_fee_bps = 0;
+        let withdrawal_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![MAX_SLASH_AMOUNT];
+
+        let ConfiguredVault {
+            vault_program_client: _,
+            restaking_program_client: _,
+            vault_config_admin: _,
+            vault_root: _,
+            ncn_root,
+            operator_roots: _,
+            slashers_amounts: _,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        let wrong_admin = Keypair::new();
+        resolver_program_client
+            ._airdrop(&wrong_admin.pubkey(), 1.0)
+            .await
+            .unwrap();
+
+        let new_admin = Keypair::new();
+        let result = resolver_program_client
+            .ncn_resolver_program_config_set_admin(&ncn_root.ncn_pubkey, &wrong_admin, &new_admin)
+            .await;
+
+        assert_resolver_error(result, ResolverError::NcnResolverProgramConfigAdminInvalid);
+    }
+}
@@ -0,0 +1,428 @@
+#[cfg(test)]
+mod tests {
+    use resolver_core::{
+        ncn_resolver_program_config::NcnResolverProgramConfig,
+        ncn_slash_proposal_ticket::NcnSlashProposalTicket,
+        slash_proposal::{SlashProposal, SlashProposalStatus},
+    };
+    use resolver_sdk::{
+        error::ResolverError, event::ResolverEvent, instruction::ResolverAssignmentPolicy,
+    };
+
+    use crate::{
+        fixtures::{
+            fixture::{ConfiguredVault, TestBuilder},
+            resolver_client::assert_resolver_error,
+        },
+        resolver::MAX_SLASH_AMOUNT,
+    };
+
+    #[tokio::test]
+    async fn test_ncn_resolver_program_config_set_assignment_policy_fixed_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let ConfiguredVault {
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+        let resolver_root = fixture.setup_active_resolver(&ncn_root).await.unwrap();
+
+        resolver_program_client
+            .ncn_resolver_program_config_set_assignment_policy(
+                &ncn_root.ncn_pubkey,
+                &ncn_root.ncn_admin,
+                Some(&resolver_root.resolver_pubkey),
+                ResolverAssignmentPolicy::Fixed,
+            )
+            .await
+            .unwrap();
+
+        let ncn_resolver_program_config: NcnResolverProgramConfig = resolver_program_client
+            .get_account(
+                &NcnResolverProgramConfig::find_program_address(
+                    &resolver_program::id(),
+                    &ncn_root.ncn_pubkey,
+                )
+                .0,
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            ncn_resolver_program_config.assignment_policy().unwrap(),
+            ResolverAssignmentPolicy::Fixed
+        );
+        assert_eq!(
+            ncn_resolver_program_config.default_resolver,
+            resolver_root.resolver_pubkey
+        );
+
+        let slash_proposal_root = resolver_program_client
+            .do_propose_slash_with_resolver(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+                &resolver_root.resolver_pubkey,
+            )
+            .await
+            .unwrap();
+
+        let ncn_slash_proposal_ticket: NcnSlashProposalTicket = resolver_program_client
+            .get_account(&slash_proposal_root.ncn_slash_proposal_ticket_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(
+            ncn_slash_proposal_ticket.resolver,
+            resolver_root.resolver_pubkey
+        );
+        let slash_proposal: SlashProposal = resolver_program_client
+            .get_account(&slash_proposal_root.slash_proposal_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(
+            slash_proposal.status().unwrap(),
+            SlashProposalStatus::ResolverAssigned
+        );
+        assert_eq!(
+            resolver_program_client.events().last(),
+            Some(&ResolverEvent::ResolverAssigned {
+                slash_proposal: slash_proposal_root.slash_proposal_pubkey,
+                resolver: resolver_root.resolver_pubkey,
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_ncn_resolver_program_config_set_assignment_policy_round_robin_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let ConfiguredVault {
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+        let first_resolver_root = fixture.setup_active_resolver(&ncn_root).await.unwrap();
+        let second_resolver_root = fixture.setup_active_resolver(&ncn_root).await.unwrap();
+
+        resolver_program_client
+            .ncn_resolver_program_config_set_assignment_policy(
+                &ncn_root.ncn_pubkey,
+                &ncn_root.ncn_admin,
+                None,
+                ResolverAssignmentPolicy::RoundRobin,
+            )
+            .await
+            .unwrap();
+
+        let slash_proposal_root = resolver_program_client
+            .do_propose_slash_with_resolver(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+                &first_resolver_root.resolver_pubkey,
+            )
+            .await
+            .unwrap();
+        let ncn_slash_proposal_ticket: NcnSlashProposalTicket = resolver_program_client
+            .get_account(&slash_proposal_root.ncn_slash_proposal_ticket_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(
+            ncn_slash_proposal_ticket.resolver,
+            first_resolver_root.resolver_pubkey
+        );
+
+        // The first resolver already had its turn
+        let result = resolver_program_client
+            .do_propose_slash_with_resolver(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+                &first_resolver_root.resolver_pubkey,
+            )
+            .await;
+        assert_resolver_error(result, ResolverError::SlashProposalResolverInvalid);
+
+        let slash_proposal_root = resolver_program_client
+            .do_propose_slash_with_resolver(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+                &second_resolver_root.resolver_pubkey,
+            )
+            .await
+            .unwrap();
+        let ncn_slash_proposal_ticket: NcnSlashProposalTicket = resolver_program_client
+            .get_account(&slash_proposal_root.ncn_slash_proposal_ticket_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(
+            ncn_slash_proposal_ticket.resolver,
+            second_resolver_root.resolver_pubkey
+        );
+    }
+
+    #[tokio::test]
+    async fn test_ncn_resolver_program_config_set_assignment_policy_random_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let ConfiguredVault {
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+        // With a single resolver every draw picks index 0
+        let resolver_root = fixture.setup_active_resolver(&ncn_root).await.unwrap();
+
+        resolver_program_client
+            .ncn_resolver_program_config_set_assignment_policy(
+                &ncn_root.ncn_pubkey,
+                &ncn_root.ncn_admin,
+                None,
+                ResolverAssignmentPolicy::Random,
+            )
+            .await
+            .unwrap();
+
+        let slash_proposal_root = resolver_program_client
+            .do_propose_slash_with_resolver(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+                &resolver_root.resolver_pubkey,
+            )
+            .await
+            .unwrap();
+
+        let ncn_slash_proposal_ticket: NcnSlashProposalTicket = resolver_program_client
+            .get_account(&slash_proposal_root.ncn_slash_proposal_ticket_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(
+            ncn_slash_proposal_ticket.resolver,
+            resolver_root.resolver_pubkey
+        );
+    }
+
+    #[tokio::test]
+    async fn test_ncn_resolver_program_config_set_assignment_policy_inactive_resolver_skipped_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let ConfiguredVault {
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+        // The first resolver is approved by the NCN but its ticket is never warmed up
+        let inactive_resolver_root = resolver_program_client
+            .do_initialize_resolver(&ncn_root)
+            .await
+            .unwrap();
+        resolver_program_client
+            .do_initialize_ncn_resolver_ticket(&ncn_root, &inactive_resolver_root.resolver_pubkey)
+            .await
+            .unwrap();
+        let active_resolver_root = fixture.setup_active_resolver(&ncn_root).await.unwrap();
+
+        resolver_program_client
+            .ncn_resolver_program_config_set_assignment_policy(
+                &ncn_root.ncn_pubkey,
+                &ncn_root.ncn_admin,
+                None,
+                ResolverAssignmentPolicy::RoundRobin,
+            )
+            .await
+            .unwrap();
+
+        // The picked resolver can't take the proposal, so it is left for a manual SetResolver
+        let slash_proposal_root = resolver_program_client
+            .do_propose_slash_with_resolver(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+                &inactive_resolver_root.resolver_pubkey,
+            )
+            .await
+            .unwrap();
+        let slash_proposal: SlashProposal = resolver_program_client
+            .get_account(&slash_proposal_root.slash_proposal_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(
+            slash_proposal.status().unwrap(),
+            SlashProposalStatus::Proposed
+        );
+
+        // The cursor still moved past the inactive resolver
+        let slash_proposal_root = resolver_program_client
+            .do_propose_slash_with_resolver(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+                &active_resolver_root.resolver_pubkey,
+            )
+            .await
+            .unwrap();
+        let ncn_slash_proposal_ticket: NcnSlashProposalTicket = resolver_program_client
+            .get_account(&slash_proposal_root.ncn_slash_proposal_ticket_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(
+            ncn_slash_proposal_ticket.resolver,
+            active_resolver_root.resolver_pubkey
+        );
+    }
+
+    #[tokio::test]
+    async fn test_ncn_resolver_program_config_set_assignment_policy_stranger_resolver_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let ConfiguredVault {
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+        let resolver_root = fixture.setup_active_resolver(&ncn_root).await.unwrap();
+
+        // Anyone can initialize a resolver for the NCN, it isn't added to the NCN registry
+        let stranger_resolver_root = resolver_program_client
+            .do_initialize_resolver(&ncn_root)
+            .await
+            .unwrap();
+        let ncn_resolver_program_config: NcnResolverProgramConfig = resolver_program_client
+            .get_account(
+                &NcnResolverProgramConfig::find_program_address(
+                    &resolver_program::id(),
+                    &ncn_root.ncn_pubkey,
+                )
+                .0,
+            )
+            .await
+            .unwrap();
+        assert_eq!(ncn_resolver_program_config.resolver_count(), 1);
+
+        resolver_program_client
+            .ncn_resolver_program_config_set_assignment_policy(
+                &ncn_root.ncn_pubkey,
+                &ncn_root.ncn_admin,
+                None,
+                ResolverAssignmentPolicy::RoundRobin,
+            )
+            .await
+            .unwrap();
+
+        let result = resolver_program_client
+            .do_propose_slash_with_resolver(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+                &stranger_resolver_root.resolver_pubkey,
+            )
+            .await;
+        assert_resolver_error(result, ResolverError::SlashProposalResolverInvalid);
+
+        // The approved resolver takes every slot
+        for _ in 0..2 {
+            let slash_proposal_root = resolver_program_client
+                .do_propose_slash_with_resolver(
+                    &ncn_root.ncn_pubkey,
+                    &operator_roots[0].operator_pubkey,
+                    slasher_root,
+                    &vault_root.vault_pubkey,
+                    100,
+                    &resolver_root.resolver_pubkey,
+                )
+                .await
+                .unwrap();
+            let ncn_slash_proposal_ticket: NcnSlashProposalTicket = resolver_program_client
+                .get_account(&slash_proposal_root.ncn_slash_proposal_ticket_pubkey)
+                .await
+                .unwrap();
+            assert_eq!(
+                ncn_slash_proposal_ticket.resolver,
+                resolver_root.resolver_pubkey
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_ncn_resolver_program_config_set_assignment_policy_fixed_without_resolver_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let ConfiguredVault { ncn_root, .. } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        let result = resolver_program_client
+            .ncn_resolver_program_config_set_assignment_policy(
+                &ncn_root.ncn_pubkey,
+                &ncn_root.ncn_admin,
+                None,
+                ResolverAssignmentPolicy::Fixed,
+            )
+            .await;
+
+        assert_resolver_error(
+            result,
+            ResolverError::NcnResolverProgramConfigAssignmentPolicyInvalid,
+        );
+    }
+}
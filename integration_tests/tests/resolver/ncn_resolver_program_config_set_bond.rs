@@ -0,0 +1,479 @@
+#[cfg(test)]
+mod tests {
+    use resolver_core::{
+        ncn_resolver_program_config::NcnResolverProgramConfig,
+        slash_proposal::{SlashProposal, SlashProposalStatus},
+    };
+    use resolver_sdk::error::ResolverError;
+    use solana_program::{native_token::sol_to_lamports, pubkey::Pubkey};
+    use solana_sdk::{signature::Keypair, signer::Signer};
+    use spl_associated_token_account::{
+        get_associated_token_address, get_associated_token_address_with_program_id,
+    };
+
+    use crate::{
+        fixtures::{
+            fixture::{ConfiguredVault, TestBuilder},
+            resolver_client::assert_resolver_error,
+        },
+        resolver::{DELETE_SLASH_PROPOSAL_DURATION, MAX_SLASH_AMOUNT},
+    };
+
+    const BOND_AMOUNT: u64 = 1_000;
+    const TRANSFER_FEE_BPS: u16 = 100;
+
+    #[tokio::test]
+    async fn test_ncn_resolver_program_config_set_bond_sol_forfeited_to_sink_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let ConfiguredVault {
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+        let operator_pubkey = operator_roots[0].operator_pubkey;
+        let resolver_root = fixture.setup_active_resolver(&ncn_root).await.unwrap();
+
+        let bond_sink = Pubkey::new_unique();
+        let bond_amount = sol_to_lamports(0.1);
+        resolver_program_client
+            .ncn_resolver_program_config_set_bond(
+                &ncn_root.ncn_pubkey,
+                &ncn_root.ncn_admin,
+                None,
+                bond_amount,
+                bond_sink,
+            )
+            .await
+            .unwrap();
+
+        let ncn_resolver_program_config: NcnResolverProgramConfig = resolver_program_client
+            .get_account(
+                &NcnResolverProgramConfig::find_program_address(
+                    &resolver_program::id(),
+                    &ncn_root.ncn_pubkey,
+                )
+                .0,
+            )
+            .await
+            .unwrap();
+        assert_eq!(ncn_resolver_program_config.bond_mint, Pubkey::default());
+        assert_eq!(ncn_resolver_program_config.bond_amount(), bond_amount);
+
+        let slash_proposal_root = resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_pubkey,
+                slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+            )
+            .await
+            .unwrap();
+
+        let slash_proposal: SlashProposal = resolver_program_client
+            .get_account(&slash_proposal_root.slash_proposal_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(slash_proposal.bond_amount(), bond_amount);
+        assert_eq!(
+            slash_proposal.bond_depositor,
+            slasher_root.slasher_admin.pubkey()
+        );
+        assert_eq!(slash_proposal.bond_recipient, bond_sink);
+
+        let slash_proposal_balance_before = fixture
+            .get_balance(&slash_proposal_root.slash_proposal_pubkey)
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .do_veto_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_pubkey,
+                slasher_root,
+                &slash_proposal_root,
+                &resolver_root,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(fixture.get_balance(&bond_sink).await.unwrap(), bond_amount);
+        assert_eq!(
+            fixture
+                .get_balance(&slash_proposal_root.slash_proposal_pubkey)
+                .await
+                .unwrap(),
+            slash_proposal_balance_before - bond_amount
+        );
+
+        let slash_proposal: SlashProposal = resolver_program_client
+            .get_account(&slash_proposal_root.slash_proposal_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(
+            slash_proposal.status().unwrap(),
+            SlashProposalStatus::Vetoed
+        );
+        assert_eq!(slash_proposal.bond_amount(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_ncn_resolver_program_config_set_bond_sol_without_sink_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let ConfiguredVault { ncn_root, .. } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        let result = resolver_program_client
+            .ncn_resolver_program_config_set_bond(
+                &ncn_root.ncn_pubkey,
+                &ncn_root.ncn_admin,
+                None,
+                sol_to_lamports(0.1),
+                Pubkey::default(),
+            )
+            .await;
+
+        assert_resolver_error(
+            result,
+            ResolverError::NcnResolverProgramConfigBondSinkRequired,
+        );
+    }
+
+    #[tokio::test]
+    async fn test_ncn_resolver_program_config_set_bond_spl_returned_on_cancel_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+        let slasher_admin_pubkey = slasher_root.slasher_admin.pubkey();
+
+        let bond_mint = Keypair::new();
+        vault_program_client
+            .create_token_mint(&bond_mint, &spl_token::id())
+            .await
+            .unwrap();
+        fixture
+            .mint_spl_to(
+                &bond_mint.pubkey(),
+                &slasher_admin_pubkey,
+                BOND_AMOUNT,
+                &spl_token::id(),
+            )
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .ncn_resolver_program_config_set_bond(
+                &ncn_root.ncn_pubkey,
+                &ncn_root.ncn_admin,
+                Some(&bond_mint.pubkey()),
+                BOND_AMOUNT,
+                Pubkey::default(),
+            )
+            .await
+            .unwrap();
+
+        let slash_proposal_root = resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+            )
+            .await
+            .unwrap();
+
+        let slasher_admin_token_account =
+            get_associated_token_address(&slasher_admin_pubkey, &bond_mint.pubkey());
+        let slash_proposal_token_account = get_associated_token_address(
+            &slash_proposal_root.slash_proposal_pubkey,
+            &bond_mint.pubkey(),
+        );
+        assert_eq!(
+            fixture
+                .get_token_account(&slasher_admin_token_account)
+                .await
+                .unwrap()
+                .amount,
+            0
+        );
+        assert_eq!(
+            fixture
+                .get_token_account(&slash_proposal_token_account)
+                .await
+                .unwrap()
+                .amount,
+            BOND_AMOUNT
+        );
+
+        resolver_program_client
+            .do_cancel_slash_proposal(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                slasher_root,
+                &slash_proposal_root,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            fixture
+                .get_token_account(&slasher_admin_token_account)
+                .await
+                .unwrap()
+                .amount,
+            BOND_AMOUNT
+        );
+        // The emptied escrow token account is closed with the proposal
+        assert!(fixture
+            .get_token_account(&slash_proposal_token_account)
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_ncn_resolver_program_config_set_bond_spl_forfeited_to_sink_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+        let resolver_root = fixture.setup_active_resolver(&ncn_root).await.unwrap();
+
+        let bond_mint = Keypair::new();
+        vault_program_client
+            .create_token_mint(&bond_mint, &spl_token::id())
+            .await
+            .unwrap();
+        fixture
+            .mint_spl_to(
+                &bond_mint.pubkey(),
+                &slasher_root.slasher_admin.pubkey(),
+                BOND_AMOUNT,
+                &spl_token::id(),
+            )
+            .await
+            .unwrap();
+
+        let bond_sink = Pubkey::new_unique();
+        fixture
+            .create_ata(&bond_mint.pubkey(), &bond_sink)
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .ncn_resolver_program_config_set_bond(
+                &ncn_root.ncn_pubkey,
+                &ncn_root.ncn_admin,
+                Some(&bond_mint.pubkey()),
+                BOND_AMOUNT,
+                bond_sink,
+            )
+            .await
+            .unwrap();
+
+        let slash_proposal_root = resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+            )
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .do_veto_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                slasher_root,
+                &slash_proposal_root,
+                &resolver_root,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            fixture
+                .get_token_account(&get_associated_token_address(
+                    &bond_sink,
+                    &bond_mint.pubkey()
+                ))
+                .await
+                .unwrap()
+                .amount,
+            BOND_AMOUNT
+        );
+
+        // The escrow token account stays open until the vetoed proposal is deleted
+        let slash_proposal_token_account = get_associated_token_address(
+            &slash_proposal_root.slash_proposal_pubkey,
+            &bond_mint.pubkey(),
+        );
+        fixture
+            .warp_slot_incremental(DELETE_SLASH_PROPOSAL_DURATION)
+            .await
+            .unwrap();
+        resolver_program_client
+            .delete_slash_proposal(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root.slasher_pubkey,
+                &slash_proposal_root.slash_proposal_pubkey,
+                &slash_proposal_root.ncn_slash_proposal_ticket_pubkey,
+                &[resolver_root.resolver_pubkey],
+            )
+            .await
+            .unwrap();
+        assert!(fixture
+            .get_token_account(&slash_proposal_token_account)
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_ncn_resolver_program_config_set_bond_transfer_fee_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+        let slasher_admin_pubkey = slasher_root.slasher_admin.pubkey();
+
+        let bond_mint = Keypair::new();
+        vault_program_client
+            .create_transfer_fee_token_mint(&bond_mint, TRANSFER_FEE_BPS)
+            .await
+            .unwrap();
+        fixture
+            .mint_token_2022_to(&bond_mint.pubkey(), &slasher_admin_pubkey, BOND_AMOUNT)
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .ncn_resolver_program_config_set_bond(
+                &ncn_root.ncn_pubkey,
+                &ncn_root.ncn_admin,
+                Some(&bond_mint.pubkey()),
+                BOND_AMOUNT,
+                Pubkey::default(),
+            )
+            .await
+            .unwrap();
+
+        let slash_proposal_root = resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+            )
+            .await
+            .unwrap();
+
+        // Only the tokens that reached the escrow back the bond
+        let received_amount = BOND_AMOUNT - BOND_AMOUNT * TRANSFER_FEE_BPS as u64 / 10_000;
+        let slash_proposal: SlashProposal = resolver_program_client
+            .get_account(&slash_proposal_root.slash_proposal_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(slash_proposal.bond_amount(), received_amount);
+
+        resolver_program_client
+            .do_cancel_slash_proposal(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                slasher_root,
+                &slash_proposal_root,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            fixture
+                .get_token_account(&get_associated_token_address_with_program_id(
+                    &slasher_admin_pubkey,
+                    &bond_mint.pubkey(),
+                    &spl_token_2022::id(),
+                ))
+                .await
+                .unwrap()
+                .amount,
+            // Returning the bond is charged the fee again, rounded up
+            received_amount - (received_amount * TRANSFER_FEE_BPS as u64).div_ceil(10_000)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_ncn_resolver_program_config_set_bond_invalid_admin_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let ConfiguredVault { ncn_root, .. } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        let result = resolver_program_client
+            .ncn_resolver_program_config_set_bond(
+                &ncn_root.ncn_pubkey,
+                &Keypair::new(),
+                None,
+                BOND_AMOUNT,
+                Pubkey::default(),
+            )
+            .await;
+
+        assert_resolver_error(result, ResolverError::NcnResolverProgramConfigAdminInvalid);
+    }
+}
@@ -0,0 +1,304 @@
+#[cfg(test)]
+mod tests {
+    use resolver_core::{
+        ncn_resolver_program_config::NcnResolverProgramConfig, slash_proposal::SlashProposal,
+    };
+    use resolver_sdk::error::ResolverError;
+    use solana_sdk::{signature::Keypair, signer::Signer};
+
+    use crate::{
+        fixtures::{
+            fixture::{ConfiguredVault, TestBuilder},
+            resolver_client::assert_resolver_error,
+        },
+        resolver::{DELETE_SLASH_PROPOSAL_DURATION, MAX_SLASH_AMOUNT, VETO_DURATION},
+    };
+
+    #[tokio::test]
+    async fn test_ncn_resolver_program_config_set_parameters_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let deposit_fee_bps = 0;
+        let withdrawal_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![MAX_SLASH_AMOUNT];
+
+        let ConfiguredVault {
+            vault_program_client: _,
+            restaking_program_client: _,
+            vault_config_admin: _,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+
+        let old_slash_proposal_root = resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &vault_root.vault_pubkey,
+                10,
+            )
+            .await
+            .unwrap();
+
+        let new_veto_duration = VETO_DURATION * 2;
+        let new_delete_slash_proposal_duration = DELETE_SLASH_PROPOSAL_DURATION * 3;
+        resolver_program_client
+            .ncn_resolver_program_config_set_parameters(
+                &ncn_root.ncn_pubkey,
+                &ncn_root.ncn_admin,
+                Some(new_veto_duration),
+                Some(new_delete_slash_proposal_duration),
+                Some(500),
+                Some(VETO_DURATION),
+                Some(2),
+            )
+            .await
+            .unwrap();
+
+        let ncn_resolver_program_config: NcnResolverProgramConfig = resolver_program_client
+            .get_account(
+                &NcnResolverProgramConfig::find_program_address(
+                    &resolver_program::id(),
+                    &ncn_root.ncn_pubkey,
+                )
+                .0,
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            ncn_resolver_program_config.veto_duration(),
+            new_veto_duration
+        );
+        assert_eq!(
+            ncn_resolver_program_config.delete_slash_proposal_duration(),
+            new_delete_slash_proposal_duration
+        );
+        assert_eq!(ncn_resolver_program_config.resolver_penalty_bps(), 500);
+        assert_eq!(
+            ncn_resolver_program_config.appeal_extension_slots(),
+            VETO_DURATION
+        );
+        assert_eq!(ncn_resolver_program_config.veto_threshold(), 2);
+
+        let new_slash_proposal_root = resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &vault_root.vault_pubkey,
+                10,
+            )
+            .await
+            .unwrap();
+
+        // The existing proposal keeps the durations it was created with
+        let old_slash_proposal: SlashProposal = resolver_program_client
+            .get_account(&old_slash_proposal_root.slash_proposal_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(
+            old_slash_proposal.veto_deadline_slot() - u64::from(old_slash_proposal.capture_slot),
+            VETO_DURATION
+        );
+        assert_eq!(
+            old_slash_proposal.delete_slash_proposal_duration(),
+            DELETE_SLASH_PROPOSAL_DURATION
+        );
+
+        let new_slash_proposal: SlashProposal = resolver_program_client
+            .get_account(&new_slash_proposal_root.slash_proposal_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(
+            new_slash_proposal.veto_deadline_slot() - u64::from(new_slash_proposal.capture_slot),
+            new_veto_duration
+        );
+        assert_eq!(
+            new_slash_proposal.delete_slash_proposal_duration(),
+            new_delete_slash_proposal_duration
+        );
+    }
+
+    #[tokio::test]
+    async fn test_ncn_resolver_program_config_set_parameters_wrong_admin_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let deposit_fee_bps = 0;
+        let withdrawal_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![MAX_SLASH_AMOUNT];
+
+        let ConfiguredVault {
+            vault_program_client: _,
+            restaking_program_client: _,
+            vault_config_admin: _,
+            vault_root: _,
+            ncn_root,
+            operator_roots: _,
+            slashers_amounts: _,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        let wrong_admin = Keypair::new();
+        resolver_program_client
+            ._airdrop(&wrong_admin.pubkey(), 1.0)
+            .await
+            .unwrap();
+
+        let result = resolver_program_client
+            .ncn_resolver_program_config_set_parameters(
+                &ncn_root.ncn_pubkey,
+                &wrong_admin,
+                Some(VETO_DURATION * 2),
+                None,
+                None,
+                None,
+                None,
+            )
+            .await;
+
+        assert_resolver_error(result, ResolverError::NcnResolverProgramConfigAdminInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_ncn_resolver_program_config_set_parameters_appeal_extension_exceeds_veto_duration_fails(
+    ) {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let ConfiguredVault { ncn_root, .. } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        let result = resolver_program_client
+            .ncn_resolver_program_config_set_parameters(
+                &ncn_root.ncn_pubkey,
+                &ncn_root.ncn_admin,
+                None,
+                None,
+                None,
+                Some(VETO_DURATION + 1),
+                None,
+            )
+            .await;
+        assert_resolver_error(
+            result,
+            ResolverError::NcnResolverProgramConfigAppealExtensionInvalid,
+        );
+
+        // Shortening the veto duration below the current extension fails too
+        resolver_program_client
+            .ncn_resolver_program_config_set_parameters(
+                &ncn_root.ncn_pubkey,
+                &ncn_root.ncn_admin,
+                None,
+                None,
+                None,
+                Some(VETO_DURATION),
+                None,
+            )
+            .await
+            .unwrap();
+        let result = resolver_program_client
+            .ncn_resolver_program_config_set_parameters(
+                &ncn_root.ncn_pubkey,
+                &ncn_root.ncn_admin,
+                Some(VETO_DURATION - 1),
+                None,
+                None,
+                None,
+                None,
+            )
+            .await;
+        assert_resolver_error(
+            result,
+            ResolverError::NcnResolverProgramConfigAppealExtensionInvalid,
+        );
+    }
+
+    #[tokio::test]
+    async fn test_ncn_resolver_program_config_set_parameters_veto_threshold_zero_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let ConfiguredVault { ncn_root, .. } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        let result = resolver_program_client
+            .ncn_resolver_program_config_set_parameters(
+                &ncn_root.ncn_pubkey,
+                &ncn_root.ncn_admin,
+                None,
+                None,
+                None,
+                None,
+                Some(0),
+            )
+            .await;
+
+        assert_resolver_error(
+            result,
+            ResolverError::NcnResolverProgramConfigVetoThresholdInvalid,
+        );
+    }
+
+    #[tokio::test]
+    async fn test_ncn_resolver_program_config_set_parameters_duration_too_long_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let ConfiguredVault { ncn_root, .. } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        let result = resolver_program_client
+            .ncn_resolver_program_config_set_parameters(
+                &ncn_root.ncn_pubkey,
+                &ncn_root.ncn_admin,
+                None,
+                Some(u64::MAX),
+                None,
+                None,
+                None,
+            )
+            .await;
+
+        assert_resolver_error(
+            result,
+            ResolverError::NcnResolverProgramConfigDurationInvalid,
+        );
+    }
+}
@@ -0,0 +1,291 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_core::{
+        vault_ncn_slasher_operator_ticket::VaultNcnSlasherOperatorTicket,
+        vault_ncn_slasher_ticket::VaultNcnSlasherTicket,
+    };
+    use resolver_core::ncn_resolver_program_config::NcnResolverProgramConfig;
+    use resolver_sdk::{error::ResolverError, event::ResolverEvent};
+    use solana_program::pubkey::Pubkey;
+    use solana_sdk::{signature::Keypair, signer::Signer};
+    use spl_associated_token_account::get_associated_token_address;
+
+    use crate::{
+        fixtures::{
+            fixture::{ConfiguredVault, TestBuilder},
+            resolver_client::assert_resolver_error,
+        },
+        resolver::{DELEGATION_AMOUNT, MAX_SLASH_AMOUNT, MINT_AMOUNT},
+    };
+
+    #[tokio::test]
+    async fn test_ncn_resolver_program_config_set_proceeds_split_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_config_admin,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        let treasury = Pubkey::new_unique();
+        let insurance_fund = Pubkey::new_unique();
+        resolver_program_client
+            .ncn_resolver_program_config_set_proceeds(
+                &ncn_root.ncn_pubkey,
+                &ncn_root.ncn_admin,
+                1_000,
+                treasury,
+                2_000,
+                insurance_fund,
+                3_000,
+            )
+            .await
+            .unwrap();
+
+        let ncn_resolver_program_config: NcnResolverProgramConfig = resolver_program_client
+            .get_account(
+                &NcnResolverProgramConfig::find_program_address(
+                    &resolver_program::id(),
+                    &ncn_root.ncn_pubkey,
+                )
+                .0,
+            )
+            .await
+            .unwrap();
+        assert_eq!(ncn_resolver_program_config.proceeds_burn_bps(), 1_000);
+        assert_eq!(ncn_resolver_program_config.proceeds_treasury, treasury);
+        assert_eq!(ncn_resolver_program_config.proceeds_treasury_bps(), 2_000);
+        assert_eq!(
+            ncn_resolver_program_config.proceeds_insurance_fund,
+            insurance_fund
+        );
+        assert_eq!(
+            ncn_resolver_program_config.proceeds_insurance_fund_bps(),
+            3_000
+        );
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let operator_root = &operator_roots[0];
+        vault_program_client
+            .do_add_delegation(
+                &vault_root,
+                &operator_root.operator_pubkey,
+                DELEGATION_AMOUNT,
+            )
+            .await
+            .unwrap();
+
+        let config = vault_program_client
+            .get_config(
+                &jito_vault_core::config::Config::find_program_address(&jito_vault_program::id()).0,
+            )
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(2 * config.epoch_length())
+            .await
+            .unwrap();
+        vault_program_client
+            .do_full_vault_update(&vault_root.vault_pubkey, &[operator_root.operator_pubkey])
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+        for owner in [slasher_root.slasher_pubkey, treasury, insurance_fund] {
+            fixture
+                .create_ata(&vault.supported_mint, &owner)
+                .await
+                .unwrap();
+        }
+
+        let epoch = fixture.get_current_slot().await.unwrap() / config.epoch_length();
+        vault_program_client
+            .initialize_vault_ncn_slasher_operator_ticket(
+                &jito_vault_core::config::Config::find_program_address(&jito_vault_program::id()).0,
+                &vault_root.vault_pubkey,
+                &ncn_root.ncn_pubkey,
+                &slasher_root.slasher_pubkey,
+                &operator_root.operator_pubkey,
+                &VaultNcnSlasherTicket::find_program_address(
+                    &jito_vault_program::id(),
+                    &vault_root.vault_pubkey,
+                    &ncn_root.ncn_pubkey,
+                    &slasher_root.slasher_pubkey,
+                )
+                .0,
+                &VaultNcnSlasherOperatorTicket::find_program_address(
+                    &jito_vault_program::id(),
+                    &vault_root.vault_pubkey,
+                    &ncn_root.ncn_pubkey,
+                    &slasher_root.slasher_pubkey,
+                    &operator_root.operator_pubkey,
+                    epoch,
+                )
+                .0,
+                &vault_config_admin,
+            )
+            .await
+            .unwrap();
+
+        let slash_proposal_root = resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_root.operator_pubkey,
+                slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+            )
+            .await
+            .unwrap();
+
+        fixture.warp_slot_incremental(101).await.unwrap();
+
+        resolver_program_client
+            .do_execute_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_root.operator_pubkey,
+                slasher_root,
+                &vault_root,
+                &slash_proposal_root,
+                &Pubkey::default(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            resolver_program_client.events(),
+            &[
+                ResolverEvent::SlashProceedsRouted {
+                    slash_proposal: slash_proposal_root.slash_proposal_pubkey,
+                    vault: vault_root.vault_pubkey,
+                    burned: 10,
+                    treasury,
+                    treasury_amount: 20,
+                    insurance_fund,
+                    insurance_fund_amount: 30,
+                    slasher_amount: 40,
+                },
+                ResolverEvent::SlashExecuted {
+                    slash_proposal: slash_proposal_root.slash_proposal_pubkey,
+                    vault: vault_root.vault_pubkey,
+                    amount: 100,
+                    remaining_amount: 0,
+                }
+            ]
+        );
+
+        for (owner, amount) in [
+            (slasher_root.slasher_pubkey, 40),
+            (treasury, 20),
+            (insurance_fund, 30),
+        ] {
+            let ata = get_associated_token_address(&owner, &vault.supported_mint);
+            let token_account = fixture.get_token_account(&ata).await.unwrap();
+            assert_eq!(token_account.amount, amount);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_ncn_resolver_program_config_set_proceeds_exceeds_max_bps_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let ConfiguredVault { ncn_root, .. } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        let result = resolver_program_client
+            .ncn_resolver_program_config_set_proceeds(
+                &ncn_root.ncn_pubkey,
+                &ncn_root.ncn_admin,
+                5_000,
+                Pubkey::new_unique(),
+                5_001,
+                Pubkey::default(),
+                0,
+            )
+            .await;
+
+        assert_resolver_error(
+            result,
+            ResolverError::NcnResolverProgramConfigProceedsInvalid,
+        );
+    }
+
+    #[tokio::test]
+    async fn test_ncn_resolver_program_config_set_proceeds_treasury_unset_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let ConfiguredVault { ncn_root, .. } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        let result = resolver_program_client
+            .ncn_resolver_program_config_set_proceeds(
+                &ncn_root.ncn_pubkey,
+                &ncn_root.ncn_admin,
+                0,
+                Pubkey::default(),
+                1_000,
+                Pubkey::default(),
+                0,
+            )
+            .await;
+
+        assert_resolver_error(
+            result,
+            ResolverError::NcnResolverProgramConfigProceedsInvalid,
+        );
+    }
+
+    #[tokio::test]
+    async fn test_ncn_resolver_program_config_set_proceeds_wrong_admin_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let ConfiguredVault { ncn_root, .. } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        let result = resolver_program_client
+            .ncn_resolver_program_config_set_proceeds(
+                &ncn_root.ncn_pubkey,
+                &Keypair::new(),
+                1_000,
+                Pubkey::default(),
+                0,
+                Pubkey::default(),
+                0,
+            )
+            .await;
+
+        assert_resolver_error(result, ResolverError::NcnResolverProgramConfigAdminInvalid);
+    }
+}
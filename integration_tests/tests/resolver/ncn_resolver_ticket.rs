@@ -0,0 +1,381 @@
+#[cfg(test)]
+mod tests {
+    use resolver_core::{
+        config::Config, ncn_resolver_program_config::NcnResolverProgramConfig,
+        ncn_resolver_ticket::NcnResolverTicket,
+    };
+    use resolver_sdk::error::ResolverError;
+
+    use crate::{
+        fixtures::{
+            fixture::{ConfiguredVault, TestBuilder},
+            resolver_client::assert_resolver_error,
+        },
+        resolver::MAX_SLASH_AMOUNT,
+    };
+
+    #[tokio::test]
+    async fn test_ncn_resolver_ticket_lifecycle_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let ConfiguredVault { ncn_root, .. } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        let config: Config = resolver_program_client
+            .get_account(&Config::find_program_address(&resolver_program::id()).0)
+            .await
+            .unwrap();
+
+        let resolver_root = resolver_program_client
+            .do_initialize_resolver(&ncn_root)
+            .await
+            .unwrap();
+        let ncn_resolver_ticket_pubkey = NcnResolverTicket::find_program_address(
+            &resolver_program::id(),
+            &ncn_root.ncn_pubkey,
+            &resolver_root.resolver_pubkey,
+        )
+        .0;
+
+        resolver_program_client
+            .do_initialize_ncn_resolver_ticket(&ncn_root, &resolver_root.resolver_pubkey)
+            .await
+            .unwrap();
+
+        let ncn_resolver_ticket: NcnResolverTicket = resolver_program_client
+            .get_account(&ncn_resolver_ticket_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(ncn_resolver_ticket.ncn, ncn_root.ncn_pubkey);
+        assert_eq!(ncn_resolver_ticket.resolver, resolver_root.resolver_pubkey);
+        assert_eq!(ncn_resolver_ticket.index(), 0);
+        let slot = fixture.get_current_slot().await.unwrap();
+        assert!(!ncn_resolver_ticket
+            .state
+            .is_active(slot, config.epoch_length()));
+
+        fixture.warp_slot_incremental(1).await.unwrap();
+        resolver_program_client
+            .do_warmup_ncn_resolver_ticket(&ncn_root, &resolver_root.resolver_pubkey)
+            .await
+            .unwrap();
+
+        // A warming up resolver can't vote yet, so it doesn't count as active
+        let ncn_resolver_program_config_pubkey = NcnResolverProgramConfig::find_program_address(
+            &resolver_program::id(),
+            &ncn_root.ncn_pubkey,
+        )
+        .0;
+        let ncn_resolver_program_config: NcnResolverProgramConfig = resolver_program_client
+            .get_account(&ncn_resolver_program_config_pubkey)
+            .await
+            .unwrap();
+        let slot = fixture.get_current_slot().await.unwrap();
+        assert_eq!(ncn_resolver_program_config.warming_resolver_count(), 1);
+        assert_eq!(
+            ncn_resolver_program_config
+                .active_resolver_count(slot, config.epoch_length())
+                .unwrap(),
+            0
+        );
+
+        fixture
+            .warp_slot_incremental(config.epoch_length())
+            .await
+            .unwrap();
+
+        let ncn_resolver_ticket: NcnResolverTicket = resolver_program_client
+            .get_account(&ncn_resolver_ticket_pubkey)
+            .await
+            .unwrap();
+        let slot = fixture.get_current_slot().await.unwrap();
+        assert!(ncn_resolver_ticket
+            .state
+            .is_active(slot, config.epoch_length()));
+
+        let ncn_resolver_program_config: NcnResolverProgramConfig = resolver_program_client
+            .get_account(&ncn_resolver_program_config_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(
+            ncn_resolver_program_config
+                .active_resolver_count(slot, config.epoch_length())
+                .unwrap(),
+            1
+        );
+
+        resolver_program_client
+            .do_cooldown_ncn_resolver_ticket(&ncn_root, &resolver_root.resolver_pubkey)
+            .await
+            .unwrap();
+
+        let ncn_resolver_program_config: NcnResolverProgramConfig = resolver_program_client
+            .get_account(&ncn_resolver_program_config_pubkey)
+            .await
+            .unwrap();
+        let slot = fixture.get_current_slot().await.unwrap();
+        assert_eq!(
+            ncn_resolver_program_config
+                .active_resolver_count(slot, config.epoch_length())
+                .unwrap(),
+            0
+        );
+        assert_eq!(ncn_resolver_program_config.warming_resolver_count(), 0);
+        fixture
+            .warp_slot_incremental(2 * config.epoch_length())
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .do_remove_ncn_resolver_ticket(&ncn_root, &resolver_root.resolver_pubkey, None)
+            .await
+            .unwrap();
+
+        assert!(resolver_program_client
+            .get_account::<NcnResolverTicket>(&ncn_resolver_ticket_pubkey)
+            .await
+            .is_err());
+
+        let ncn_resolver_program_config: NcnResolverProgramConfig = resolver_program_client
+            .get_account(&ncn_resolver_program_config_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(ncn_resolver_program_config.resolver_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_remove_ncn_resolver_ticket_moves_last_index_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let ConfiguredVault { ncn_root, .. } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        let config: Config = resolver_program_client
+            .get_account(&Config::find_program_address(&resolver_program::id()).0)
+            .await
+            .unwrap();
+
+        let mut resolver_pubkeys = Vec::new();
+        for _ in 0..3 {
+            let resolver_root = resolver_program_client
+                .do_initialize_resolver(&ncn_root)
+                .await
+                .unwrap();
+            resolver_program_client
+                .do_initialize_ncn_resolver_ticket(&ncn_root, &resolver_root.resolver_pubkey)
+                .await
+                .unwrap();
+            resolver_pubkeys.push(resolver_root.resolver_pubkey);
+        }
+
+        // The first resolver leaves the registry
+        fixture.warp_slot_incremental(1).await.unwrap();
+        resolver_program_client
+            .do_warmup_ncn_resolver_ticket(&ncn_root, &resolver_pubkeys[0])
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(config.epoch_length())
+            .await
+            .unwrap();
+        resolver_program_client
+            .do_cooldown_ncn_resolver_ticket(&ncn_root, &resolver_pubkeys[0])
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(2 * config.epoch_length())
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .do_remove_ncn_resolver_ticket(
+                &ncn_root,
+                &resolver_pubkeys[0],
+                Some(&resolver_pubkeys[2]),
+            )
+            .await
+            .unwrap();
+
+        // The last resolver takes the freed index
+        for (resolver, index) in [(&resolver_pubkeys[1], 1), (&resolver_pubkeys[2], 0)] {
+            let ncn_resolver_ticket: NcnResolverTicket = resolver_program_client
+                .get_account(
+                    &NcnResolverTicket::find_program_address(
+                        &resolver_program::id(),
+                        &ncn_root.ncn_pubkey,
+                        resolver,
+                    )
+                    .0,
+                )
+                .await
+                .unwrap();
+            assert_eq!(ncn_resolver_ticket.index(), index);
+        }
+
+        let ncn_resolver_program_config: NcnResolverProgramConfig = resolver_program_client
+            .get_account(
+                &NcnResolverProgramConfig::find_program_address(
+                    &resolver_program::id(),
+                    &ncn_root.ncn_pubkey,
+                )
+                .0,
+            )
+            .await
+            .unwrap();
+        assert_eq!(ncn_resolver_program_config.resolver_count(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_set_resolver_ticket_inactive_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let ConfiguredVault {
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+
+        // The ticket is initialized but never warmed up
+        let resolver_root = resolver_program_client
+            .do_initialize_resolver(&ncn_root)
+            .await
+            .unwrap();
+        resolver_program_client
+            .do_initialize_ncn_resolver_ticket(&ncn_root, &resolver_root.resolver_pubkey)
+            .await
+            .unwrap();
+
+        let slash_proposal_root = resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+            )
+            .await
+            .unwrap();
+
+        let result = resolver_program_client
+            .do_set_resolver(
+                &ncn_root,
+                &operator_roots[0].operator_pubkey,
+                slasher_root,
+                &slash_proposal_root,
+                &resolver_root.resolver_pubkey,
+            )
+            .await;
+
+        assert_resolver_error(result, ResolverError::NcnResolverTicketInactive);
+    }
+
+    #[tokio::test]
+    async fn test_remove_ncn_resolver_ticket_active_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let ConfiguredVault { ncn_root, .. } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        let resolver_root = fixture.setup_active_resolver(&ncn_root).await.unwrap();
+
+        let result = resolver_program_client
+            .do_remove_ncn_resolver_ticket(&ncn_root, &resolver_root.resolver_pubkey, None)
+            .await;
+
+        assert_resolver_error(result, ResolverError::NcnResolverTicketNotInactive);
+    }
+    #[tokio::test]
+    async fn test_approve_and_amend_slash_ticket_inactive_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let ConfiguredVault {
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+        let resolver_root = fixture.setup_active_resolver(&ncn_root).await.unwrap();
+
+        let slash_proposal_root = resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+            )
+            .await
+            .unwrap();
+        resolver_program_client
+            .do_set_resolver(
+                &ncn_root,
+                &operator_roots[0].operator_pubkey,
+                slasher_root,
+                &slash_proposal_root,
+                &resolver_root.resolver_pubkey,
+            )
+            .await
+            .unwrap();
+
+        // The NCN removes the resolver after it was assigned
+        let config: Config = resolver_program_client
+            .get_account(&Config::find_program_address(&resolver_program::id()).0)
+            .await
+            .unwrap();
+        resolver_program_client
+            .do_cooldown_ncn_resolver_ticket(&ncn_root, &resolver_root.resolver_pubkey)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(2 * config.epoch_length())
+            .await
+            .unwrap();
+
+        let result = resolver_program_client
+            .do_amend_slash_amount(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                slasher_root,
+                &slash_proposal_root,
+                &resolver_root,
+                MAX_SLASH_AMOUNT / 2,
+            )
+            .await;
+        assert_resolver_error(result, ResolverError::NcnResolverTicketInactive);
+
+        let result = resolver_program_client
+            .do_approve_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                slasher_root,
+                &slash_proposal_root,
+                &resolver_root,
+            )
+            .await;
+        assert_resolver_error(result, ResolverError::NcnResolverTicketInactive);
+    }
+}
@@ -1,10 +1,19 @@
 #[cfg(test)]
 mod tests {
-    use resolver_core::slash_proposal::SlashProposal;
+    use resolver_core::{
+        config::Config,
+        slash_proposal::{SlashProposal, SlashProposalStatus},
+        slasher::Slasher,
+    };
+    use resolver_sdk::{error::ResolverError, event::ResolverEvent, instruction::SlashReason};
+    use solana_sdk::{signature::Keypair, signer::Signer};
 
     use crate::{
-        fixtures::fixture::{ConfiguredVault, TestBuilder},
-        resolver::MAX_SLASH_AMOUNT,
+        fixtures::{
+            fixture::{ConfiguredVault, TestBuilder},
+            resolver_client::assert_resolver_error,
+        },
+        resolver::{DELEGATION_AMOUNT, MAX_SLASH_AMOUNT, MINT_AMOUNT},
     };
 
     #[tokio::test]
@@ -22,7 +31,7 @@ mod tests {
             vault_program_client: _,
             restaking_program_client: _,
             vault_config_admin: _,
-            vault_root: _,
+            vault_root,
             ncn_root,
             operator_roots,
             slashers_amounts,
@@ -45,32 +54,565 @@ mod tests {
             .await
             .unwrap();
 
-        resolver_program_client
+        let slash_proposal_root = resolver_program_client
             .do_propose_slash(
                 &ncn_root.ncn_pubkey,
                 &operator_roots[0].operator_pubkey,
                 &slasher_root,
+                &vault_root.vault_pubkey,
                 100,
             )
             .await
             .unwrap();
 
         let slash_proposal: SlashProposal = resolver_program_client
-            .get_account(
-                &SlashProposal::find_program_address(
-                    &resolver_program::id(),
-                    &ncn_root.ncn_pubkey,
-                    &operator_roots[0].operator_pubkey,
-                    &slasher_root.slasher_pubkey,
-                )
-                .0,
-            )
+            .get_account(&slash_proposal_root.slash_proposal_pubkey)
             .await
             .unwrap();
 
         assert_eq!(slash_proposal.operator, operator_roots[0].operator_pubkey);
         assert_eq!(slash_proposal.slasher, slasher_root.slasher_pubkey);
+        assert_eq!(slash_proposal.id(), 0);
         assert_eq!(slash_proposal.amount(), 100);
-        assert!(!slash_proposal.completed());
+        assert_eq!(
+            slash_proposal.status().unwrap(),
+            SlashProposalStatus::Proposed
+        );
+
+        assert_eq!(
+            resolver_program_client.events(),
+            &[ResolverEvent::SlashProposed {
+                ncn: ncn_root.ncn_pubkey,
+                operator: operator_roots[0].operator_pubkey,
+                slasher: slasher_root.slasher_pubkey,
+                slash_proposal: slash_proposal_root.slash_proposal_pubkey,
+                id: 0,
+                amount: 100,
+                veto_deadline_slot: slash_proposal.veto_deadline_slot(),
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_propose_slash_concurrent_proposals_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let deposit_fee_bps = 0;
+        let withdrawal_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![MAX_SLASH_AMOUNT];
+
+        let ConfiguredVault {
+            vault_program_client: _,
+            restaking_program_client: _,
+            vault_config_admin: _,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+
+        let first_slash_proposal_root = resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+            )
+            .await
+            .unwrap();
+
+        let second_slash_proposal_root = resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &vault_root.vault_pubkey,
+                50,
+            )
+            .await
+            .unwrap();
+
+        assert_ne!(
+            first_slash_proposal_root.slash_proposal_pubkey,
+            second_slash_proposal_root.slash_proposal_pubkey
+        );
+
+        let first_slash_proposal: SlashProposal = resolver_program_client
+            .get_account(&first_slash_proposal_root.slash_proposal_pubkey)
+            .await
+            .unwrap();
+        let second_slash_proposal: SlashProposal = resolver_program_client
+            .get_account(&second_slash_proposal_root.slash_proposal_pubkey)
+            .await
+            .unwrap();
+
+        assert_eq!(first_slash_proposal.id(), 0);
+        assert_eq!(first_slash_proposal.amount(), 100);
+        assert_eq!(second_slash_proposal.id(), 1);
+        assert_eq!(second_slash_proposal.amount(), 50);
+
+        let slasher: Slasher = resolver_program_client
+            .get_account(&slasher_root.slasher_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(slasher.proposal_count(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_propose_slash_with_evidence_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let deposit_fee_bps = 0;
+        let withdrawal_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![MAX_SLASH_AMOUNT];
+
+        let ConfiguredVault {
+            vault_program_client: _,
+            restaking_program_client: _,
+            vault_config_admin: _,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+        let evidence_hash = [7; 32];
+        let metadata_uri = "https://example.com/evidence.json".to_string();
+
+        let slash_proposal_root = resolver_program_client
+            .do_propose_slash_with_evidence(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+                SlashReason::Equivocation,
+                evidence_hash,
+                metadata_uri.clone(),
+            )
+            .await
+            .unwrap();
+
+        let slash_proposal: SlashProposal = resolver_program_client
+            .get_account(&slash_proposal_root.slash_proposal_pubkey)
+            .await
+            .unwrap();
+
+        assert_eq!(slash_proposal.reason().unwrap(), SlashReason::Equivocation);
+        assert_eq!(slash_proposal.evidence_hash(), &evidence_hash);
+        assert_eq!(slash_proposal.metadata_uri(), metadata_uri);
+    }
+
+    #[tokio::test]
+    async fn test_propose_slash_metadata_uri_too_long_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let deposit_fee_bps = 0;
+        let withdrawal_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![MAX_SLASH_AMOUNT];
+
+        let ConfiguredVault {
+            vault_program_client: _,
+            restaking_program_client: _,
+            vault_config_admin: _,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+
+        let test_result = resolver_program_client
+            .do_propose_slash_with_evidence(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+                SlashReason::Custom,
+                [0; 32],
+                "a".repeat(SlashProposal::MAX_METADATA_URI_LEN + 1),
+            )
+            .await;
+
+        assert_resolver_error(test_result, ResolverError::SlashProposalMetadataUriTooLong);
+    }
+
+    #[tokio::test]
+    async fn test_propose_slash_slasher_ticket_inactive_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let deposit_fee_bps = 0;
+        let withdrawal_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![MAX_SLASH_AMOUNT];
+
+        let ConfiguredVault {
+            vault_program_client: _,
+            mut restaking_program_client,
+            vault_config_admin: _,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        // The ticket is never warmed up, so the slasher is not allowed to slash the vault
+        let slasher_root = resolver_program_client
+            .do_initialize_slasher(&ncn_root)
+            .await
+            .unwrap();
+        restaking_program_client
+            .do_initialize_ncn_vault_slasher_ticket(
+                &ncn_root,
+                &vault_root.vault_pubkey,
+                &slasher_root.slasher_pubkey,
+                MAX_SLASH_AMOUNT,
+            )
+            .await
+            .unwrap();
+
+        let test_result = resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+            )
+            .await;
+
+        assert_resolver_error(test_result, ResolverError::SlasherTicketInactive);
+    }
+
+    #[tokio::test]
+    async fn test_propose_slash_slasher_ncn_invalid_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let deposit_fee_bps = 0;
+        let withdrawal_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![MAX_SLASH_AMOUNT];
+
+        let ConfiguredVault {
+            vault_program_client: _,
+            mut restaking_program_client,
+            vault_config_admin: _,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        // The slasher belongs to another NCN, even though this NCN lets it slash the vault
+        let other_ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+        let slasher_root = resolver_program_client
+            .do_initialize_slasher(&other_ncn_root)
+            .await
+            .unwrap();
+        restaking_program_client
+            .do_initialize_ncn_vault_slasher_ticket(
+                &ncn_root,
+                &vault_root.vault_pubkey,
+                &slasher_root.slasher_pubkey,
+                MAX_SLASH_AMOUNT,
+            )
+            .await
+            .unwrap();
+        fixture.warp_slot_incremental(1).await.unwrap();
+        restaking_program_client
+            .do_warmup_ncn_vault_slasher_ticket(
+                &ncn_root,
+                &vault_root.vault_pubkey,
+                &slasher_root.slasher_pubkey,
+            )
+            .await
+            .unwrap();
+
+        let config: Config = resolver_program_client
+            .get_account(&Config::find_program_address(&resolver_program::id()).0)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(config.epoch_length())
+            .await
+            .unwrap();
+
+        let test_result = resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+            )
+            .await;
+
+        assert_resolver_error(test_result, ResolverError::SlasherNcnInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_propose_slash_amount_exceeds_max_slashable_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let deposit_fee_bps = 0;
+        let withdrawal_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![MAX_SLASH_AMOUNT];
+
+        let ConfiguredVault {
+            vault_program_client: _,
+            restaking_program_client: _,
+            vault_config_admin: _,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+
+        let test_result = resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &vault_root.vault_pubkey,
+                MAX_SLASH_AMOUNT + 1,
+            )
+            .await;
+
+        assert_resolver_error(
+            test_result,
+            ResolverError::SlashProposalAmountExceedsMaxSlashable,
+        );
+    }
+
+    #[tokio::test]
+    async fn test_propose_slash_amount_zero_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let ConfiguredVault {
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+
+        let test_result = resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                slasher_root,
+                &vault_root.vault_pubkey,
+                0,
+            )
+            .await;
+
+        assert_resolver_error(test_result, ResolverError::SlashProposalAmountZero);
+    }
+
+    #[tokio::test]
+    async fn test_propose_slash_with_delegation_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let deposit_fee_bps = 0;
+        let withdrawal_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![MAX_SLASH_AMOUNT];
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            restaking_program_client: _,
+            vault_config_admin: _,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_add_delegation(
+                &vault_root,
+                &operator_roots[0].operator_pubkey,
+                DELEGATION_AMOUNT,
+            )
+            .await
+            .unwrap();
+
+        let slash_proposal_root = resolver_program_client
+            .do_propose_slash_with_delegation(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &vault_root.vault_pubkey,
+                MAX_SLASH_AMOUNT,
+            )
+            .await
+            .unwrap();
+
+        let slash_proposal: SlashProposal = resolver_program_client
+            .get_account(&slash_proposal_root.slash_proposal_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(slash_proposal.amount(), MAX_SLASH_AMOUNT);
+    }
+
+    #[tokio::test]
+    async fn test_propose_slash_amount_exceeds_delegation_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let deposit_fee_bps = 0;
+        let withdrawal_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![MAX_SLASH_AMOUNT];
+
+        let ConfiguredVault {
+            vault_program_client: _,
+            restaking_program_client: _,
+            vault_config_admin: _,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+
+        // Nothing is delegated to the operator
+        let test_result = resolver_program_client
+            .do_propose_slash_with_delegation(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &vault_root.vault_pubkey,
+                MAX_SLASH_AMOUNT,
+            )
+            .await;
+
+        assert_resolver_error(
+            test_result,
+            ResolverError::SlashProposalAmountExceedsDelegation,
+        );
     }
 }
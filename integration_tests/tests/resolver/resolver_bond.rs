@@ -0,0 +1,758 @@
+#[cfg(test)]
+mod tests {
+    use resolver_core::{config::Config, resolver::Resolver};
+    use resolver_sdk::{error::ResolverError, event::ResolverEvent};
+    use solana_sdk::{signature::Keypair, signer::Signer};
+    use spl_associated_token_account::{
+        get_associated_token_address, get_associated_token_address_with_program_id,
+    };
+
+    use crate::{
+        fixtures::{
+            fixture::{ConfiguredVault, TestBuilder},
+            resolver_client::assert_resolver_error,
+        },
+        resolver::{MAX_SLASH_AMOUNT, VETO_DURATION},
+    };
+
+    const BOND_AMOUNT: u64 = 1_000;
+    const RESOLVER_PENALTY_BPS: u16 = 2_500;
+    const TRANSFER_FEE_BPS: u16 = 100;
+
+    #[tokio::test]
+    async fn test_resolver_bond_withdraw_after_cooldown_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            ncn_root,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        let resolver_root = resolver_program_client
+            .do_initialize_resolver(&ncn_root)
+            .await
+            .unwrap();
+        let resolver_admin_pubkey = resolver_root.resolver_admin.pubkey();
+
+        let bond_mint = Keypair::new();
+        vault_program_client
+            .create_token_mint(&bond_mint, &spl_token::id())
+            .await
+            .unwrap();
+        fixture
+            .mint_spl_to(
+                &bond_mint.pubkey(),
+                &resolver_admin_pubkey,
+                BOND_AMOUNT,
+                &spl_token::id(),
+            )
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .resolver_deposit_bond(&resolver_root, &bond_mint.pubkey(), BOND_AMOUNT)
+            .await
+            .unwrap();
+
+        let resolver: Resolver = resolver_program_client
+            .get_account(&resolver_root.resolver_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(resolver.bond_mint, bond_mint.pubkey());
+        assert_eq!(resolver.bond_amount(), BOND_AMOUNT);
+
+        let resolver_admin_token_account =
+            get_associated_token_address(&resolver_admin_pubkey, &bond_mint.pubkey());
+        let resolver_bond_token_account =
+            get_associated_token_address(&resolver_root.resolver_pubkey, &bond_mint.pubkey());
+        assert_eq!(
+            fixture
+                .get_token_account(&resolver_bond_token_account)
+                .await
+                .unwrap()
+                .amount,
+            BOND_AMOUNT
+        );
+
+        resolver_program_client
+            .resolver_deregister(&resolver_root, &[])
+            .await
+            .unwrap();
+
+        let config: Config = resolver_program_client
+            .get_account(&Config::find_program_address(&resolver_program::id()).0)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(Resolver::BOND_COOLDOWN_EPOCHS * config.epoch_length())
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .resolver_withdraw_bond(&resolver_root)
+            .await
+            .unwrap();
+
+        let resolver: Resolver = resolver_program_client
+            .get_account(&resolver_root.resolver_pubkey)
+            .await
+            .unwrap();
+        assert!(resolver.is_deregistered());
+        assert_eq!(resolver.bond_amount(), 0);
+        assert_eq!(
+            fixture
+                .get_token_account(&resolver_admin_token_account)
+                .await
+                .unwrap()
+                .amount,
+            BOND_AMOUNT
+        );
+        assert_eq!(
+            fixture
+                .get_token_account(&resolver_bond_token_account)
+                .await
+                .unwrap()
+                .amount,
+            0
+        );
+    }
+
+    #[tokio::test]
+    async fn test_resolver_deposit_bond_transfer_fee_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            ncn_root,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        let resolver_root = resolver_program_client
+            .do_initialize_resolver(&ncn_root)
+            .await
+            .unwrap();
+
+        let bond_mint = Keypair::new();
+        vault_program_client
+            .create_transfer_fee_token_mint(&bond_mint, TRANSFER_FEE_BPS)
+            .await
+            .unwrap();
+        fixture
+            .mint_token_2022_to(
+                &bond_mint.pubkey(),
+                &resolver_root.resolver_admin.pubkey(),
+                BOND_AMOUNT,
+            )
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .resolver_deposit_bond(&resolver_root, &bond_mint.pubkey(), BOND_AMOUNT)
+            .await
+            .unwrap();
+
+        // Only the tokens that reached the escrow back the bond
+        let received_amount = BOND_AMOUNT - BOND_AMOUNT * TRANSFER_FEE_BPS as u64 / 10_000;
+        let resolver: Resolver = resolver_program_client
+            .get_account(&resolver_root.resolver_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(resolver.bond_amount(), received_amount);
+        assert_eq!(
+            fixture
+                .get_token_account(&get_associated_token_address_with_program_id(
+                    &resolver_root.resolver_pubkey,
+                    &bond_mint.pubkey(),
+                    &spl_token_2022::id(),
+                ))
+                .await
+                .unwrap()
+                .amount,
+            received_amount
+        );
+    }
+
+    #[tokio::test]
+    async fn test_resolver_withdraw_bond_before_cooldown_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            ncn_root,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        let resolver_root = resolver_program_client
+            .do_initialize_resolver(&ncn_root)
+            .await
+            .unwrap();
+
+        let bond_mint = Keypair::new();
+        vault_program_client
+            .create_token_mint(&bond_mint, &spl_token::id())
+            .await
+            .unwrap();
+        fixture
+            .mint_spl_to(
+                &bond_mint.pubkey(),
+                &resolver_root.resolver_admin.pubkey(),
+                BOND_AMOUNT,
+                &spl_token::id(),
+            )
+            .await
+            .unwrap();
+        resolver_program_client
+            .resolver_deposit_bond(&resolver_root, &bond_mint.pubkey(), BOND_AMOUNT)
+            .await
+            .unwrap();
+
+        let result = resolver_program_client
+            .resolver_withdraw_bond(&resolver_root)
+            .await;
+        assert_resolver_error(result, ResolverError::ResolverNotDeregistered);
+
+        resolver_program_client
+            .resolver_deregister(&resolver_root, &[])
+            .await
+            .unwrap();
+
+        let result = resolver_program_client
+            .resolver_withdraw_bond(&resolver_root)
+            .await;
+        assert_resolver_error(result, ResolverError::ResolverBondCooldownNotEnded);
+    }
+
+    #[tokio::test]
+    async fn test_resolver_withdraw_bond_open_assignment_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+        let operator_pubkey = operator_roots[0].operator_pubkey;
+        let resolver_root = fixture.setup_active_resolver(&ncn_root).await.unwrap();
+
+        let bond_mint = Keypair::new();
+        vault_program_client
+            .create_token_mint(&bond_mint, &spl_token::id())
+            .await
+            .unwrap();
+        fixture
+            .mint_spl_to(
+                &bond_mint.pubkey(),
+                &resolver_root.resolver_admin.pubkey(),
+                BOND_AMOUNT,
+                &spl_token::id(),
+            )
+            .await
+            .unwrap();
+        resolver_program_client
+            .resolver_deposit_bond(&resolver_root, &bond_mint.pubkey(), BOND_AMOUNT)
+            .await
+            .unwrap();
+
+        let slash_proposal_root = resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_pubkey,
+                slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+            )
+            .await
+            .unwrap();
+        resolver_program_client
+            .do_set_resolver(
+                &ncn_root,
+                &operator_pubkey,
+                slasher_root,
+                &slash_proposal_root,
+                &resolver_root.resolver_pubkey,
+            )
+            .await
+            .unwrap();
+
+        let resolver: Resolver = resolver_program_client
+            .get_account(&resolver_root.resolver_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(resolver.open_assignments(), 1);
+
+        resolver_program_client
+            .resolver_deregister(&resolver_root, &[ncn_root.ncn_pubkey])
+            .await
+            .unwrap();
+
+        // A deregistered resolver can no longer decide the proposals it is assigned to
+        let result = resolver_program_client
+            .do_veto_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_pubkey,
+                slasher_root,
+                &slash_proposal_root,
+                &resolver_root,
+            )
+            .await;
+        assert_resolver_error(result, ResolverError::ResolverDeregistered);
+
+        let config: Config = resolver_program_client
+            .get_account(&Config::find_program_address(&resolver_program::id()).0)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(Resolver::BOND_COOLDOWN_EPOCHS * config.epoch_length())
+            .await
+            .unwrap();
+
+        let result = resolver_program_client
+            .resolver_withdraw_bond(&resolver_root)
+            .await;
+        assert_resolver_error(result, ResolverError::ResolverAssignmentsOpen);
+
+        // Closing the proposal releases the assignment
+        resolver_program_client
+            .do_cancel_slash_proposal(
+                &ncn_root.ncn_pubkey,
+                &operator_pubkey,
+                slasher_root,
+                &slash_proposal_root,
+            )
+            .await
+            .unwrap();
+
+        let resolver: Resolver = resolver_program_client
+            .get_account(&resolver_root.resolver_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(resolver.open_assignments(), 0);
+
+        resolver_program_client
+            .resolver_withdraw_bond(&resolver_root)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_resolver_deregister_active_ticket_missing_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let ConfiguredVault { ncn_root, .. } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        let resolver_root = fixture.setup_active_resolver(&ncn_root).await.unwrap();
+
+        // The ticket with the NCN must be passed so the resolver stops counting as active there
+        let result = resolver_program_client
+            .resolver_deregister(&resolver_root, &[])
+            .await;
+        assert_resolver_error(result, ResolverError::ResolverNcnTicketsActive);
+
+        resolver_program_client
+            .resolver_deregister(&resolver_root, &[ncn_root.ncn_pubkey])
+            .await
+            .unwrap();
+
+        let resolver: Resolver = resolver_program_client
+            .get_account(&resolver_root.resolver_pubkey)
+            .await
+            .unwrap();
+        assert!(resolver.is_deregistered());
+        assert_eq!(resolver.active_ncn_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_penalize_resolver_missed_decision_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+        let operator_pubkey = operator_roots[0].operator_pubkey;
+        let resolver_root = fixture.setup_active_resolver(&ncn_root).await.unwrap();
+
+        let bond_mint = Keypair::new();
+        vault_program_client
+            .create_token_mint(&bond_mint, &spl_token::id())
+            .await
+            .unwrap();
+        fixture
+            .mint_spl_to(
+                &bond_mint.pubkey(),
+                &resolver_root.resolver_admin.pubkey(),
+                BOND_AMOUNT,
+                &spl_token::id(),
+            )
+            .await
+            .unwrap();
+        // Without a bond sink the penalty goes to the operator
+        fixture
+            .create_ata(&bond_mint.pubkey(), &operator_pubkey)
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .resolver_deposit_bond(&resolver_root, &bond_mint.pubkey(), BOND_AMOUNT)
+            .await
+            .unwrap();
+        resolver_program_client
+            .ncn_resolver_program_config_set_parameters(
+                &ncn_root.ncn_pubkey,
+                &ncn_root.ncn_admin,
+                None,
+                None,
+                Some(RESOLVER_PENALTY_BPS),
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+
+        let slash_proposal_root = resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_pubkey,
+                slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+            )
+            .await
+            .unwrap();
+        resolver_program_client
+            .do_set_resolver(
+                &ncn_root,
+                &operator_pubkey,
+                slasher_root,
+                &slash_proposal_root,
+                &resolver_root.resolver_pubkey,
+            )
+            .await
+            .unwrap();
+        resolver_program_client
+            .require_resolver_decision(
+                &ncn_root,
+                &operator_pubkey,
+                slasher_root,
+                &slash_proposal_root,
+            )
+            .await
+            .unwrap();
+
+        // The resolver lets the veto period end without a decision
+        fixture
+            .warp_slot_incremental(VETO_DURATION + 1)
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .penalize_resolver(
+                &ncn_root,
+                &operator_pubkey,
+                slasher_root,
+                &slash_proposal_root,
+                &resolver_root.resolver_pubkey,
+            )
+            .await
+            .unwrap();
+
+        let penalty = BOND_AMOUNT * RESOLVER_PENALTY_BPS as u64 / 10_000;
+        let resolver: Resolver = resolver_program_client
+            .get_account(&resolver_root.resolver_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(resolver.bond_amount(), BOND_AMOUNT - penalty);
+        assert_eq!(
+            fixture
+                .get_token_account(&get_associated_token_address(
+                    &operator_pubkey,
+                    &bond_mint.pubkey()
+                ))
+                .await
+                .unwrap()
+                .amount,
+            penalty
+        );
+        assert_eq!(
+            resolver_program_client.events(),
+            &[ResolverEvent::ResolverPenalized {
+                resolver: resolver_root.resolver_pubkey,
+                slash_proposal: slash_proposal_root.slash_proposal_pubkey,
+                amount: penalty,
+            }]
+        );
+
+        let result = resolver_program_client
+            .penalize_resolver(
+                &ncn_root,
+                &operator_pubkey,
+                slasher_root,
+                &slash_proposal_root,
+                &resolver_root.resolver_pubkey,
+            )
+            .await;
+        assert_resolver_error(result, ResolverError::SlashProposalResolverAlreadyPenalized);
+    }
+
+    #[tokio::test]
+    async fn test_penalize_resolver_after_veto_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+        let operator_pubkey = operator_roots[0].operator_pubkey;
+        let resolver_root = fixture.setup_active_resolver(&ncn_root).await.unwrap();
+
+        let bond_mint = Keypair::new();
+        vault_program_client
+            .create_token_mint(&bond_mint, &spl_token::id())
+            .await
+            .unwrap();
+        fixture
+            .mint_spl_to(
+                &bond_mint.pubkey(),
+                &resolver_root.resolver_admin.pubkey(),
+                BOND_AMOUNT,
+                &spl_token::id(),
+            )
+            .await
+            .unwrap();
+        fixture
+            .create_ata(&bond_mint.pubkey(), &operator_pubkey)
+            .await
+            .unwrap();
+        resolver_program_client
+            .resolver_deposit_bond(&resolver_root, &bond_mint.pubkey(), BOND_AMOUNT)
+            .await
+            .unwrap();
+
+        let slash_proposal_root = resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_pubkey,
+                slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+            )
+            .await
+            .unwrap();
+        resolver_program_client
+            .do_set_resolver(
+                &ncn_root,
+                &operator_pubkey,
+                slasher_root,
+                &slash_proposal_root,
+                &resolver_root.resolver_pubkey,
+            )
+            .await
+            .unwrap();
+        resolver_program_client
+            .require_resolver_decision(
+                &ncn_root,
+                &operator_pubkey,
+                slasher_root,
+                &slash_proposal_root,
+            )
+            .await
+            .unwrap();
+        resolver_program_client
+            .do_veto_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_pubkey,
+                slasher_root,
+                &slash_proposal_root,
+                &resolver_root,
+            )
+            .await
+            .unwrap();
+
+        fixture
+            .warp_slot_incremental(VETO_DURATION + 1)
+            .await
+            .unwrap();
+
+        let result = resolver_program_client
+            .penalize_resolver(
+                &ncn_root,
+                &operator_pubkey,
+                slasher_root,
+                &slash_proposal_root,
+                &resolver_root.resolver_pubkey,
+            )
+            .await;
+        assert_resolver_error(result, ResolverError::SlashProposalResolverDecided);
+    }
+
+    #[tokio::test]
+    async fn test_require_resolver_decision_resolver_not_assigned_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let ConfiguredVault {
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+        let operator_pubkey = operator_roots[0].operator_pubkey;
+
+        let slash_proposal_root = resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_pubkey,
+                slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+            )
+            .await
+            .unwrap();
+
+        let result = resolver_program_client
+            .require_resolver_decision(
+                &ncn_root,
+                &operator_pubkey,
+                slasher_root,
+                &slash_proposal_root,
+            )
+            .await;
+        assert_resolver_error(result, ResolverError::SlashProposalCannotRequireDecision);
+    }
+
+    #[tokio::test]
+    async fn test_ncn_resolver_program_config_set_resolver_penalty_too_high_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let ConfiguredVault { ncn_root, .. } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        let result = resolver_program_client
+            .ncn_resolver_program_config_set_parameters(
+                &ncn_root.ncn_pubkey,
+                &ncn_root.ncn_admin,
+                None,
+                None,
+                Some(10_001),
+                None,
+                None,
+            )
+            .await;
+
+        assert_resolver_error(
+            result,
+            ResolverError::NcnResolverProgramConfigResolverPenaltyInvalid,
+        );
+    }
+
+    #[tokio::test]
+    async fn test_resolver_deposit_bond_other_mint_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            ncn_root,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        let resolver_root = resolver_program_client
+            .do_initialize_resolver(&ncn_root)
+            .await
+            .unwrap();
+
+        let mut bond_mints = Vec::new();
+        for _ in 0..2 {
+            let bond_mint = Keypair::new();
+            vault_program_client
+                .create_token_mint(&bond_mint, &spl_token::id())
+                .await
+                .unwrap();
+            fixture
+                .mint_spl_to(
+                    &bond_mint.pubkey(),
+                    &resolver_root.resolver_admin.pubkey(),
+                    BOND_AMOUNT,
+                    &spl_token::id(),
+                )
+                .await
+                .unwrap();
+            bond_mints.push(bond_mint.pubkey());
+        }
+
+        resolver_program_client
+            .resolver_deposit_bond(&resolver_root, &bond_mints[0], BOND_AMOUNT)
+            .await
+            .unwrap();
+
+        let result = resolver_program_client
+            .resolver_deposit_bond(&resolver_root, &bond_mints[1], BOND_AMOUNT)
+            .await;
+        assert_resolver_error(result, ResolverError::ResolverBondMintInvalid);
+
+        let resolver: Resolver = resolver_program_client
+            .get_account(&resolver_root.resolver_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(resolver.bond_mint, bond_mints[0]);
+        assert_eq!(resolver.bond_amount(), BOND_AMOUNT);
+    }
+}
@@ -0,0 +1,215 @@
+#[cfg(test)]
+mod tests {
+    use resolver_core::{
+        config::Config,
+        ncn_resolver_program_config::NcnResolverProgramConfig,
+        resolver_ncn_ticket::ResolverNcnTicket,
+        slash_proposal::{SlashProposal, SlashProposalStatus},
+    };
+    use resolver_sdk::error::ResolverError;
+
+    use crate::{
+        fixtures::{
+            fixture::{ConfiguredVault, TestBuilder},
+            resolver_client::assert_resolver_error,
+        },
+        resolver::{
+            DELETE_SLASH_PROPOSAL_DURATION, MAX_SLASH_AMOUNT, REQUIRE_RESOLVER_ASSIGNMENT,
+            VETO_DURATION, VETO_THRESHOLD,
+        },
+    };
+
+    #[tokio::test]
+    async fn test_resolver_ncn_ticket_shared_resolver_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let ConfiguredVault {
+            mut restaking_program_client,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+
+        // A resolver initialized for another NCN
+        let other_ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+        resolver_program_client
+            .do_initialize_ncn_resolver_program_config(
+                &Config::find_program_address(&resolver_program::id()).0,
+                &other_ncn_root.ncn_pubkey,
+                &other_ncn_root.ncn_admin,
+                VETO_DURATION,
+                DELETE_SLASH_PROPOSAL_DURATION,
+                VETO_THRESHOLD,
+                REQUIRE_RESOLVER_ASSIGNMENT,
+            )
+            .await
+            .unwrap();
+        let resolver_root = resolver_program_client
+            .do_initialize_resolver(&other_ncn_root)
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .do_initialize_resolver_ncn_ticket(&resolver_root, &ncn_root.ncn_pubkey)
+            .await
+            .unwrap();
+
+        let resolver_ncn_ticket_pubkey = ResolverNcnTicket::find_program_address(
+            &resolver_program::id(),
+            &resolver_root.resolver_pubkey,
+            &ncn_root.ncn_pubkey,
+        )
+        .0;
+        let resolver_ncn_ticket: ResolverNcnTicket = resolver_program_client
+            .get_account(&resolver_ncn_ticket_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(resolver_ncn_ticket.resolver, resolver_root.resolver_pubkey);
+        assert_eq!(resolver_ncn_ticket.ncn, ncn_root.ncn_pubkey);
+
+        let ncn_resolver_program_config: NcnResolverProgramConfig = resolver_program_client
+            .get_account(
+                &NcnResolverProgramConfig::find_program_address(
+                    &resolver_program::id(),
+                    &ncn_root.ncn_pubkey,
+                )
+                .0,
+            )
+            .await
+            .unwrap();
+        assert_eq!(ncn_resolver_program_config.resolver_count(), 0);
+
+        // The NCN approves the shared resolver
+        resolver_program_client
+            .initialize_ncn_resolver_ticket(
+                &ncn_root,
+                &resolver_root.resolver_pubkey,
+                Some(&resolver_ncn_ticket_pubkey),
+            )
+            .await
+            .unwrap();
+        fixture.warp_slot_incremental(1).await.unwrap();
+        resolver_program_client
+            .do_warmup_ncn_resolver_ticket(&ncn_root, &resolver_root.resolver_pubkey)
+            .await
+            .unwrap();
+        let config: Config = resolver_program_client
+            .get_account(&Config::find_program_address(&resolver_program::id()).0)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(config.epoch_length())
+            .await
+            .unwrap();
+
+        let slash_proposal_root = resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+            )
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .do_set_resolver(
+                &ncn_root,
+                &operator_roots[0].operator_pubkey,
+                slasher_root,
+                &slash_proposal_root,
+                &resolver_root.resolver_pubkey,
+            )
+            .await
+            .unwrap();
+        resolver_program_client
+            .do_veto_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                slasher_root,
+                &slash_proposal_root,
+                &resolver_root,
+            )
+            .await
+            .unwrap();
+
+        let slash_proposal: SlashProposal = resolver_program_client
+            .get_account(&slash_proposal_root.slash_proposal_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(
+            slash_proposal.status().unwrap(),
+            SlashProposalStatus::Vetoed
+        );
+    }
+
+    #[tokio::test]
+    async fn test_initialize_ncn_resolver_ticket_not_shared_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let ConfiguredVault {
+            mut restaking_program_client,
+            ncn_root,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        let other_ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+        resolver_program_client
+            .do_initialize_ncn_resolver_program_config(
+                &Config::find_program_address(&resolver_program::id()).0,
+                &other_ncn_root.ncn_pubkey,
+                &other_ncn_root.ncn_admin,
+                VETO_DURATION,
+                DELETE_SLASH_PROPOSAL_DURATION,
+                VETO_THRESHOLD,
+                REQUIRE_RESOLVER_ASSIGNMENT,
+            )
+            .await
+            .unwrap();
+        let resolver_root = resolver_program_client
+            .do_initialize_resolver(&other_ncn_root)
+            .await
+            .unwrap();
+
+        let result = resolver_program_client
+            .do_initialize_ncn_resolver_ticket(&ncn_root, &resolver_root.resolver_pubkey)
+            .await;
+
+        assert_resolver_error(result, ResolverError::ResolverNcnInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_resolver_ncn_ticket_already_registered_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let ConfiguredVault { ncn_root, .. } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        let resolver_root = resolver_program_client
+            .do_initialize_resolver(&ncn_root)
+            .await
+            .unwrap();
+
+        let result = resolver_program_client
+            .do_initialize_resolver_ncn_ticket(&resolver_root, &ncn_root.ncn_pubkey)
+            .await;
+
+        assert_resolver_error(result, ResolverError::ResolverNcnAlreadyRegistered);
+    }
+}
@@ -0,0 +1,133 @@
+#[cfg(test)]
+mod tests {
+    use resolver_core::resolver::Resolver;
+    use resolver_sdk::{
+        error::ResolverError,
+        event::{AdminRole, ResolverEvent},
+    };
+    use solana_sdk::{signature::Keypair, signer::Signer};
+
+    use crate::{
+        fixtures::{
+            fixture::{ConfiguredVault, TestBuilder},
+            resolver_client::assert_resolver_error,
+        },
+        resolver::MAX_SLASH_AMOUNT,
+    };
+
+    #[tokio::test]
+    async fn test_resolver_set_admin_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let deposit_fee_bps = 0;
+        let withdrawal_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![MAX_SLASH_AMOUNT];
+
+        let ConfiguredVault {
+            vault_program_client: _,
+            restaking_program_client: _,
+            vault_config_admin: _,
+            vault_root: _,
+            ncn_root,
+            operator_roots: _,
+            slashers_amounts: _,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        let resolver_root = resolver_program_client
+            .do_initialize_resolver(&ncn_root)
+            .await
+            .unwrap();
+
+        let new_admin = Keypair::new();
+        resolver_program_client
+            .resolver_set_admin(
+                &resolver_root.resolver_pubkey,
+                &resolver_root.resolver_admin,
+                &new_admin,
+            )
+            .await
+            .unwrap();
+
+        let resolver: Resolver = resolver_program_client
+            .get_account(&resolver_root.resolver_pubkey)
+            .await
+            .unwrap();
+
+        assert_eq!(resolver.admin, new_admin.pubkey());
+        assert_eq!(resolver.veto_admin, new_admin.pubkey());
+        assert_eq!(resolver.metadata_admin, new_admin.pubkey());
+
+        assert_eq!(
+            resolver_program_client.events(),
+            &[ResolverEvent::AdminChanged {
+                account: resolver_root.resolver_pubkey,
+                role: AdminRole::Admin,
+                old_admin: resolver_root.resolver_admin.pubkey(),
+                new_admin: new_admin.pubkey(),
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_resolver_set_admin_wrong_admin_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let deposit_fee_bps = 0;
+        let withdrawal_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![MAX_SLASH_AMOUNT];
+
+        let ConfiguredVault {
+            vault_program_client: _,
+            restaking_program_client: _,
+            vault_config_admin: _,
+            vault_root: _,
+            ncn_root,
+            operator_roots: _,
+            slashers_amounts: _,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        let resolver_root = resolver_program_client
+            .do_initialize_resolver(&ncn_root)
+            .await
+            .unwrap();
+
+        let wrong_admin = Keypair::new();
+        resolver_program_client
+            ._airdrop(&wrong_admin.pubkey(), 1.0)
+            .await
+            .unwrap();
+
+        let new_admin = Keypair::new();
+        let result = resolver_program_client
+            .resolver_set_admin(&resolver_root.resolver_pubkey, &wrong_admin, &new_admin)
+            .await;
+
+        assert_resolver_error(result, ResolverError::ResolverAdminInvalid);
+    }
+}
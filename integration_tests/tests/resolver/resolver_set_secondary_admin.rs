@@ -0,0 +1,79 @@
+#[cfg(test)]
+mod tests {
+    use resolver_core::resolver::Resolver;
+    use resolver_sdk::instruction::ResolverAdminRole;
+    use solana_sdk::{signature::Keypair, signer::Signer};
+
+    use crate::{
+        fixtures::fixture::{ConfiguredVault, TestBuilder},
+        resolver::MAX_SLASH_AMOUNT,
+    };
+
+    #[tokio::test]
+    async fn test_resolver_set_secondary_admin_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let deposit_fee_bps = 0;
+        let withdrawal_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![MAX_SLASH_AMOUNT];
+
+        let ConfiguredVault {
+            vault_program_client: _,
+            restaking_program_client: _,
+            vault_config_admin: _,
+            vault_root: _,
+            ncn_root,
+            operator_roots: _,
+            slashers_amounts: _,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        let resolver_root = resolver_program_client
+            .do_initialize_resolver(&ncn_root)
+            .await
+            .unwrap();
+
+        let veto_admin = Keypair::new();
+        resolver_program_client
+            .resolver_set_secondary_admin(
+                &resolver_root.resolver_pubkey,
+                &resolver_root.resolver_admin,
+                &veto_admin,
+                ResolverAdminRole::VetoAdmin,
+            )
+            .await
+            .unwrap();
+
+        let metadata_admin = Keypair::new();
+        resolver_program_client
+            .resolver_set_secondary_admin(
+                &resolver_root.resolver_pubkey,
+                &resolver_root.resolver_admin,
+                &metadata_admin,
+                ResolverAdminRole::MetadataAdmin,
+            )
+            .await
+            .unwrap();
+
+        let resolver: Resolver = resolver_program_client
+            .get_account(&resolver_root.resolver_pubkey)
+            .await
+            .unwrap();
+
+        assert_eq!(resolver.admin, resolver_root.resolver_admin.pubkey());
+        assert_eq!(resolver.veto_admin, veto_admin.pubkey());
+        assert_eq!(resolver.metadata_admin, metadata_admin.pubkey());
+    }
+}
@@ -1,14 +1,21 @@
 #[cfg(test)]
 mod tests {
     use resolver_core::{
-        ncn_slash_proposal_ticket::NcnSlashProposalTicket, slash_proposal::SlashProposal,
+        config::Config, ncn_slash_proposal_ticket::NcnSlashProposalTicket, resolver::Resolver,
     };
-    use solana_program::pubkey::Pubkey;
-    use solana_sdk::{signature::Keypair, signer::Signer};
+    use resolver_sdk::error::ResolverError;
+    use solana_program::{instruction::InstructionError, pubkey::Pubkey};
+    use solana_sdk::transaction::TransactionError;
 
     use crate::{
-        fixtures::fixture::{ConfiguredVault, TestBuilder},
-        resolver::MAX_SLASH_AMOUNT,
+        fixtures::{
+            fixture::{ConfiguredVault, TestBuilder},
+            resolver_client::assert_resolver_error,
+        },
+        resolver::{
+            DELETE_SLASH_PROPOSAL_DURATION, MAX_SLASH_AMOUNT, REQUIRE_RESOLVER_ASSIGNMENT,
+            VETO_DURATION, VETO_THRESHOLD,
+        },
     };
 
     #[tokio::test]
@@ -26,7 +33,7 @@ mod tests {
             vault_program_client: _,
             restaking_program_client: _,
             vault_config_admin: _,
-            vault_root: _,
+            vault_root,
             ncn_root,
             operator_roots,
             slashers_amounts,
@@ -44,35 +51,19 @@ mod tests {
 
         let slasher_root = &slashers_amounts[0].0;
 
-        resolver_program_client
-            .do_initialize_resolver(&ncn_root)
-            .await
-            .unwrap();
+        let resolver_root = fixture.setup_active_resolver(&ncn_root).await.unwrap();
 
-        resolver_program_client
+        let slash_proposal_root = resolver_program_client
             .do_propose_slash(
                 &ncn_root.ncn_pubkey,
                 &operator_roots[0].operator_pubkey,
                 &slasher_root,
+                &vault_root.vault_pubkey,
                 100,
             )
             .await
             .unwrap();
-
-        let slasher_root = &slashers_amounts[0].0;
-        let slash_proposal = SlashProposal::find_program_address(
-            &resolver_program::id(),
-            &ncn_root.ncn_pubkey,
-            &operator_roots[0].operator_pubkey,
-            &slasher_root.slasher_pubkey,
-        )
-        .0;
-        let ncn_slash_proposal_ticket_pubkey = NcnSlashProposalTicket::find_program_address(
-            &resolver_program::id(),
-            &ncn_root.ncn_pubkey,
-            &slash_proposal,
-        )
-        .0;
+        let ncn_slash_proposal_ticket_pubkey = slash_proposal_root.ncn_slash_proposal_ticket_pubkey;
         let ncn_slash_proposal_ticket: NcnSlashProposalTicket = resolver_program_client
             .get_account(&ncn_slash_proposal_ticket_pubkey)
             .await
@@ -80,13 +71,13 @@ mod tests {
 
         assert_eq!(ncn_slash_proposal_ticket.resolver, Pubkey::default());
 
-        let new_resolver = Keypair::new();
         resolver_program_client
             .do_set_resolver(
                 &ncn_root,
                 &operator_roots[0].operator_pubkey,
                 &slasher_root,
-                &new_resolver.pubkey(),
+                &slash_proposal_root,
+                &resolver_root.resolver_pubkey,
             )
             .await
             .unwrap();
@@ -95,6 +86,465 @@ mod tests {
             .await
             .unwrap();
 
-        assert_eq!(ncn_slash_proposal_ticket.resolver, new_resolver.pubkey());
+        assert_eq!(
+            ncn_slash_proposal_ticket.resolver,
+            resolver_root.resolver_pubkey
+        );
+    }
+
+    #[tokio::test]
+    async fn test_set_resolver_vetoed_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let deposit_fee_bps = 0;
+        let withdrawal_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![MAX_SLASH_AMOUNT];
+
+        let ConfiguredVault {
+            vault_program_client: _,
+            restaking_program_client: _,
+            vault_config_admin: _,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+
+        let resolver_root = fixture.setup_active_resolver(&ncn_root).await.unwrap();
+
+        let slash_proposal_root = resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+            )
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .do_veto_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &slash_proposal_root,
+                &resolver_root,
+            )
+            .await
+            .unwrap();
+
+        let test_result = resolver_program_client
+            .do_set_resolver(
+                &ncn_root,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &slash_proposal_root,
+                &resolver_root.resolver_pubkey,
+            )
+            .await;
+
+        assert_resolver_error(
+            test_result,
+            ResolverError::SlashProposalCannotAssignResolver,
+        );
+    }
+
+    #[tokio::test]
+    async fn test_set_resolver_not_resolver_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let deposit_fee_bps = 0;
+        let withdrawal_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![MAX_SLASH_AMOUNT];
+
+        let ConfiguredVault {
+            vault_program_client: _,
+            restaking_program_client: _,
+            vault_config_admin: _,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+
+        let slash_proposal_root = resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+            )
+            .await
+            .unwrap();
+
+        let test_result = resolver_program_client
+            .do_set_resolver(
+                &ncn_root,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &slash_proposal_root,
+                &Pubkey::new_unique(),
+            )
+            .await;
+
+        assert_eq!(
+            test_result.err().unwrap().to_transaction_error().unwrap(),
+            TransactionError::InstructionError(0, InstructionError::InvalidAccountOwner)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_set_resolver_ncn_invalid_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let deposit_fee_bps = 0;
+        let withdrawal_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![MAX_SLASH_AMOUNT];
+
+        let ConfiguredVault {
+            vault_program_client: _,
+            restaking_program_client: mut restaking_program_client,
+            vault_config_admin: _,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+
+        // A resolver registered for another NCN
+        let other_ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+        resolver_program_client
+            .do_initialize_ncn_resolver_program_config(
+                &Config::find_program_address(&resolver_program::id()).0,
+                &other_ncn_root.ncn_pubkey,
+                &other_ncn_root.ncn_admin,
+                VETO_DURATION,
+                DELETE_SLASH_PROPOSAL_DURATION,
+                VETO_THRESHOLD,
+                REQUIRE_RESOLVER_ASSIGNMENT,
+            )
+            .await
+            .unwrap();
+        let other_resolver_root = resolver_program_client
+            .do_initialize_resolver(&other_ncn_root)
+            .await
+            .unwrap();
+
+        let slash_proposal_root = resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+            )
+            .await
+            .unwrap();
+
+        let test_result = resolver_program_client
+            .do_set_resolver(
+                &ncn_root,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &slash_proposal_root,
+                &other_resolver_root.resolver_pubkey,
+            )
+            .await;
+
+        // The resolver has no NcnResolverTicket for the NCN
+        assert_eq!(
+            test_result.err().unwrap().to_transaction_error().unwrap(),
+            TransactionError::InstructionError(0, InstructionError::InvalidAccountOwner)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_set_resolver_veto_period_ended_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let deposit_fee_bps = 0;
+        let withdrawal_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![MAX_SLASH_AMOUNT];
+
+        let ConfiguredVault {
+            vault_program_client: _,
+            restaking_program_client: _,
+            vault_config_admin: _,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+
+        let resolver_root = fixture.setup_active_resolver(&ncn_root).await.unwrap();
+
+        let slash_proposal_root = resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+            )
+            .await
+            .unwrap();
+
+        fixture.warp_slot_incremental(VETO_DURATION).await.unwrap();
+
+        let test_result = resolver_program_client
+            .do_set_resolver(
+                &ncn_root,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &slash_proposal_root,
+                &resolver_root.resolver_pubkey,
+            )
+            .await;
+
+        assert_resolver_error(test_result, ResolverError::SlashProposalVetoPeriodEnded);
+    }
+    #[tokio::test]
+    async fn test_set_resolver_replaced_resolver_released_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let ConfiguredVault {
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+
+        let first_resolver_root = fixture.setup_active_resolver(&ncn_root).await.unwrap();
+        let second_resolver_root = fixture.setup_active_resolver(&ncn_root).await.unwrap();
+
+        let slash_proposal_root = resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+            )
+            .await
+            .unwrap();
+
+        for resolver_root in [&first_resolver_root, &second_resolver_root] {
+            resolver_program_client
+                .do_set_resolver(
+                    &ncn_root,
+                    &operator_roots[0].operator_pubkey,
+                    &slasher_root,
+                    &slash_proposal_root,
+                    &resolver_root.resolver_pubkey,
+                )
+                .await
+                .unwrap();
+        }
+
+        let first_resolver: Resolver = resolver_program_client
+            .get_account(&first_resolver_root.resolver_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(first_resolver.open_assignments(), 0);
+        let second_resolver: Resolver = resolver_program_client
+            .get_account(&second_resolver_root.resolver_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(second_resolver.open_assignments(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_set_resolver_veto_threshold_unreachable_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let ConfiguredVault {
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators_and_resolver_policy(
+                0,
+                0,
+                0,
+                1,
+                &[MAX_SLASH_AMOUNT],
+                2,
+                REQUIRE_RESOLVER_ASSIGNMENT,
+            )
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+
+        // A single active resolver can't reach a threshold of 2 vetoes
+        let resolver_root = fixture.setup_active_resolver(&ncn_root).await.unwrap();
+
+        let slash_proposal_root = resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+            )
+            .await
+            .unwrap();
+
+        let result = resolver_program_client
+            .do_set_resolver(
+                &ncn_root,
+                &operator_roots[0].operator_pubkey,
+                slasher_root,
+                &slash_proposal_root,
+                &resolver_root.resolver_pubkey,
+            )
+            .await;
+
+        assert_resolver_error(
+            result,
+            ResolverError::NcnResolverProgramConfigVetoThresholdUnreachable,
+        );
+    }
+
+    #[tokio::test]
+    async fn test_set_resolver_warming_up_resolver_not_counted_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let ConfiguredVault {
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators_and_resolver_policy(
+                0,
+                0,
+                0,
+                1,
+                &[MAX_SLASH_AMOUNT],
+                2,
+                REQUIRE_RESOLVER_ASSIGNMENT,
+            )
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+
+        let resolver_root = fixture.setup_active_resolver(&ncn_root).await.unwrap();
+
+        // The second resolver is warmed up but can't vote until the next epoch
+        let warming_resolver_root = resolver_program_client
+            .do_initialize_resolver(&ncn_root)
+            .await
+            .unwrap();
+        resolver_program_client
+            .do_initialize_ncn_resolver_ticket(&ncn_root, &warming_resolver_root.resolver_pubkey)
+            .await
+            .unwrap();
+        fixture.warp_slot_incremental(1).await.unwrap();
+        resolver_program_client
+            .do_warmup_ncn_resolver_ticket(&ncn_root, &warming_resolver_root.resolver_pubkey)
+            .await
+            .unwrap();
+
+        let slash_proposal_root = resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+            )
+            .await
+            .unwrap();
+
+        let result = resolver_program_client
+            .do_set_resolver(
+                &ncn_root,
+                &operator_roots[0].operator_pubkey,
+                slasher_root,
+                &slash_proposal_root,
+                &resolver_root.resolver_pubkey,
+            )
+            .await;
+
+        assert_resolver_error(
+            result,
+            ResolverError::NcnResolverProgramConfigVetoThresholdUnreachable,
+        );
     }
 }
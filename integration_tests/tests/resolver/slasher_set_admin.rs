@@ -1,6 +1,7 @@
 #[cfg(test)]
 mod tests {
     use resolver_core::slasher::Slasher;
+    use resolver_sdk::event::{AdminRole, ResolverEvent};
     use solana_sdk::{signature::Keypair, signer::Signer};
 
     use crate::{
@@ -56,5 +57,14 @@ mod tests {
             .unwrap();
 
         assert_eq!(slasher.admin, new_admin.pubkey());
+        assert_eq!(
+            resolver_program_client.events(),
+            &[ResolverEvent::AdminChanged {
+                account: slasher_root.slasher_pubkey,
+                role: AdminRole::Admin,
+                old_admin: slasher_root.slasher_admin.pubkey(),
+                new_admin: new_admin.pubkey(),
+            }]
+        );
     }
 }
@@ -0,0 +1,456 @@
+#[cfg(test)]
+mod tests {
+    use resolver_core::{
+        slash_proposal::{SlashProposal, SlashProposalStatus},
+        slash_proposal_appeal::SlashProposalAppeal,
+    };
+    use resolver_sdk::{error::ResolverError, event::ResolverEvent};
+    use solana_sdk::{signature::Keypair, signer::Signer};
+
+    use crate::{
+        fixtures::{
+            fixture::{ConfiguredVault, TestBuilder},
+            resolver_client::assert_resolver_error,
+        },
+        resolver::{MAX_SLASH_AMOUNT, VETO_DURATION},
+    };
+
+    const APPEAL_EXTENSION_SLOTS: u64 = VETO_DURATION / 2;
+
+    #[tokio::test]
+    async fn test_submit_appeal_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let ConfiguredVault {
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        let operator_root = &operator_roots[0];
+        let slasher_root = &slashers_amounts[0].0;
+        let resolver_root = fixture.setup_active_resolver(&ncn_root).await.unwrap();
+
+        resolver_program_client
+            .ncn_resolver_program_config_set_parameters(
+                &ncn_root.ncn_pubkey,
+                &ncn_root.ncn_admin,
+                None,
+                None,
+                None,
+                Some(APPEAL_EXTENSION_SLOTS),
+                None,
+            )
+            .await
+            .unwrap();
+
+        let slash_proposal_root = resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_root.operator_pubkey,
+                slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+            )
+            .await
+            .unwrap();
+        let slash_proposal: SlashProposal = resolver_program_client
+            .get_account(&slash_proposal_root.slash_proposal_pubkey)
+            .await
+            .unwrap();
+        let veto_deadline_slot = slash_proposal.veto_deadline_slot();
+        let delete_deadline_slot = slash_proposal.delete_deadline_slot();
+
+        resolver_program_client
+            .do_submit_appeal(
+                &ncn_root.ncn_pubkey,
+                operator_root,
+                slasher_root,
+                &slash_proposal_root,
+            )
+            .await
+            .unwrap();
+
+        let slash_proposal_appeal: SlashProposalAppeal = resolver_program_client
+            .get_account(
+                &SlashProposalAppeal::find_program_address(
+                    &resolver_program::id(),
+                    &slash_proposal_root.slash_proposal_pubkey,
+                )
+                .0,
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            slash_proposal_appeal.slash_proposal,
+            slash_proposal_root.slash_proposal_pubkey
+        );
+        assert_eq!(
+            slash_proposal_appeal.operator,
+            operator_root.operator_pubkey
+        );
+        assert_eq!(slash_proposal_appeal.evidence_hash(), &[2; 32]);
+        assert_eq!(
+            slash_proposal_appeal.metadata_uri(),
+            "https://example.com/appeal"
+        );
+        assert_eq!(
+            slash_proposal_appeal.payer,
+            operator_root.operator_admin.pubkey()
+        );
+
+        let slash_proposal: SlashProposal = resolver_program_client
+            .get_account(&slash_proposal_root.slash_proposal_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(
+            slash_proposal.veto_deadline_slot(),
+            veto_deadline_slot + APPEAL_EXTENSION_SLOTS
+        );
+        assert_eq!(
+            slash_proposal.delete_deadline_slot(),
+            delete_deadline_slot + APPEAL_EXTENSION_SLOTS
+        );
+        assert!(slash_proposal.appealed());
+        assert_eq!(
+            resolver_program_client.events().last(),
+            Some(&ResolverEvent::AppealSubmitted {
+                slash_proposal: slash_proposal_root.slash_proposal_pubkey,
+                operator: operator_root.operator_pubkey,
+                evidence_hash: [2; 32],
+                veto_deadline_slot: veto_deadline_slot + APPEAL_EXTENSION_SLOTS,
+            })
+        );
+
+        // Resolvers can still veto past the original deadline
+        fixture.warp_slot_incremental(VETO_DURATION).await.unwrap();
+        resolver_program_client
+            .do_veto_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_root.operator_pubkey,
+                slasher_root,
+                &slash_proposal_root,
+                &resolver_root,
+            )
+            .await
+            .unwrap();
+        let slash_proposal: SlashProposal = resolver_program_client
+            .get_account(&slash_proposal_root.slash_proposal_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(
+            slash_proposal.status().unwrap(),
+            SlashProposalStatus::Vetoed
+        );
+    }
+
+    #[tokio::test]
+    async fn test_submit_appeal_twice_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let ConfiguredVault {
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        let operator_root = &operator_roots[0];
+        let slasher_root = &slashers_amounts[0].0;
+
+        resolver_program_client
+            .ncn_resolver_program_config_set_parameters(
+                &ncn_root.ncn_pubkey,
+                &ncn_root.ncn_admin,
+                None,
+                None,
+                None,
+                Some(APPEAL_EXTENSION_SLOTS),
+                None,
+            )
+            .await
+            .unwrap();
+
+        let slash_proposal_root = resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_root.operator_pubkey,
+                slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+            )
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .do_submit_appeal(
+                &ncn_root.ncn_pubkey,
+                operator_root,
+                slasher_root,
+                &slash_proposal_root,
+            )
+            .await
+            .unwrap();
+        let slash_proposal: SlashProposal = resolver_program_client
+            .get_account(&slash_proposal_root.slash_proposal_pubkey)
+            .await
+            .unwrap();
+        let veto_deadline_slot = slash_proposal.veto_deadline_slot();
+
+        // The appeal account already exists, so the deadline is only extended once
+        fixture.warp_slot_incremental(1).await.unwrap();
+        assert!(resolver_program_client
+            .do_submit_appeal(
+                &ncn_root.ncn_pubkey,
+                operator_root,
+                slasher_root,
+                &slash_proposal_root,
+            )
+            .await
+            .is_err());
+
+        let slash_proposal: SlashProposal = resolver_program_client
+            .get_account(&slash_proposal_root.slash_proposal_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(slash_proposal.veto_deadline_slot(), veto_deadline_slot);
+    }
+
+    #[tokio::test]
+    async fn test_submit_appeal_veto_period_ended_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let ConfiguredVault {
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        let operator_root = &operator_roots[0];
+        let slasher_root = &slashers_amounts[0].0;
+
+        let slash_proposal_root = resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_root.operator_pubkey,
+                slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+            )
+            .await
+            .unwrap();
+
+        fixture.warp_slot_incremental(VETO_DURATION).await.unwrap();
+
+        let result = resolver_program_client
+            .do_submit_appeal(
+                &ncn_root.ncn_pubkey,
+                operator_root,
+                slasher_root,
+                &slash_proposal_root,
+            )
+            .await;
+
+        assert_resolver_error(result, ResolverError::SlashProposalVetoPeriodEnded);
+    }
+
+    #[tokio::test]
+    async fn test_submit_appeal_wrong_admin_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let ConfiguredVault {
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        let operator_root = &operator_roots[0];
+        let slasher_root = &slashers_amounts[0].0;
+
+        let slash_proposal_root = resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_root.operator_pubkey,
+                slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+            )
+            .await
+            .unwrap();
+
+        let wrong_admin = Keypair::new();
+        resolver_program_client
+            ._airdrop(&wrong_admin.pubkey(), 1.0)
+            .await
+            .unwrap();
+
+        let result = resolver_program_client
+            .submit_appeal(
+                &ncn_root.ncn_pubkey,
+                &operator_root.operator_pubkey,
+                &slasher_root.slasher_pubkey,
+                &slash_proposal_root.slash_proposal_pubkey,
+                &wrong_admin,
+                [2; 32],
+                "https://example.com/appeal".to_string(),
+            )
+            .await;
+
+        assert_resolver_error(result, ResolverError::OperatorAdminInvalid);
+    }
+    #[tokio::test]
+    async fn test_submit_appeal_closed_on_cancel_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let ConfiguredVault {
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        let operator_root = &operator_roots[0];
+        let slasher_root = &slashers_amounts[0].0;
+
+        let slash_proposal_root = resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_root.operator_pubkey,
+                slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+            )
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .do_submit_appeal(
+                &ncn_root.ncn_pubkey,
+                operator_root,
+                slasher_root,
+                &slash_proposal_root,
+            )
+            .await
+            .unwrap();
+
+        let slash_proposal_appeal_pubkey = SlashProposalAppeal::find_program_address(
+            &resolver_program::id(),
+            &slash_proposal_root.slash_proposal_pubkey,
+        )
+        .0;
+        let slash_proposal_appeal_rent = fixture
+            .get_balance(&slash_proposal_appeal_pubkey)
+            .await
+            .unwrap();
+        let operator_admin_balance = fixture
+            .get_balance(&operator_root.operator_admin.pubkey())
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .do_cancel_slash_proposal(
+                &ncn_root.ncn_pubkey,
+                &operator_root.operator_pubkey,
+                slasher_root,
+                &slash_proposal_root,
+            )
+            .await
+            .unwrap();
+
+        assert!(resolver_program_client
+            .get_account::<SlashProposalAppeal>(&slash_proposal_appeal_pubkey)
+            .await
+            .is_err());
+        assert_eq!(
+            fixture
+                .get_balance(&operator_root.operator_admin.pubkey())
+                .await
+                .unwrap(),
+            operator_admin_balance + slash_proposal_appeal_rent
+        );
+    }
+
+    #[tokio::test]
+    async fn test_submit_appeal_missing_on_cancel_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let ConfiguredVault {
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        let operator_root = &operator_roots[0];
+        let slasher_root = &slashers_amounts[0].0;
+
+        let slash_proposal_root = resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_root.operator_pubkey,
+                slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+            )
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .do_submit_appeal(
+                &ncn_root.ncn_pubkey,
+                operator_root,
+                slasher_root,
+                &slash_proposal_root,
+            )
+            .await
+            .unwrap();
+
+        // The SlashProposalAppeal would be left behind without a proposal to close it with
+        let result = resolver_program_client
+            .cancel_slash_proposal_closing(
+                &ncn_root.ncn_pubkey,
+                &operator_root.operator_pubkey,
+                &slasher_root.slasher_pubkey,
+                &slash_proposal_root.slash_proposal_pubkey,
+                &slash_proposal_root.ncn_slash_proposal_ticket_pubkey,
+                &slasher_root.slasher_admin,
+                &[],
+            )
+            .await;
+
+        assert_resolver_error(result, ResolverError::SlashProposalAccountsMissing);
+    }
+}
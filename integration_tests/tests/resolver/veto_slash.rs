@@ -1,10 +1,23 @@
 #[cfg(test)]
 mod tests {
-    use resolver_core::slash_proposal::SlashProposal;
+    use resolver_core::{
+        config::Config,
+        ncn_resolver_program_config::NcnResolverProgramConfig,
+        ncn_slash_proposal_ticket::NcnSlashProposalTicket,
+        resolver_vote::ResolverVote,
+        slash_proposal::{SlashProposal, SlashProposalStatus},
+    };
+    use resolver_sdk::{
+        error::ResolverError, event::ResolverEvent, instruction::ResolverAdminRole,
+    };
+    use solana_sdk::{signature::Keypair, signer::Signer};
 
     use crate::{
-        fixtures::fixture::{ConfiguredVault, TestBuilder},
-        resolver::MAX_SLASH_AMOUNT,
+        fixtures::{
+            fixture::{ConfiguredVault, TestBuilder},
+            resolver_client::assert_resolver_error,
+        },
+        resolver::{MAX_SLASH_AMOUNT, REQUIRE_RESOLVER_ASSIGNMENT},
     };
 
     #[tokio::test]
@@ -22,7 +35,7 @@ mod tests {
             vault_program_client: _,
             restaking_program_client: _,
             vault_config_admin: _,
-            vault_root: _,
+            vault_root,
             ncn_root,
             operator_roots,
             slashers_amounts,
@@ -40,28 +53,20 @@ mod tests {
 
         let slasher_root = &slashers_amounts[0].0;
 
-        let resolver_root = resolver_program_client
-            .do_initialize_resolver(&ncn_root)
-            .await
-            .unwrap();
+        let resolver_root = fixture.setup_active_resolver(&ncn_root).await.unwrap();
 
-        resolver_program_client
+        let slash_proposal_root = resolver_program_client
             .do_propose_slash(
                 &ncn_root.ncn_pubkey,
                 &operator_roots[0].operator_pubkey,
                 &slasher_root,
+                &vault_root.vault_pubkey,
                 100,
             )
             .await
             .unwrap();
+        let slash_proposal_pubkey = slash_proposal_root.slash_proposal_pubkey;
 
-        let slash_proposal_pubkey = SlashProposal::find_program_address(
-            &resolver_program::id(),
-            &ncn_root.ncn_pubkey,
-            &operator_roots[0].operator_pubkey,
-            &slasher_root.slasher_pubkey,
-        )
-        .0;
         let slash_proposal: SlashProposal = resolver_program_client
             .get_account(&slash_proposal_pubkey)
             .await
@@ -70,13 +75,17 @@ mod tests {
         assert_eq!(slash_proposal.operator, operator_roots[0].operator_pubkey);
         assert_eq!(slash_proposal.slasher, slasher_root.slasher_pubkey);
         assert_eq!(slash_proposal.amount(), 100);
-        assert!(!slash_proposal.completed());
+        assert_eq!(
+            slash_proposal.status().unwrap(),
+            SlashProposalStatus::Proposed
+        );
 
         resolver_program_client
             .do_set_resolver(
                 &ncn_root,
                 &operator_roots[0].operator_pubkey,
                 &slasher_root,
+                &slash_proposal_root,
                 &resolver_root.resolver_pubkey,
             )
             .await
@@ -87,6 +96,7 @@ mod tests {
                 &ncn_root.ncn_pubkey,
                 &operator_roots[0].operator_pubkey,
                 &slasher_root,
+                &slash_proposal_root,
                 &resolver_root,
             )
             .await
@@ -100,6 +110,430 @@ mod tests {
         assert_eq!(slash_proposal.operator, operator_roots[0].operator_pubkey);
         assert_eq!(slash_proposal.slasher, slasher_root.slasher_pubkey);
         assert_eq!(slash_proposal.amount(), 100);
-        assert!(slash_proposal.completed());
+        assert_eq!(
+            slash_proposal.status().unwrap(),
+            SlashProposalStatus::Vetoed
+        );
+
+        assert_eq!(
+            resolver_program_client.events(),
+            &[ResolverEvent::SlashVetoed {
+                slash_proposal: slash_proposal_pubkey,
+                veto_count: 1,
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_veto_slash_quorum_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let deposit_fee_bps = 0;
+        let withdrawal_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![MAX_SLASH_AMOUNT];
+        let veto_threshold = 2;
+
+        let ConfiguredVault {
+            vault_program_client: _,
+            restaking_program_client: _,
+            vault_config_admin: _,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators_and_resolver_policy(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+                veto_threshold,
+                REQUIRE_RESOLVER_ASSIGNMENT,
+            )
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+
+        let first_resolver_root = fixture.setup_active_resolver(&ncn_root).await.unwrap();
+        let second_resolver_root = fixture.setup_active_resolver(&ncn_root).await.unwrap();
+
+        let slash_proposal_root = resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+            )
+            .await
+            .unwrap();
+
+        resolver_program_client
+            .do_veto_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &slash_proposal_root,
+                &first_resolver_root,
+            )
+            .await
+            .unwrap();
+
+        let resolver_vote: ResolverVote = resolver_program_client
+            .get_account(
+                &ResolverVote::find_program_address(
+                    &resolver_program::id(),
+                    &slash_proposal_root.slash_proposal_pubkey,
+                    &first_resolver_root.resolver_pubkey,
+                )
+                .0,
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            resolver_vote.slash_proposal,
+            slash_proposal_root.slash_proposal_pubkey
+        );
+        assert_eq!(resolver_vote.resolver, first_resolver_root.resolver_pubkey);
+
+        let ncn_slash_proposal_ticket: NcnSlashProposalTicket = resolver_program_client
+            .get_account(&slash_proposal_root.ncn_slash_proposal_ticket_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(ncn_slash_proposal_ticket.veto_count(), 1);
+
+        let slash_proposal: SlashProposal = resolver_program_client
+            .get_account(&slash_proposal_root.slash_proposal_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(
+            slash_proposal.status().unwrap(),
+            SlashProposalStatus::Proposed
+        );
+
+        // The same resolver can only vote once
+        fixture.warp_slot_incremental(1).await.unwrap();
+        assert!(resolver_program_client
+            .do_veto_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &slash_proposal_root,
+                &first_resolver_root,
+            )
+            .await
+            .is_err());
+
+        resolver_program_client
+            .do_veto_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &slash_proposal_root,
+                &second_resolver_root,
+            )
+            .await
+            .unwrap();
+
+        let ncn_slash_proposal_ticket: NcnSlashProposalTicket = resolver_program_client
+            .get_account(&slash_proposal_root.ncn_slash_proposal_ticket_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(ncn_slash_proposal_ticket.veto_count(), 2);
+
+        let slash_proposal: SlashProposal = resolver_program_client
+            .get_account(&slash_proposal_root.slash_proposal_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(
+            slash_proposal.status().unwrap(),
+            SlashProposalStatus::Vetoed
+        );
+    }
+
+    #[tokio::test]
+    async fn test_veto_slash_veto_admin_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let deposit_fee_bps = 0;
+        let withdrawal_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![MAX_SLASH_AMOUNT];
+
+        let ConfiguredVault {
+            vault_program_client: _,
+            restaking_program_client: _,
+            vault_config_admin: _,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+
+        let resolver_root = fixture.setup_active_resolver(&ncn_root).await.unwrap();
+
+        let veto_admin = Keypair::new();
+        resolver_program_client
+            ._airdrop(&veto_admin.pubkey(), 1.0)
+            .await
+            .unwrap();
+        resolver_program_client
+            .resolver_set_secondary_admin(
+                &resolver_root.resolver_pubkey,
+                &resolver_root.resolver_admin,
+                &veto_admin,
+                ResolverAdminRole::VetoAdmin,
+            )
+            .await
+            .unwrap();
+
+        let slash_proposal_root = resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+            )
+            .await
+            .unwrap();
+
+        // The root admin no longer holds the veto role
+        let result = resolver_program_client
+            .do_veto_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root,
+                &slash_proposal_root,
+                &resolver_root,
+            )
+            .await;
+        assert_resolver_error(result, ResolverError::ResolverVetoAdminInvalid);
+
+        resolver_program_client
+            .veto_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &slasher_root.slasher_pubkey,
+                &resolver_root.resolver_pubkey,
+                &slash_proposal_root.slash_proposal_pubkey,
+                &slash_proposal_root.ncn_slash_proposal_ticket_pubkey,
+                &veto_admin,
+            )
+            .await
+            .unwrap();
+
+        let slash_proposal: SlashProposal = resolver_program_client
+            .get_account(&slash_proposal_root.slash_proposal_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(
+            slash_proposal.status().unwrap(),
+            SlashProposalStatus::Vetoed
+        );
+    }
+
+    #[tokio::test]
+    async fn test_veto_slash_threshold_above_active_resolvers_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let ConfiguredVault {
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators_and_resolver_policy(
+                0,
+                0,
+                0,
+                1,
+                &[MAX_SLASH_AMOUNT],
+                2,
+                REQUIRE_RESOLVER_ASSIGNMENT,
+            )
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+
+        let first_resolver_root = fixture.setup_active_resolver(&ncn_root).await.unwrap();
+        let second_resolver_root = fixture.setup_active_resolver(&ncn_root).await.unwrap();
+
+        let slash_proposal_root = resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+            )
+            .await
+            .unwrap();
+
+        // Once the second resolver cools down, the remaining one can veto on its own
+        resolver_program_client
+            .do_cooldown_ncn_resolver_ticket(&ncn_root, &second_resolver_root.resolver_pubkey)
+            .await
+            .unwrap();
+
+        let ncn_resolver_program_config: NcnResolverProgramConfig = resolver_program_client
+            .get_account(
+                &NcnResolverProgramConfig::find_program_address(
+                    &resolver_program::id(),
+                    &ncn_root.ncn_pubkey,
+                )
+                .0,
+            )
+            .await
+            .unwrap();
+        let config: Config = resolver_program_client
+            .get_account(&Config::find_program_address(&resolver_program::id()).0)
+            .await
+            .unwrap();
+        let slot = fixture.get_current_slot().await.unwrap();
+        assert_eq!(
+            ncn_resolver_program_config
+                .active_resolver_count(slot, config.epoch_length())
+                .unwrap(),
+            1
+        );
+        assert_eq!(
+            ncn_resolver_program_config
+                .effective_veto_threshold(slot, config.epoch_length())
+                .unwrap(),
+            1
+        );
+
+        resolver_program_client
+            .do_veto_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                slasher_root,
+                &slash_proposal_root,
+                &first_resolver_root,
+            )
+            .await
+            .unwrap();
+
+        let slash_proposal: SlashProposal = resolver_program_client
+            .get_account(&slash_proposal_root.slash_proposal_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(
+            slash_proposal.status().unwrap(),
+            SlashProposalStatus::Vetoed
+        );
+    }
+
+    #[tokio::test]
+    async fn test_veto_slash_after_resolver_deregisters_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut resolver_program_client = fixture.resolver_program_client();
+
+        let ConfiguredVault {
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators_and_resolver_policy(
+                0,
+                0,
+                0,
+                1,
+                &[MAX_SLASH_AMOUNT],
+                2,
+                REQUIRE_RESOLVER_ASSIGNMENT,
+            )
+            .await
+            .unwrap();
+
+        let slasher_root = &slashers_amounts[0].0;
+
+        let first_resolver_root = fixture.setup_active_resolver(&ncn_root).await.unwrap();
+        let second_resolver_root = fixture.setup_active_resolver(&ncn_root).await.unwrap();
+
+        let slash_proposal_root = resolver_program_client
+            .do_propose_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                slasher_root,
+                &vault_root.vault_pubkey,
+                100,
+            )
+            .await
+            .unwrap();
+
+        // Deregistering cools down the resolver's ticket, so it no longer counts towards the quorum
+        resolver_program_client
+            .resolver_deregister(&second_resolver_root, &[ncn_root.ncn_pubkey])
+            .await
+            .unwrap();
+
+        let ncn_resolver_program_config: NcnResolverProgramConfig = resolver_program_client
+            .get_account(
+                &NcnResolverProgramConfig::find_program_address(
+                    &resolver_program::id(),
+                    &ncn_root.ncn_pubkey,
+                )
+                .0,
+            )
+            .await
+            .unwrap();
+        let config: Config = resolver_program_client
+            .get_account(&Config::find_program_address(&resolver_program::id()).0)
+            .await
+            .unwrap();
+        let slot = fixture.get_current_slot().await.unwrap();
+        assert_eq!(
+            ncn_resolver_program_config
+                .active_resolver_count(slot, config.epoch_length())
+                .unwrap(),
+            1
+        );
+
+        resolver_program_client
+            .do_veto_slash(
+                &ncn_root.ncn_pubkey,
+                &operator_roots[0].operator_pubkey,
+                slasher_root,
+                &slash_proposal_root,
+                &first_resolver_root,
+            )
+            .await
+            .unwrap();
+
+        let slash_proposal: SlashProposal = resolver_program_client
+            .get_account(&slash_proposal_root.slash_proposal_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(
+            slash_proposal.status().unwrap(),
+            SlashProposalStatus::Vetoed
+        );
     }
 }
@@ -1,5 +1,6 @@
 use bytemuck::{Pod, Zeroable};
 use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
+use resolver_sdk::error::ResolverError;
 use shank::ShankAccount;
 use solana_program::{
     account_info::AccountInfo, epoch_schedule::DEFAULT_SLOTS_PER_EPOCH, msg,
@@ -55,6 +56,26 @@ impl Config {
         self.epoch_length.into()
     }
 
+    pub fn set_epoch_length(&mut self, epoch_length: u64) -> Result<(), ResolverError> {
+        if epoch_length == 0 {
+            msg!("Epoch length must be greater than zero");
+            return Err(ResolverError::ConfigEpochLengthInvalid);
+        }
+
+        self.epoch_length = PodU64::from(epoch_length);
+
+        Ok(())
+    }
+
+    pub fn check_admin(&self, admin: &Pubkey) -> Result<(), ResolverError> {
+        if self.admin.ne(admin) {
+            msg!("Config admin is incorrect");
+            return Err(ResolverError::ConfigAdminInvalid);
+        }
+
+        Ok(())
+    }
+
     /// Returns the seeds for the PDA
     pub fn seeds() -> Vec<Vec<u8>> {
         vec![b"config".to_vec()]
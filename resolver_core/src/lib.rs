@@ -1,6 +1,10 @@
 pub mod config;
 pub mod ncn_resolver_program_config;
+pub mod ncn_resolver_ticket;
 pub mod ncn_slash_proposal_ticket;
 pub mod resolver;
+pub mod resolver_ncn_ticket;
+pub mod resolver_vote;
 pub mod slash_proposal;
+pub mod slash_proposal_appeal;
 pub mod slasher;
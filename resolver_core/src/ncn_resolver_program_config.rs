@@ -1,7 +1,16 @@
 use bytemuck::{Pod, Zeroable};
-use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
+use jito_bytemuck::{
+    types::{PodBool, PodU16, PodU64},
+    AccountDeserialize, Discriminator,
+};
+use resolver_sdk::{error::ResolverError, instruction::ResolverAssignmentPolicy};
 use shank::ShankAccount;
-use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+use solana_program::{
+    account_info::AccountInfo, epoch_schedule::DEFAULT_SLOTS_PER_EPOCH, msg,
+    program_error::ProgramError, pubkey::Pubkey,
+};
+
+use crate::ncn_resolver_ticket::NcnResolverTicket;
 
 /// The global configuration account for the resolver program. Manages
 /// program-wide settings and state.
@@ -11,26 +20,127 @@ pub struct NcnResolverProgramConfig {
     /// The resolver admin of the NCN
     pub resolver_admin: Pubkey,
 
-    /// The length of an epoch in slots
+    /// The slots resolvers have to veto a slash proposal after it is proposed
     veto_duration: PodU64,
 
+    /// The slots after its deadline a slash proposal can be deleted
     delete_slash_proposal_duration: PodU64,
 
+    /// The number of resolvers approved by the NCN, their NcnResolverTickets hold the indices
+    /// below it
     resolver_count: PodU64,
 
+    /// The number of resolver vetoes required to veto a slash proposal
+    veto_threshold: PodU64,
+
+    /// Whether a resolver must be assigned before a slash proposal can be executed
+    require_resolver_assignment: PodBool,
+
+    /// The resolver assigned to new slash proposals with the Fixed policy
+    pub default_resolver: Pubkey,
+
+    /// The next resolver index assigned with the RoundRobin policy
+    next_assignment_index: PodU64,
+
+    /// How a resolver is assigned to new slash proposals
+    assignment_policy: u8,
+
+    /// The mint of the bond slashers deposit when proposing, the default pubkey for SOL
+    pub bond_mint: Pubkey,
+
+    /// The bond slashers deposit when proposing, zero to disable bonds
+    bond_amount: PodU64,
+
+    /// The account receiving forfeited bonds, the default pubkey to send SPL bonds to the operator.
+    /// Required for SOL bonds
+    pub bond_sink: Pubkey,
+
+    /// The share of a resolver bond forfeited when it misses a decision the NCN required, in bps
+    resolver_penalty_bps: PodU16,
+
+    /// The share of slashed funds burned, in bps
+    proceeds_burn_bps: PodU16,
+
+    /// The owner of the token accounts receiving the NCN treasury share of slashed funds
+    pub proceeds_treasury: Pubkey,
+
+    /// The share of slashed funds sent to the NCN treasury, in bps
+    proceeds_treasury_bps: PodU16,
+
+    /// The owner of the token accounts receiving the insurance fund share of slashed funds
+    pub proceeds_insurance_fund: Pubkey,
+
+    /// The share of slashed funds sent to the insurance fund, in bps
+    proceeds_insurance_fund_bps: PodU16,
+
+    /// The slots an operator appeal adds to the veto deadline of a slash proposal, bounded by
+    /// the veto duration
+    appeal_extension_slots: PodU64,
+
+    /// The number of resolvers whose NcnResolverTicket became active and wasn't cooled down since,
+    /// not counting those in `warming_resolver_count`
+    active_resolver_count: PodU64,
+
+    /// The number of resolvers warmed up in `warmup_epoch`, they can only vote from the next
+    /// epoch on and are moved to `active_resolver_count` on a later warmup or cooldown
+    warming_resolver_count: PodU64,
+
+    /// The epoch the resolvers in `warming_resolver_count` were warmed up in
+    warmup_epoch: PodU64,
+
     /// The bump seed for the PDA
     pub bump: u8,
 }
 
+/// How the funds slashed from a vault are split by [`NcnResolverProgramConfig::split_proceeds`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SlashProceeds {
+    /// The amount burned
+    pub burn: u64,
+
+    /// The amount sent to the NCN treasury
+    pub treasury: u64,
+
+    /// The amount sent to the insurance fund
+    pub insurance_fund: u64,
+
+    /// The amount kept in the slasher token account
+    pub slasher: u64,
+}
+
+/// Returns the epoch `slot` falls in
+fn epoch(slot: u64, epoch_length: u64) -> Result<u64, ResolverError> {
+    slot.checked_div(epoch_length)
+        .ok_or(ResolverError::DivisionByZero)
+}
+
+/// Returns `bps` basis points of `amount`, rounded down
+fn bps_share(amount: u64, bps: u16) -> Result<u64, ResolverError> {
+    let share = (amount as u128)
+        .checked_mul(bps as u128)
+        .ok_or(ResolverError::ArithmeticOverflow)?
+        .checked_div(NcnResolverProgramConfig::MAX_BPS as u128)
+        .ok_or(ResolverError::DivisionByZero)?;
+
+    u64::try_from(share).map_err(|_| ResolverError::ArithmeticOverflow)
+}
+
 impl Discriminator for NcnResolverProgramConfig {
     const DISCRIMINATOR: u8 = 2;
 }
 
 impl NcnResolverProgramConfig {
+    pub const MAX_BPS: u16 = 10_000;
+    /// The longest veto or delete duration, about 20 days of slots, keeping deadlines far from
+    /// overflowing
+    pub const MAX_DURATION_SLOTS: u64 = 10 * DEFAULT_SLOTS_PER_EPOCH;
+
     pub fn new(
         resolver_admin: Pubkey,
         veto_duration: u64,
         delete_slash_proposal_duration: u64,
+        veto_threshold: u64,
+        require_resolver_assignment: bool,
         bump: u8,
     ) -> Self {
         Self {
@@ -38,6 +148,24 @@ impl NcnResolverProgramConfig {
             veto_duration: PodU64::from(veto_duration),
             delete_slash_proposal_duration: PodU64::from(delete_slash_proposal_duration),
             resolver_count: PodU64::from(0),
+            veto_threshold: PodU64::from(veto_threshold),
+            require_resolver_assignment: PodBool::from_bool(require_resolver_assignment),
+            default_resolver: Pubkey::default(),
+            next_assignment_index: PodU64::from(0),
+            assignment_policy: ResolverAssignmentPolicy::Manual as u8,
+            bond_mint: Pubkey::default(),
+            bond_amount: PodU64::from(0),
+            bond_sink: Pubkey::default(),
+            resolver_penalty_bps: PodU16::from(0),
+            proceeds_burn_bps: PodU16::from(0),
+            proceeds_treasury: Pubkey::default(),
+            proceeds_treasury_bps: PodU16::from(0),
+            proceeds_insurance_fund: Pubkey::default(),
+            proceeds_insurance_fund_bps: PodU16::from(0),
+            appeal_extension_slots: PodU64::from(0),
+            active_resolver_count: PodU64::from(0),
+            warming_resolver_count: PodU64::from(0),
+            warmup_epoch: PodU64::from(0),
             bump,
         }
     }
@@ -54,12 +182,355 @@ impl NcnResolverProgramConfig {
         self.resolver_count.into()
     }
 
+    pub fn veto_threshold(&self) -> u64 {
+        self.veto_threshold.into()
+    }
+
+    pub fn set_veto_threshold(&mut self, veto_threshold: u64) -> Result<(), ResolverError> {
+        if veto_threshold == 0 {
+            msg!("Veto threshold must be greater than zero");
+            return Err(ResolverError::NcnResolverProgramConfigVetoThresholdInvalid);
+        }
+        self.veto_threshold = PodU64::from(veto_threshold);
+
+        Ok(())
+    }
+
+    /// Returns the number of resolvers that can vote at `slot`
+    pub fn active_resolver_count(
+        &self,
+        slot: u64,
+        epoch_length: u64,
+    ) -> Result<u64, ResolverError> {
+        let active_resolver_count = self.active_resolver_count.into();
+        if epoch(slot, epoch_length)? > self.warmup_epoch() {
+            active_resolver_count
+                .checked_add(self.warming_resolver_count())
+                .ok_or(ResolverError::ArithmeticOverflow)
+        } else {
+            Ok(active_resolver_count)
+        }
+    }
+
+    pub fn warming_resolver_count(&self) -> u64 {
+        self.warming_resolver_count.into()
+    }
+
+    pub fn warmup_epoch(&self) -> u64 {
+        self.warmup_epoch.into()
+    }
+
+    /// Warms up a resolver of the NCN, it only counts as active from the next epoch on, once it
+    /// can vote
+    pub fn warmup_resolver(
+        &mut self,
+        ncn_resolver_ticket: &mut NcnResolverTicket,
+        slot: u64,
+        epoch_length: u64,
+    ) -> Result<(), ResolverError> {
+        if !ncn_resolver_ticket.state.activate(slot, epoch_length) {
+            msg!("Resolver is not ready to be activated");
+            return Err(ResolverError::NcnResolverTicketFailedWarmup);
+        }
+
+        self.settle_warming_resolvers(slot, epoch_length)?;
+        let count = self
+            .warming_resolver_count()
+            .checked_add(1)
+            .ok_or(ResolverError::ArithmeticOverflow)?;
+        self.warming_resolver_count = PodU64::from(count);
+        self.warmup_epoch = PodU64::from(epoch(slot, epoch_length)?);
+
+        Ok(())
+    }
+
+    /// Cools down a resolver of the NCN, it stops counting as active as it can no longer vote
+    pub fn cooldown_resolver(
+        &mut self,
+        ncn_resolver_ticket: &mut NcnResolverTicket,
+        slot: u64,
+        epoch_length: u64,
+    ) -> Result<(), ResolverError> {
+        if !ncn_resolver_ticket.state.deactivate(slot, epoch_length) {
+            msg!("Resolver is not ready to be deactivated");
+            return Err(ResolverError::NcnResolverTicketFailedCooldown);
+        }
+
+        // Only active tickets can be cooled down, so the resolver was warmed up in an earlier
+        // epoch and is settled into the active count
+        self.settle_warming_resolvers(slot, epoch_length)?;
+        let count = u64::from(self.active_resolver_count)
+            .checked_sub(1)
+            .ok_or(ResolverError::ArithmeticUnderflow)?;
+        self.active_resolver_count = PodU64::from(count);
+
+        Ok(())
+    }
+
+    /// Moves the resolvers warmed up in an epoch before the one of `slot` to the active count
+    fn settle_warming_resolvers(
+        &mut self,
+        slot: u64,
+        epoch_length: u64,
+    ) -> Result<(), ResolverError> {
+        if epoch(slot, epoch_length)? > self.warmup_epoch() {
+            self.active_resolver_count =
+                PodU64::from(self.active_resolver_count(slot, epoch_length)?);
+            self.warming_resolver_count = PodU64::from(0);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the number of vetoes needed to veto a slash proposal, the veto threshold bounded by
+    /// the active resolvers so that cooling down resolvers can't make a veto unreachable
+    pub fn effective_veto_threshold(
+        &self,
+        slot: u64,
+        epoch_length: u64,
+    ) -> Result<u64, ResolverError> {
+        Ok(self
+            .veto_threshold()
+            .min(self.active_resolver_count(slot, epoch_length)?)
+            .max(1))
+    }
+
+    /// Checks enough resolvers are active to reach the veto threshold before a resolver is
+    /// assigned to a slash proposal
+    pub fn check_veto_threshold_reachable(
+        &self,
+        slot: u64,
+        epoch_length: u64,
+    ) -> Result<(), ResolverError> {
+        let active_resolver_count = self.active_resolver_count(slot, epoch_length)?;
+        if self.veto_threshold() > active_resolver_count {
+            msg!(
+                "Veto threshold {} exceeds the {} active resolvers",
+                self.veto_threshold(),
+                active_resolver_count
+            );
+            return Err(ResolverError::NcnResolverProgramConfigVetoThresholdUnreachable);
+        }
+
+        Ok(())
+    }
+
+    pub fn require_resolver_assignment(&self) -> bool {
+        self.require_resolver_assignment.into()
+    }
+
+    pub fn assignment_policy(&self) -> Result<ResolverAssignmentPolicy, ResolverError> {
+        ResolverAssignmentPolicy::try_from(self.assignment_policy)
+    }
+
+    pub fn set_assignment_policy(
+        &mut self,
+        assignment_policy: ResolverAssignmentPolicy,
+        default_resolver: Pubkey,
+    ) {
+        self.assignment_policy = assignment_policy as u8;
+        self.default_resolver = default_resolver;
+    }
+
+    /// Returns the resolver index to assign with the RoundRobin policy and moves to the next one,
+    /// or `None` if no resolver is registered
+    pub fn next_round_robin_index(&mut self) -> Option<u64> {
+        let resolver_count = self.resolver_count();
+        if resolver_count == 0 {
+            return None;
+        }
+
+        let index = u64::from(self.next_assignment_index) % resolver_count;
+        self.next_assignment_index = PodU64::from((index + 1) % resolver_count);
+        Some(index)
+    }
+
+    pub fn bond_amount(&self) -> u64 {
+        self.bond_amount.into()
+    }
+
+    pub fn set_bond(&mut self, bond_mint: Pubkey, bond_amount: u64, bond_sink: Pubkey) {
+        self.bond_mint = bond_mint;
+        self.bond_amount = PodU64::from(bond_amount);
+        self.bond_sink = bond_sink;
+    }
+
+    /// Returns where a forfeited bond of a proposal against `operator` is sent
+    pub fn bond_recipient(&self, operator: &Pubkey) -> Pubkey {
+        if self.bond_sink.eq(&Pubkey::default()) {
+            *operator
+        } else {
+            self.bond_sink
+        }
+    }
+
+    pub fn resolver_penalty_bps(&self) -> u16 {
+        self.resolver_penalty_bps.into()
+    }
+
+    pub fn set_resolver_penalty_bps(
+        &mut self,
+        resolver_penalty_bps: u16,
+    ) -> Result<(), ResolverError> {
+        if resolver_penalty_bps > Self::MAX_BPS {
+            msg!(
+                "Resolver penalty {} exceeds {} bps",
+                resolver_penalty_bps,
+                Self::MAX_BPS
+            );
+            return Err(ResolverError::NcnResolverProgramConfigResolverPenaltyInvalid);
+        }
+        self.resolver_penalty_bps = PodU16::from(resolver_penalty_bps);
+
+        Ok(())
+    }
+
+    /// Returns the share of `bond_amount` forfeited by a resolver missing a required decision
+    pub fn resolver_penalty(&self, bond_amount: u64) -> Result<u64, ResolverError> {
+        bps_share(bond_amount, self.resolver_penalty_bps())
+    }
+
+    pub fn proceeds_burn_bps(&self) -> u16 {
+        self.proceeds_burn_bps.into()
+    }
+
+    pub fn proceeds_treasury_bps(&self) -> u16 {
+        self.proceeds_treasury_bps.into()
+    }
+
+    pub fn proceeds_insurance_fund_bps(&self) -> u16 {
+        self.proceeds_insurance_fund_bps.into()
+    }
+
+    /// Sets how slashed funds are routed, the slasher keeps the share left over by the burn,
+    /// treasury and insurance fund shares
+    pub fn set_proceeds(
+        &mut self,
+        burn_bps: u16,
+        treasury: Pubkey,
+        treasury_bps: u16,
+        insurance_fund: Pubkey,
+        insurance_fund_bps: u16,
+    ) -> Result<(), ResolverError> {
+        let total_bps = burn_bps as u32 + treasury_bps as u32 + insurance_fund_bps as u32;
+        if total_bps > Self::MAX_BPS as u32 {
+            msg!(
+                "Slash proceeds split {} exceeds {} bps",
+                total_bps,
+                Self::MAX_BPS
+            );
+            return Err(ResolverError::NcnResolverProgramConfigProceedsInvalid);
+        }
+        if treasury_bps > 0 && treasury.eq(&Pubkey::default()) {
+            msg!("Slash proceeds treasury is not set");
+            return Err(ResolverError::NcnResolverProgramConfigProceedsInvalid);
+        }
+        if insurance_fund_bps > 0 && insurance_fund.eq(&Pubkey::default()) {
+            msg!("Slash proceeds insurance fund is not set");
+            return Err(ResolverError::NcnResolverProgramConfigProceedsInvalid);
+        }
+
+        self.proceeds_burn_bps = PodU16::from(burn_bps);
+        self.proceeds_treasury = treasury;
+        self.proceeds_treasury_bps = PodU16::from(treasury_bps);
+        self.proceeds_insurance_fund = insurance_fund;
+        self.proceeds_insurance_fund_bps = PodU16::from(insurance_fund_bps);
+
+        Ok(())
+    }
+
+    /// Splits `amount` slashed funds between the burn, the treasury, the insurance fund and the
+    /// slasher, which keeps the rounding leftover
+    pub fn split_proceeds(&self, amount: u64) -> Result<SlashProceeds, ResolverError> {
+        let burn = bps_share(amount, self.proceeds_burn_bps())?;
+        let treasury = bps_share(amount, self.proceeds_treasury_bps())?;
+        let insurance_fund = bps_share(amount, self.proceeds_insurance_fund_bps())?;
+        let slasher = amount
+            .checked_sub(burn)
+            .and_then(|amount| amount.checked_sub(treasury))
+            .and_then(|amount| amount.checked_sub(insurance_fund))
+            .ok_or(ResolverError::ArithmeticUnderflow)?;
+
+        Ok(SlashProceeds {
+            burn,
+            treasury,
+            insurance_fund,
+            slasher,
+        })
+    }
+
+    pub fn set_veto_duration(&mut self, veto_duration: u64) {
+        self.veto_duration = PodU64::from(veto_duration);
+    }
+
+    pub fn set_delete_slash_proposal_duration(&mut self, delete_slash_proposal_duration: u64) {
+        self.delete_slash_proposal_duration = PodU64::from(delete_slash_proposal_duration);
+    }
+
+    pub fn appeal_extension_slots(&self) -> u64 {
+        self.appeal_extension_slots.into()
+    }
+
+    pub fn set_appeal_extension_slots(&mut self, appeal_extension_slots: u64) {
+        self.appeal_extension_slots = PodU64::from(appeal_extension_slots);
+    }
+
+    /// Checks the veto and delete durations don't exceed [`Self::MAX_DURATION_SLOTS`]
+    pub fn check_durations(&self) -> Result<(), ResolverError> {
+        if self.veto_duration() > Self::MAX_DURATION_SLOTS
+            || self.delete_slash_proposal_duration() > Self::MAX_DURATION_SLOTS
+        {
+            msg!(
+                "Veto duration {} or delete duration {} exceeds {} slots",
+                self.veto_duration(),
+                self.delete_slash_proposal_duration(),
+                Self::MAX_DURATION_SLOTS
+            );
+            return Err(ResolverError::NcnResolverProgramConfigDurationInvalid);
+        }
+
+        Ok(())
+    }
+
+    /// Checks the appeal extension doesn't exceed the veto duration
+    pub fn check_appeal_extension_slots(&self) -> Result<(), ResolverError> {
+        if self.appeal_extension_slots() > self.veto_duration() {
+            msg!(
+                "Appeal extension {} exceeds the veto duration {}",
+                self.appeal_extension_slots(),
+                self.veto_duration()
+            );
+            return Err(ResolverError::NcnResolverProgramConfigAppealExtensionInvalid);
+        }
+
+        Ok(())
+    }
+
+    pub fn check_resolver_admin(&self, resolver_admin: &Pubkey) -> Result<(), ResolverError> {
+        if self.resolver_admin.ne(resolver_admin) {
+            msg!("Admin is not the resolver admin");
+            return Err(ResolverError::NcnResolverProgramConfigAdminInvalid);
+        }
+
+        Ok(())
+    }
+
     pub fn increment_resolver_count(&mut self) {
         let mut count = self.resolver_count();
         count += 1;
         self.resolver_count = PodU64::from(count);
     }
 
+    pub fn decrement_resolver_count(&mut self) -> Result<(), ResolverError> {
+        let count = self
+            .resolver_count()
+            .checked_sub(1)
+            .ok_or(ResolverError::ArithmeticUnderflow)?;
+        self.resolver_count = PodU64::from(count);
+
+        Ok(())
+    }
+
     pub fn seeds(ncn: &Pubkey) -> Vec<Vec<u8>> {
         Vec::from_iter([
             b"ncn_resolver_program_config".to_vec(),
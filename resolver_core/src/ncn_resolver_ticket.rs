@@ -0,0 +1,123 @@
+use bytemuck::{Pod, Zeroable};
+use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
+use jito_jsm_core::slot_toggle::SlotToggle;
+use resolver_sdk::error::ResolverError;
+use shank::ShankAccount;
+use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+
+/// Records that an NCN approved a resolver. The resolver can only be assigned to slash
+/// proposals or veto them while the ticket is active.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, AccountDeserialize, ShankAccount)]
+#[repr(C)]
+pub struct NcnResolverTicket {
+    /// The NCN account
+    pub ncn: Pubkey,
+
+    /// The resolver account
+    pub resolver: Pubkey,
+
+    /// The index of the resolver in the NCN registry, the assignment policies pick from the
+    /// indices below the NCN resolver count
+    index: PodU64,
+
+    /// The warmup and cooldown state of the resolver for the NCN
+    pub state: SlotToggle,
+
+    /// The bump seed for the PDA
+    pub bump: u8,
+}
+
+impl Discriminator for NcnResolverTicket {
+    const DISCRIMINATOR: u8 = 8;
+}
+
+impl NcnResolverTicket {
+    pub fn new(ncn: Pubkey, resolver: Pubkey, index: u64, slot: u64, bump: u8) -> Self {
+        Self {
+            ncn,
+            resolver,
+            index: PodU64::from(index),
+            state: SlotToggle::new(slot),
+            bump,
+        }
+    }
+
+    pub fn index(&self) -> u64 {
+        self.index.into()
+    }
+
+    pub fn set_index(&mut self, index: u64) {
+        self.index = PodU64::from(index);
+    }
+
+    pub fn check_active(&self, slot: u64, epoch_length: u64) -> Result<(), ResolverError> {
+        if !self.state.is_active(slot, epoch_length) {
+            msg!("NcnResolverTicket is not active");
+            return Err(ResolverError::NcnResolverTicketInactive);
+        }
+
+        Ok(())
+    }
+
+    pub fn seeds(ncn: &Pubkey, resolver: &Pubkey) -> Vec<Vec<u8>> {
+        Vec::from_iter([
+            b"ncn_resolver_ticket".to_vec(),
+            ncn.as_ref().to_vec(),
+            resolver.as_ref().to_vec(),
+        ])
+    }
+
+    pub fn find_program_address(
+        program_id: &Pubkey,
+        ncn: &Pubkey,
+        resolver: &Pubkey,
+    ) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds = Self::seeds(ncn, resolver);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
+        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
+        (pda, bump, seeds)
+    }
+
+    /// Loads the account as an [`NcnResolverTicket`] account, returning an error if it is not.
+    ///
+    /// # Arguments
+    /// * `program_id` - The program ID
+    /// * `ncn_resolver_ticket` - The account to load the NcnResolverTicket from
+    /// * `ncn` - The NCN that approved the resolver
+    /// * `resolver` - The resolver approved by the NCN
+    /// * `expect_writable` - Whether the account should be writable
+    ///
+    /// # Returns
+    /// * `Result<(), ProgramError>` - The result of the operation
+    pub fn load(
+        program_id: &Pubkey,
+        ncn_resolver_ticket: &AccountInfo,
+        ncn: &AccountInfo,
+        resolver: &AccountInfo,
+        expect_writable: bool,
+    ) -> Result<(), ProgramError> {
+        if ncn_resolver_ticket.owner.ne(program_id) {
+            msg!("NcnResolverTicket account has an invalid owner");
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        if ncn_resolver_ticket.data_is_empty() {
+            msg!("NcnResolverTicket account data is empty");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if expect_writable && !ncn_resolver_ticket.is_writable {
+            msg!("NcnResolverTicket account is not writable");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if ncn_resolver_ticket.data.borrow()[0].ne(&Self::DISCRIMINATOR) {
+            msg!("NcnResolverTicket account discriminator is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let expected_pubkey = Self::find_program_address(program_id, ncn.key, resolver.key).0;
+        if ncn_resolver_ticket.key.ne(&expected_pubkey) {
+            msg!("NcnResolverTicket account is not at the correct PDA");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Ok(())
+    }
+}
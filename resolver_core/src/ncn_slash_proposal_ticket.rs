@@ -1,5 +1,8 @@
 use bytemuck::{Pod, Zeroable};
-use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_bytemuck::{
+    types::{PodBool, PodU64},
+    AccountDeserialize, Discriminator,
+};
 use resolver_sdk::error::ResolverError;
 use shank::ShankAccount;
 use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
@@ -16,6 +19,15 @@ pub struct NcnSlashProposalTicket {
 
     pub resolver: Pubkey,
 
+    /// The number of resolvers that vetoed the slash proposal
+    veto_count: PodU64,
+
+    /// Whether the NCN requires the assigned resolver to veto or approve the slash proposal
+    decision_required: PodBool,
+
+    /// Whether the assigned resolver was penalized for missing the required decision
+    resolver_penalized: PodBool,
+
     /// The bump seed for the PDA
     pub bump: u8,
     // Reserved space
@@ -34,6 +46,9 @@ impl NcnSlashProposalTicket {
             ncn,
             slash_proposal,
             resolver: Pubkey::default(),
+            veto_count: PodU64::from(0),
+            decision_required: PodBool::from_bool(false),
+            resolver_penalized: PodBool::from_bool(false),
             bump,
             // reserved: [0; 263],
         }
@@ -43,6 +58,58 @@ impl NcnSlashProposalTicket {
         self.resolver = new_resolver;
     }
 
+    pub fn veto_count(&self) -> u64 {
+        self.veto_count.into()
+    }
+
+    /// Records a resolver veto and returns whether the veto threshold has been reached
+    ///
+    /// # Arguments
+    /// * `veto_threshold` - The number of vetoes required to veto the slash proposal
+    pub fn record_veto(&mut self, veto_threshold: u64) -> Result<bool, ResolverError> {
+        let veto_count = self
+            .veto_count()
+            .checked_add(1)
+            .ok_or(ResolverError::ArithmeticOverflow)?;
+        self.veto_count = PodU64::from(veto_count);
+
+        Ok(veto_count >= veto_threshold)
+    }
+
+    pub fn decision_required(&self) -> bool {
+        self.decision_required.into()
+    }
+
+    pub fn require_decision(&mut self) {
+        self.decision_required = PodBool::from_bool(true);
+    }
+
+    /// Marks the assigned resolver penalized, returning an error if it can't be penalized
+    pub fn penalize_resolver(&mut self) -> Result<(), ResolverError> {
+        if !self.decision_required() {
+            msg!("Slash proposal does not require a resolver decision");
+            return Err(ResolverError::SlashProposalDecisionNotRequired);
+        }
+        if self.resolver_penalized.into() {
+            msg!("Resolver was already penalized for the slash proposal");
+            return Err(ResolverError::SlashProposalResolverAlreadyPenalized);
+        }
+        self.resolver_penalized = PodBool::from_bool(true);
+
+        Ok(())
+    }
+
+    /// Approving a slash proposal ends its veto period early, so it's only allowed while no
+    /// resolver vetoed it
+    pub fn check_no_vetoes(&self) -> Result<(), ResolverError> {
+        if self.veto_count() > 0 {
+            msg!("Slash proposal has {} vetoes", self.veto_count());
+            return Err(ResolverError::SlashProposalHasVetoes);
+        }
+
+        Ok(())
+    }
+
     pub fn check_slash_proposal(&self, slash_proposal: &Pubkey) -> Result<(), ResolverError> {
         if self.slash_proposal.ne(slash_proposal) {
             msg!("Slash proposal is incorrect");
@@ -52,6 +119,11 @@ impl NcnSlashProposalTicket {
         Ok(())
     }
 
+    /// Whether a resolver was ever assigned to the slash proposal
+    pub fn resolver_assigned(&self) -> bool {
+        self.resolver.ne(&Pubkey::default())
+    }
+
     pub fn check_resolver(&self, resolver: &Pubkey) -> Result<(), ResolverError> {
         if self.resolver.ne(resolver) {
             msg!("Slash proposal's resolver is incorrect");
@@ -13,8 +13,31 @@ pub struct Resolver {
     /// The admin pubkey
     pub admin: Pubkey,
 
-    /// The resolver index
-    index: PodU64,
+    /// The admin allowed to veto or approve slash proposals
+    pub veto_admin: Pubkey,
+
+    /// Reserved for the admin of the resolver metadata, the resolver has no metadata yet so no
+    /// instruction checks it
+    pub metadata_admin: Pubkey,
+
+    /// The NCN the resolver was initialized for, other NCNs are linked with a ResolverNcnTicket
+    pub ncn: Pubkey,
+
+    /// The mint of the bond locked by the resolver, the default pubkey until its first deposit
+    pub bond_mint: Pubkey,
+
+    /// The bond locked in the token account owned by the resolver
+    bond_amount: PodU64,
+
+    /// The slot the resolver deregistered at, zero while it is registered
+    deregistered_slot: PodU64,
+
+    /// The number of slash proposals the resolver is assigned to that were not closed yet
+    open_assignments: PodU64,
+
+    /// The number of NCNs with a warmed up NcnResolverTicket of the resolver that wasn't cooled
+    /// down yet, all of them are cooled down when the resolver deregisters
+    active_ncn_count: PodU64,
 
     /// The bump seed for the PDA
     pub bump: u8,
@@ -25,17 +48,175 @@ impl Discriminator for Resolver {
 }
 
 impl Resolver {
-    pub fn new(base: Pubkey, admin: Pubkey, index: u64, bump: u8) -> Self {
+    /// The number of epochs a deregistered resolver waits before withdrawing its bond, it must
+    /// also wait for the proposals it was assigned to be closed, as it can be penalized until then
+    pub const BOND_COOLDOWN_EPOCHS: u64 = 2;
+
+    pub fn new(base: Pubkey, admin: Pubkey, ncn: Pubkey, bump: u8) -> Self {
         Self {
             base,
             admin,
-            index: PodU64::from(index),
+            veto_admin: admin,
+            metadata_admin: admin,
+            ncn,
+            bond_mint: Pubkey::default(),
+            bond_amount: PodU64::from(0),
+            deregistered_slot: PodU64::from(0),
+            open_assignments: PodU64::from(0),
+            active_ncn_count: PodU64::from(0),
             bump,
         }
     }
 
-    pub fn index(&self) -> u64 {
-        self.index.into()
+    pub fn bond_amount(&self) -> u64 {
+        self.bond_amount.into()
+    }
+
+    pub fn deregistered_slot(&self) -> u64 {
+        self.deregistered_slot.into()
+    }
+
+    pub fn is_deregistered(&self) -> bool {
+        self.deregistered_slot() != 0
+    }
+
+    /// Deregistered resolvers can no longer be assigned to or decide slash proposals
+    pub fn check_registered(&self) -> Result<(), ResolverError> {
+        if self.is_deregistered() {
+            msg!("Resolver is deregistered");
+            return Err(ResolverError::ResolverDeregistered);
+        }
+
+        Ok(())
+    }
+
+    pub fn open_assignments(&self) -> u64 {
+        self.open_assignments.into()
+    }
+
+    /// Records an assignment to a slash proposal, keeping the bond locked until it is closed
+    pub fn open_assignment(&mut self) -> Result<(), ResolverError> {
+        self.check_registered()?;
+        let open_assignments = self
+            .open_assignments()
+            .checked_add(1)
+            .ok_or(ResolverError::ArithmeticOverflow)?;
+        self.open_assignments = PodU64::from(open_assignments);
+
+        Ok(())
+    }
+
+    /// Releases an assignment once the slash proposal is closed or assigned to another resolver
+    pub fn close_assignment(&mut self) -> Result<(), ResolverError> {
+        let open_assignments = self
+            .open_assignments()
+            .checked_sub(1)
+            .ok_or(ResolverError::ArithmeticUnderflow)?;
+        self.open_assignments = PodU64::from(open_assignments);
+
+        Ok(())
+    }
+
+    pub fn active_ncn_count(&self) -> u64 {
+        self.active_ncn_count.into()
+    }
+
+    /// Records a warmed up NcnResolverTicket, deregistered resolvers can't be warmed up again
+    pub fn increment_active_ncn_count(&mut self) -> Result<(), ResolverError> {
+        self.check_registered()?;
+        let active_ncn_count = self
+            .active_ncn_count()
+            .checked_add(1)
+            .ok_or(ResolverError::ArithmeticOverflow)?;
+        self.active_ncn_count = PodU64::from(active_ncn_count);
+
+        Ok(())
+    }
+
+    pub fn decrement_active_ncn_count(&mut self) -> Result<(), ResolverError> {
+        let active_ncn_count = self
+            .active_ncn_count()
+            .checked_sub(1)
+            .ok_or(ResolverError::ArithmeticUnderflow)?;
+        self.active_ncn_count = PodU64::from(active_ncn_count);
+
+        Ok(())
+    }
+
+    /// Adds `amount` to the bond, the mint is set by the first deposit
+    pub fn deposit_bond(&mut self, bond_mint: &Pubkey, amount: u64) -> Result<(), ResolverError> {
+        self.check_registered()?;
+        if self.bond_mint.eq(&Pubkey::default()) {
+            self.bond_mint = *bond_mint;
+        } else if self.bond_mint.ne(bond_mint) {
+            msg!("Bond mint is not the resolver bond mint");
+            return Err(ResolverError::ResolverBondMintInvalid);
+        }
+
+        let bond_amount = self
+            .bond_amount()
+            .checked_add(amount)
+            .ok_or(ResolverError::ArithmeticOverflow)?;
+        self.bond_amount = PodU64::from(bond_amount);
+
+        Ok(())
+    }
+
+    /// Removes a penalty from the bond, returning the amount actually forfeited
+    pub fn forfeit_bond(&mut self, penalty: u64) -> u64 {
+        let penalty = penalty.min(self.bond_amount());
+        self.bond_amount = PodU64::from(self.bond_amount() - penalty);
+        penalty
+    }
+
+    /// Deregisters the resolver, its NcnResolverTickets must all be cooled down first so it stops
+    /// counting towards the veto threshold of the NCNs
+    pub fn deregister(&mut self, slot: u64) -> Result<(), ResolverError> {
+        if self.is_deregistered() {
+            msg!("Resolver is already deregistered");
+            return Err(ResolverError::ResolverDeregistered);
+        }
+        if self.active_ncn_count() > 0 {
+            msg!(
+                "Resolver still has {} active NcnResolverTickets",
+                self.active_ncn_count()
+            );
+            return Err(ResolverError::ResolverNcnTicketsActive);
+        }
+        self.deregistered_slot = PodU64::from(slot);
+
+        Ok(())
+    }
+
+    /// Clears the bond once the cooldown after deregistering ended, returning its amount
+    pub fn withdraw_bond(&mut self, slot: u64, epoch_length: u64) -> Result<u64, ResolverError> {
+        if !self.is_deregistered() {
+            msg!("Resolver is not deregistered");
+            return Err(ResolverError::ResolverNotDeregistered);
+        }
+
+        let cooldown_end_slot = epoch_length
+            .checked_mul(Self::BOND_COOLDOWN_EPOCHS)
+            .and_then(|cooldown| self.deregistered_slot().checked_add(cooldown))
+            .ok_or(ResolverError::ArithmeticOverflow)?;
+        if slot < cooldown_end_slot {
+            msg!(
+                "Resolver bond can be withdrawn from slot {}",
+                cooldown_end_slot
+            );
+            return Err(ResolverError::ResolverBondCooldownNotEnded);
+        }
+        if self.open_assignments() > 0 {
+            msg!(
+                "Resolver is still assigned to {} slash proposals",
+                self.open_assignments()
+            );
+            return Err(ResolverError::ResolverAssignmentsOpen);
+        }
+
+        let bond_amount = self.bond_amount();
+        self.bond_amount = PodU64::from(0);
+        Ok(bond_amount)
     }
 
     pub fn check_admin(&self, candidate_resolver_admin: &Pubkey) -> Result<(), ResolverError> {
@@ -47,10 +228,47 @@ impl Resolver {
         Ok(())
     }
 
+    pub fn check_veto_admin(&self, candidate_veto_admin: &Pubkey) -> Result<(), ResolverError> {
+        if self.veto_admin.ne(candidate_veto_admin) {
+            msg!("Resolver veto admin is incorrect");
+            return Err(ResolverError::ResolverVetoAdminInvalid);
+        }
+
+        Ok(())
+    }
+
+    /// Replaces the secondary admins still held by the old admin with the new admin
+    pub fn update_secondary_admin(&mut self, old_admin: &Pubkey, new_admin: &Pubkey) {
+        if self.veto_admin.eq(old_admin) {
+            self.veto_admin = *new_admin;
+            msg!("Veto admin set to {:?}", new_admin);
+        }
+
+        if self.metadata_admin.eq(old_admin) {
+            self.metadata_admin = *new_admin;
+            msg!("Metadata admin set to {:?}", new_admin);
+        }
+    }
+
+    pub fn check_ncn(&self, ncn: &Pubkey) -> Result<(), ResolverError> {
+        if self.ncn.ne(ncn) {
+            msg!("Resolver is not registered for the NCN");
+            return Err(ResolverError::ResolverNcnInvalid);
+        }
+
+        Ok(())
+    }
+
     pub fn seeds(base: &Pubkey) -> Vec<Vec<u8>> {
         Vec::from_iter([b"resolver".to_vec(), base.as_ref().to_vec()])
     }
 
+    pub fn signing_seeds(&self) -> Vec<Vec<u8>> {
+        let mut resolver_seeds = Self::seeds(&self.base);
+        resolver_seeds.push(vec![self.bump]);
+        resolver_seeds
+    }
+
     pub fn find_program_address(program_id: &Pubkey, base: &Pubkey) -> (Pubkey, u8, Vec<Vec<u8>>) {
         let seeds = Self::seeds(base);
         let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
@@ -0,0 +1,95 @@
+use bytemuck::{Pod, Zeroable};
+use jito_bytemuck::{AccountDeserialize, Discriminator};
+use shank::ShankAccount;
+use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+
+/// Links a resolver to an NCN other than the one it was initialized for, so a single resolver
+/// can be shared across NCNs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, AccountDeserialize, ShankAccount)]
+#[repr(C)]
+pub struct ResolverNcnTicket {
+    /// The resolver account
+    pub resolver: Pubkey,
+
+    /// The NCN account
+    pub ncn: Pubkey,
+
+    /// The bump seed for the PDA
+    pub bump: u8,
+}
+
+impl Discriminator for ResolverNcnTicket {
+    const DISCRIMINATOR: u8 = 9;
+}
+
+impl ResolverNcnTicket {
+    pub fn new(resolver: Pubkey, ncn: Pubkey, bump: u8) -> Self {
+        Self {
+            resolver,
+            ncn,
+            bump,
+        }
+    }
+
+    pub fn seeds(resolver: &Pubkey, ncn: &Pubkey) -> Vec<Vec<u8>> {
+        Vec::from_iter([
+            b"resolver_ncn_ticket".to_vec(),
+            resolver.as_ref().to_vec(),
+            ncn.as_ref().to_vec(),
+        ])
+    }
+
+    pub fn find_program_address(
+        program_id: &Pubkey,
+        resolver: &Pubkey,
+        ncn: &Pubkey,
+    ) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds = Self::seeds(resolver, ncn);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
+        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
+        (pda, bump, seeds)
+    }
+
+    /// Loads the account as an [`ResolverNcnTicket`] account, returning an error if it is not.
+    ///
+    /// # Arguments
+    /// * `program_id` - The program ID
+    /// * `resolver_ncn_ticket` - The account to load the ResolverNcnTicket from
+    /// * `resolver` - The resolver shared with the NCN
+    /// * `ncn` - The NCN the resolver opted into
+    /// * `expect_writable` - Whether the account should be writable
+    ///
+    /// # Returns
+    /// * `Result<(), ProgramError>` - The result of the operation
+    pub fn load(
+        program_id: &Pubkey,
+        resolver_ncn_ticket: &AccountInfo,
+        resolver: &AccountInfo,
+        ncn: &AccountInfo,
+        expect_writable: bool,
+    ) -> Result<(), ProgramError> {
+        if resolver_ncn_ticket.owner.ne(program_id) {
+            msg!("ResolverNcnTicket account has an invalid owner");
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        if resolver_ncn_ticket.data_is_empty() {
+            msg!("ResolverNcnTicket account data is empty");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if expect_writable && !resolver_ncn_ticket.is_writable {
+            msg!("ResolverNcnTicket account is not writable");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if resolver_ncn_ticket.data.borrow()[0].ne(&Self::DISCRIMINATOR) {
+            msg!("ResolverNcnTicket account discriminator is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let expected_pubkey = Self::find_program_address(program_id, resolver.key, ncn.key).0;
+        if resolver_ncn_ticket.key.ne(&expected_pubkey) {
+            msg!("ResolverNcnTicket account is not at the correct PDA");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,114 @@
+use bytemuck::{Pod, Zeroable};
+use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
+use shank::ShankAccount;
+use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+
+/// Records a veto vote cast by a resolver on a slash proposal.
+/// The PDA can only be created once per (slash proposal, resolver) pair.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, AccountDeserialize, ShankAccount)]
+#[repr(C)]
+pub struct ResolverVote {
+    /// The slash proposal account
+    pub slash_proposal: Pubkey,
+
+    /// The resolver account that cast the vote
+    pub resolver: Pubkey,
+
+    /// The slot the vote was cast at
+    slot: PodU64,
+
+    /// The account that paid the rent, refunded when the vote is closed
+    pub payer: Pubkey,
+
+    /// The bump seed for the PDA
+    pub bump: u8,
+}
+
+impl Discriminator for ResolverVote {
+    const DISCRIMINATOR: u8 = 7;
+}
+
+impl ResolverVote {
+    pub fn new(
+        slash_proposal: Pubkey,
+        resolver: Pubkey,
+        slot: u64,
+        payer: Pubkey,
+        bump: u8,
+    ) -> Self {
+        Self {
+            slash_proposal,
+            resolver,
+            slot: PodU64::from(slot),
+            payer,
+            bump,
+        }
+    }
+
+    pub fn slot(&self) -> u64 {
+        self.slot.into()
+    }
+
+    pub fn seeds(slash_proposal: &Pubkey, resolver: &Pubkey) -> Vec<Vec<u8>> {
+        Vec::from_iter([
+            b"resolver_vote".to_vec(),
+            slash_proposal.as_ref().to_vec(),
+            resolver.as_ref().to_vec(),
+        ])
+    }
+
+    pub fn find_program_address(
+        program_id: &Pubkey,
+        slash_proposal: &Pubkey,
+        resolver: &Pubkey,
+    ) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds = Self::seeds(slash_proposal, resolver);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
+        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
+        (pda, bump, seeds)
+    }
+
+    /// Loads the account as an [`ResolverVote`] account, returning an error if it is not.
+    ///
+    /// # Arguments
+    /// * `program_id` - The program ID
+    /// * `resolver_vote` - The account to load the ResolverVote from
+    /// * `slash_proposal` - The slash proposal the vote was cast on
+    /// * `resolver` - The resolver that cast the vote
+    /// * `expect_writable` - Whether the account should be writable
+    ///
+    /// # Returns
+    /// * `Result<(), ProgramError>` - The result of the operation
+    pub fn load(
+        program_id: &Pubkey,
+        resolver_vote: &AccountInfo,
+        slash_proposal: &AccountInfo,
+        resolver: &AccountInfo,
+        expect_writable: bool,
+    ) -> Result<(), ProgramError> {
+        if resolver_vote.owner.ne(program_id) {
+            msg!("ResolverVote account has an invalid owner");
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        if resolver_vote.data_is_empty() {
+            msg!("ResolverVote account data is empty");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if expect_writable && !resolver_vote.is_writable {
+            msg!("ResolverVote account is not writable");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if resolver_vote.data.borrow()[0].ne(&Self::DISCRIMINATOR) {
+            msg!("ResolverVote account discriminator is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let expected_pubkey =
+            Self::find_program_address(program_id, slash_proposal.key, resolver.key).0;
+        if resolver_vote.key.ne(&expected_pubkey) {
+            msg!("ResolverVote account is not at the correct PDA");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Ok(())
+    }
+}
@@ -3,10 +3,105 @@ use jito_bytemuck::{
     types::{PodBool, PodU64},
     AccountDeserialize, Discriminator,
 };
-use resolver_sdk::error::ResolverError;
-use shank::ShankAccount;
+use resolver_sdk::{
+    error::ResolverError,
+    instruction::{SlashAllocation, SlashReason},
+};
+use shank::{ShankAccount, ShankType};
 use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
 
+/// The lifecycle status of a [`SlashProposal`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum SlashProposalStatus {
+    /// Proposed by the slasher, waiting for a resolver
+    Proposed,
+    /// A resolver has been assigned by the NCN resolver admin
+    ResolverAssigned,
+    /// Vetoed by the resolvers
+    Vetoed,
+    /// Approved by the assigned resolver before the veto deadline
+    Approved,
+    /// Executed by the slasher
+    Executed,
+    /// Cancelled by the slasher
+    Cancelled,
+    /// Never completed before the delete deadline, only reported by the ProposalDeleted event as
+    /// the proposal is closed when it expires
+    Expired,
+}
+
+impl SlashProposalStatus {
+    /// Whether the proposal reached a final outcome
+    pub const fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            Self::Vetoed | Self::Executed | Self::Cancelled | Self::Expired
+        )
+    }
+
+    /// The error returned when a proposal can not move into this status
+    const fn transition_error(&self) -> ResolverError {
+        match self {
+            Self::Proposed => ResolverError::SlashProposalStatusInvalid,
+            Self::ResolverAssigned => ResolverError::SlashProposalCannotAssignResolver,
+            Self::Vetoed => ResolverError::SlashProposalCannotVeto,
+            Self::Approved => ResolverError::SlashProposalCannotApprove,
+            Self::Executed => ResolverError::SlashProposalCannotExecute,
+            Self::Cancelled => ResolverError::SlashProposalCannotCancel,
+            Self::Expired => ResolverError::SlashProposalCannotExpire,
+        }
+    }
+}
+
+impl TryFrom<u8> for SlashProposalStatus {
+    type Error = ResolverError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Proposed),
+            1 => Ok(Self::ResolverAssigned),
+            2 => Ok(Self::Vetoed),
+            3 => Ok(Self::Approved),
+            4 => Ok(Self::Executed),
+            5 => Ok(Self::Cancelled),
+            6 => Ok(Self::Expired),
+            _ => Err(ResolverError::SlashProposalStatusInvalid),
+        }
+    }
+}
+
+/// A vault slashed by a [`SlashProposal`] and its share of the slash amount
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, ShankType)]
+#[repr(C)]
+pub struct SlashProposalVault {
+    /// The vault account
+    pub vault: Pubkey,
+
+    /// The stake the vault delegated to the operator when the slash was proposed
+    security: PodU64,
+
+    /// The share of the slash amount allocated to the vault
+    amount: PodU64,
+
+    /// The share already slashed from the vault
+    executed_amount: PodU64,
+}
+
+impl SlashProposalVault {
+    pub fn security(&self) -> u64 {
+        self.security.into()
+    }
+
+    pub fn amount(&self) -> u64 {
+        self.amount.into()
+    }
+
+    pub fn executed_amount(&self) -> u64 {
+        self.executed_amount.into()
+    }
+}
+
 /// The vault configuration account for the vault program.
 /// Manages program-wide settings and state.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, AccountDeserialize, ShankAccount)]
@@ -18,16 +113,66 @@ pub struct SlashProposal {
     /// The operator account
     pub slasher: Pubkey,
 
-    /// The slash amount
+    /// The proposal id, unique per slasher
+    id: PodU64,
+
+    /// The slash amount, lowered by the resolver if it amended the proposal
     amount: PodU64,
 
+    /// The slash amount the slasher proposed
+    original_amount: PodU64,
+
     pub capture_slot: PodU64,
 
     veto_deadline_slot: PodU64,
 
     delete_deadline_slot: PodU64,
 
-    completed: PodBool,
+    /// The delete duration of the NCN when the proposal was created
+    delete_slash_proposal_duration: PodU64,
+
+    /// The [`SlashProposalStatus`] discriminant
+    status: u8,
+
+    /// The [`SlashReason`] discriminant
+    reason: u8,
+
+    /// The hash of the off-chain evidence backing the proposal
+    evidence_hash: [u8; 32],
+
+    /// The length of the metadata URI in bytes
+    metadata_uri_len: u8,
+
+    /// The URI pointing to the proposal metadata
+    metadata_uri: [u8; 128],
+
+    /// The mint of the bond, the default pubkey for a SOL bond
+    pub bond_mint: Pubkey,
+
+    /// The bond held in escrow, zero once it has been returned or forfeited
+    bond_amount: PodU64,
+
+    /// The slasher admin that deposited the bond, it gets the bond back unless the proposal is
+    /// vetoed
+    pub bond_depositor: Pubkey,
+
+    /// The account receiving the bond when the proposal is vetoed
+    pub bond_recipient: Pubkey,
+
+    /// The [`SlashAllocation`] discriminant
+    allocation: u8,
+
+    /// The number of vaults in `vaults`
+    vault_count: u8,
+
+    /// The vaults slashed by the proposal
+    vaults: [SlashProposalVault; 16],
+
+    /// The amount slashed across all vaults so far
+    executed_amount: PodU64,
+
+    /// Whether the operator submitted a SlashProposalAppeal, closed along with the proposal
+    appealed: PodBool,
 
     /// The bump seed for the PDA
     pub bump: u8,
@@ -44,11 +189,27 @@ impl Default for SlashProposal {
         Self {
             operator: Pubkey::default(),
             slasher: Pubkey::default(),
+            id: PodU64::from(0),
             amount: PodU64::from(0),
+            original_amount: PodU64::from(0),
             capture_slot: PodU64::from(0),
             veto_deadline_slot: PodU64::from(0),
             delete_deadline_slot: PodU64::from(0),
-            completed: PodBool::from_bool(false),
+            delete_slash_proposal_duration: PodU64::from(0),
+            status: SlashProposalStatus::Proposed as u8,
+            reason: SlashReason::Downtime as u8,
+            evidence_hash: [0; 32],
+            metadata_uri_len: 0,
+            metadata_uri: [0; Self::MAX_METADATA_URI_LEN],
+            bond_mint: Pubkey::default(),
+            bond_amount: PodU64::from(0),
+            bond_depositor: Pubkey::default(),
+            bond_recipient: Pubkey::default(),
+            allocation: SlashAllocation::ProRata as u8,
+            vault_count: 0,
+            vaults: [SlashProposalVault::zeroed(); Self::MAX_VAULTS],
+            executed_amount: PodU64::from(0),
+            appealed: PodBool::from_bool(false),
             bump: 0,
             // reserved: [0; 263],
         }
@@ -56,49 +217,420 @@ impl Default for SlashProposal {
 }
 
 impl SlashProposal {
+    pub const MAX_METADATA_URI_LEN: usize = 128;
+    pub const MAX_VAULTS: usize = 16;
+
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         operator: Pubkey,
         slasher: Pubkey,
+        id: u64,
         amount: u64,
         capture_slot: u64,
         veto_deadline_slot: u64,
+        delete_deadline_slot: u64,
+        delete_slash_proposal_duration: u64,
+        reason: SlashReason,
+        evidence_hash: [u8; 32],
+        metadata_uri: &str,
         bump: u8,
-    ) -> Self {
-        Self {
+    ) -> Result<Self, ResolverError> {
+        if metadata_uri.len() > Self::MAX_METADATA_URI_LEN {
+            msg!("Metadata URI is too long");
+            return Err(ResolverError::SlashProposalMetadataUriTooLong);
+        }
+        let mut metadata_uri_bytes = [0; Self::MAX_METADATA_URI_LEN];
+        metadata_uri_bytes[..metadata_uri.len()].copy_from_slice(metadata_uri.as_bytes());
+
+        Ok(Self {
             operator,
             slasher,
+            id: PodU64::from(id),
             amount: PodU64::from(amount),
+            original_amount: PodU64::from(amount),
             capture_slot: PodU64::from(capture_slot),
             veto_deadline_slot: PodU64::from(veto_deadline_slot),
-            delete_deadline_slot: PodU64::from(capture_slot),
-            completed: PodBool::from_bool(false),
+            delete_deadline_slot: PodU64::from(delete_deadline_slot),
+            delete_slash_proposal_duration: PodU64::from(delete_slash_proposal_duration),
+            status: SlashProposalStatus::Proposed as u8,
+            reason: reason as u8,
+            evidence_hash,
+            metadata_uri_len: metadata_uri.len() as u8,
+            metadata_uri: metadata_uri_bytes,
+            bond_mint: Pubkey::default(),
+            bond_amount: PodU64::from(0),
+            bond_depositor: Pubkey::default(),
+            bond_recipient: Pubkey::default(),
+            allocation: SlashAllocation::ProRata as u8,
+            vault_count: 0,
+            vaults: [SlashProposalVault::zeroed(); Self::MAX_VAULTS],
+            executed_amount: PodU64::from(0),
+            appealed: PodBool::from_bool(false),
             bump,
             // reserved: [0; 263],
-        }
+        })
+    }
+
+    pub fn id(&self) -> u64 {
+        self.id.into()
     }
 
     pub fn amount(&self) -> u64 {
         self.amount.into()
     }
 
+    pub fn original_amount(&self) -> u64 {
+        self.original_amount.into()
+    }
+
+    /// Lowers the slash amount, returning an error unless `amount` is below the current one
+    pub fn amend_amount(&mut self, amount: u64) -> Result<(), ResolverError> {
+        if amount == 0 || amount >= self.amount() {
+            msg!(
+                "Amended slash amount {} must be positive and below {}",
+                amount,
+                self.amount()
+            );
+            return Err(ResolverError::SlashProposalAmendedAmountInvalid);
+        }
+        self.amount = PodU64::from(amount);
+
+        self.allocate()
+    }
+
+    pub fn allocation(&self) -> Result<SlashAllocation, ResolverError> {
+        SlashAllocation::try_from(self.allocation)
+    }
+
+    pub fn vaults(&self) -> &[SlashProposalVault] {
+        &self.vaults[..self.vault_count as usize]
+    }
+
+    pub fn executed_amount(&self) -> u64 {
+        self.executed_amount.into()
+    }
+
+    /// The amount still to be slashed before the proposal is fully executed
+    pub fn remaining_amount(&self) -> u64 {
+        self.amount().saturating_sub(self.executed_amount())
+    }
+
+    /// Records the vaults slashed by the proposal with the stake they delegated to the
+    /// operator, and splits the slash amount across them
+    ///
+    /// # Arguments
+    /// * `allocation` - How the amount is split across the vaults
+    /// * `vaults` - The vaults and their delegated stake, in allocation order
+    pub fn set_vaults(
+        &mut self,
+        allocation: SlashAllocation,
+        vaults: &[(Pubkey, u64)],
+    ) -> Result<(), ResolverError> {
+        if vaults.is_empty() || vaults.len() > Self::MAX_VAULTS {
+            msg!(
+                "Slash proposal must cover between 1 and {} vaults",
+                Self::MAX_VAULTS
+            );
+            return Err(ResolverError::SlashProposalVaultCountInvalid);
+        }
+
+        for (index, (vault, security)) in vaults.iter().enumerate() {
+            if vaults[..index].iter().any(|(other, _)| other.eq(vault)) {
+                msg!("Vault {} is passed more than once", vault);
+                return Err(ResolverError::SlashProposalVaultInvalid);
+            }
+            self.vaults[index] = SlashProposalVault {
+                vault: *vault,
+                security: PodU64::from(*security),
+                amount: PodU64::from(0),
+                executed_amount: PodU64::from(0),
+            };
+        }
+        self.vault_count = vaults.len() as u8;
+        self.allocation = allocation as u8;
+
+        self.allocate()
+    }
+
+    /// Splits the slash amount across the vaults, pro-rata to their delegated stake or by
+    /// filling each vault up to its stake in order
+    fn allocate(&mut self) -> Result<(), ResolverError> {
+        let amount = self.amount();
+        let allocation = self.allocation()?;
+        let vault_count = self.vault_count as usize;
+        let vaults = &mut self.vaults[..vault_count];
+
+        let total_security = vaults.iter().try_fold(0_u64, |total, vault| {
+            total
+                .checked_add(vault.security())
+                .ok_or(ResolverError::ArithmeticOverflow)
+        })?;
+        if amount > total_security {
+            msg!(
+                "Slash amount {} exceeds the operator's delegated stake {}",
+                amount,
+                total_security
+            );
+            return Err(ResolverError::SlashProposalAmountExceedsDelegation);
+        }
+        if amount == 0 {
+            return Ok(());
+        }
+
+        let mut remaining = amount;
+        for vault in vaults.iter_mut() {
+            let share = match allocation {
+                SlashAllocation::ProRata => {
+                    let share = (amount as u128)
+                        .checked_mul(vault.security() as u128)
+                        .ok_or(ResolverError::ArithmeticOverflow)?
+                        .checked_div(total_security as u128)
+                        .ok_or(ResolverError::DivisionByZero)?;
+                    share as u64
+                }
+                SlashAllocation::Ordered => vault.security().min(remaining),
+            };
+            vault.amount = PodU64::from(share);
+            remaining -= share;
+        }
+
+        // The rounding leftover of a pro-rata split goes to the first vaults with stake left
+        for vault in vaults.iter_mut() {
+            if remaining == 0 {
+                break;
+            }
+            let extra = (vault.security() - vault.amount()).min(remaining);
+            vault.amount = PodU64::from(vault.amount() + extra);
+            remaining -= extra;
+        }
+
+        Ok(())
+    }
+
+    /// Marks the share of `vault` slashed, returning the amount to slash from it
+    pub fn record_vault_slash(&mut self, vault: &Pubkey) -> Result<u64, ResolverError> {
+        let vault_count = self.vault_count as usize;
+        let slash_proposal_vault = self.vaults[..vault_count]
+            .iter_mut()
+            .find(|slash_proposal_vault| slash_proposal_vault.vault.eq(vault))
+            .ok_or_else(|| {
+                msg!("Vault {} is not covered by the slash proposal", vault);
+                ResolverError::SlashProposalVaultInvalid
+            })?;
+
+        let amount = slash_proposal_vault
+            .amount()
+            .checked_sub(slash_proposal_vault.executed_amount())
+            .ok_or(ResolverError::ArithmeticUnderflow)?;
+        if amount == 0 {
+            msg!("Vault {} was already slashed", vault);
+            return Err(ResolverError::SlashProposalVaultAlreadySlashed);
+        }
+        slash_proposal_vault.executed_amount = slash_proposal_vault.amount;
+
+        let executed_amount = self
+            .executed_amount()
+            .checked_add(amount)
+            .ok_or(ResolverError::ArithmeticOverflow)?;
+        self.executed_amount = PodU64::from(executed_amount);
+
+        Ok(amount)
+    }
+
     pub fn veto_deadline_slot(&self) -> u64 {
         self.veto_deadline_slot.into()
     }
 
+    /// Pushes the veto and delete deadlines back by `slots`, giving resolvers time to weigh an
+    /// appeal without shortening the window to execute the proposal afterwards
+    pub fn extend_deadlines(&mut self, slots: u64) -> Result<(), ResolverError> {
+        let veto_deadline_slot = self
+            .veto_deadline_slot()
+            .checked_add(slots)
+            .ok_or(ResolverError::ArithmeticOverflow)?;
+        let delete_deadline_slot = self
+            .delete_deadline_slot()
+            .checked_add(slots)
+            .ok_or(ResolverError::ArithmeticOverflow)?;
+        self.veto_deadline_slot = PodU64::from(veto_deadline_slot);
+        self.delete_deadline_slot = PodU64::from(delete_deadline_slot);
+
+        Ok(())
+    }
+
     pub fn delete_deadline_slot(&self) -> u64 {
         self.delete_deadline_slot.into()
     }
 
-    pub fn completed(&self) -> bool {
-        self.completed.into()
+    pub fn delete_slash_proposal_duration(&self) -> u64 {
+        self.delete_slash_proposal_duration.into()
+    }
+
+    pub fn status(&self) -> Result<SlashProposalStatus, ResolverError> {
+        SlashProposalStatus::try_from(self.status)
+    }
+
+    pub fn reason(&self) -> Result<SlashReason, ResolverError> {
+        SlashReason::try_from(self.reason)
+    }
+
+    pub fn evidence_hash(&self) -> &[u8; 32] {
+        &self.evidence_hash
+    }
+
+    pub fn metadata_uri(&self) -> &str {
+        let len = (self.metadata_uri_len as usize).min(Self::MAX_METADATA_URI_LEN);
+        std::str::from_utf8(&self.metadata_uri[..len]).unwrap_or_default()
+    }
+
+    pub fn bond_amount(&self) -> u64 {
+        self.bond_amount.into()
+    }
+
+    /// Records the bond deposited by `depositor` when the proposal is created
+    pub fn set_bond(
+        &mut self,
+        bond_mint: Pubkey,
+        bond_amount: u64,
+        bond_depositor: Pubkey,
+        bond_recipient: Pubkey,
+    ) {
+        self.bond_mint = bond_mint;
+        self.bond_amount = PodU64::from(bond_amount);
+        self.bond_depositor = bond_depositor;
+        self.bond_recipient = bond_recipient;
+    }
+
+    /// Clears the bond held in escrow, returning its amount so it can be paid out
+    pub fn take_bond(&mut self) -> u64 {
+        let bond_amount = self.bond_amount();
+        self.bond_amount = PodU64::from(0);
+        bond_amount
+    }
+
+    pub fn signing_seeds(&self, ncn: &Pubkey) -> Vec<Vec<u8>> {
+        let mut slash_proposal_seeds = Self::seeds(ncn, &self.operator, &self.slasher, self.id());
+        slash_proposal_seeds.push(vec![self.bump]);
+        slash_proposal_seeds
     }
 
     pub fn set_delete_deadline_slot(&mut self, deadline_slot: u64) {
-        self.veto_deadline_slot = PodU64::from(deadline_slot);
+        self.delete_deadline_slot = PodU64::from(deadline_slot);
+    }
+
+    /// Checks that the proposal can move from its current status into `next`
+    ///
+    /// # Arguments
+    /// * `next` - The status to move into
+    ///
+    /// # Returns
+    /// * `Result<(), ResolverError>` - The transition specific error if the move is illegal
+    pub fn check_transition(&self, next: SlashProposalStatus) -> Result<(), ResolverError> {
+        use SlashProposalStatus::*;
+
+        let current = self.status()?;
+        let legal = match next {
+            Proposed => false,
+            ResolverAssigned | Vetoed => matches!(current, Proposed | ResolverAssigned),
+            Approved => matches!(current, ResolverAssigned),
+            Executed => matches!(current, Proposed | ResolverAssigned | Approved),
+            // A partly executed proposal can't be withdrawn, but expires once its delete
+            // deadline passes so a vault that can no longer be slashed doesn't lock it forever
+            Cancelled => {
+                matches!(current, Proposed | ResolverAssigned | Approved)
+                    && self.executed_amount() == 0
+            }
+            Expired => matches!(current, Proposed | ResolverAssigned | Approved),
+        };
+
+        if !legal {
+            msg!(
+                "Slash proposal can not transition from {:?} to {:?}",
+                current,
+                next
+            );
+            return Err(next.transition_error());
+        }
+
+        Ok(())
+    }
+
+    /// Moves the proposal into `next`, returning an error if the transition is illegal
+    pub fn transition(&mut self, next: SlashProposalStatus) -> Result<(), ResolverError> {
+        self.check_transition(next)?;
+        self.status = next as u8;
+
+        Ok(())
+    }
+
+    /// Checks the proposal is in one of `statuses` for an action that doesn't change its status,
+    /// returning the action specific `error` otherwise
+    fn check_status(
+        &self,
+        statuses: &[SlashProposalStatus],
+        action: &str,
+        error: ResolverError,
+    ) -> Result<(), ResolverError> {
+        let status = self.status()?;
+        if !statuses.contains(&status) {
+            msg!("Slash proposal can not {} in status {:?}", action, status);
+            return Err(error);
+        }
+
+        Ok(())
+    }
+
+    /// Checks the operator can still appeal, before the proposal is decided
+    pub fn check_appealable(&self) -> Result<(), ResolverError> {
+        self.check_status(
+            &[
+                SlashProposalStatus::Proposed,
+                SlashProposalStatus::ResolverAssigned,
+            ],
+            "be appealed",
+            ResolverError::SlashProposalCannotAppeal,
+        )
+    }
+
+    /// Checks the assigned resolver can amend the amount, which is settled once it approved
+    pub fn check_amendable(&self) -> Result<(), ResolverError> {
+        self.check_status(
+            &[SlashProposalStatus::ResolverAssigned],
+            "be amended",
+            ResolverError::SlashProposalCannotAmend,
+        )
+    }
+
+    /// Checks the NCN can require a decision, only from a resolver still deciding the proposal
+    pub fn check_decision_requirable(&self) -> Result<(), ResolverError> {
+        self.check_status(
+            &[SlashProposalStatus::ResolverAssigned],
+            "require a resolver decision",
+            ResolverError::SlashProposalCannotRequireDecision,
+        )
     }
 
-    pub fn set_completed(&mut self, completed: bool) {
-        self.completed = PodBool::from_bool(completed);
+    /// Checks the assigned resolver missed its decision. It decided if it approved the proposal
+    /// or cast a veto on it, and the decision is moot once the proposal was cancelled
+    pub fn check_resolver_undecided(&self) -> Result<(), ResolverError> {
+        self.check_status(
+            &[
+                SlashProposalStatus::Proposed,
+                SlashProposalStatus::ResolverAssigned,
+                SlashProposalStatus::Executed,
+                SlashProposalStatus::Expired,
+            ],
+            "penalize its resolver",
+            ResolverError::SlashProposalResolverDecided,
+        )
+    }
+
+    pub fn appealed(&self) -> bool {
+        self.appealed.into()
+    }
+
+    pub fn record_appeal(&mut self) {
+        self.appealed = PodBool::from_bool(true);
     }
 
     pub fn check_veto_period_ended(&self, current_slot: u64) -> Result<(), ResolverError> {
@@ -119,15 +651,6 @@ impl SlashProposal {
         Ok(())
     }
 
-    pub fn check_completed(&self) -> Result<(), ResolverError> {
-        if self.completed.into() {
-            msg!("Slash proposal completed");
-            return Err(ResolverError::SlashProposalCompleted);
-        }
-
-        Ok(())
-    }
-
     pub fn check_delete_deadline_ended(&self, current_slot: u64) -> Result<(), ResolverError> {
         if self.delete_deadline_slot() > current_slot {
             msg!("Delete period not ended");
@@ -137,12 +660,13 @@ impl SlashProposal {
         Ok(())
     }
 
-    pub fn seeds(ncn: &Pubkey, operator: &Pubkey, slasher: &Pubkey) -> Vec<Vec<u8>> {
+    pub fn seeds(ncn: &Pubkey, operator: &Pubkey, slasher: &Pubkey, id: u64) -> Vec<Vec<u8>> {
         Vec::from_iter([
             b"slash_proposal".to_vec(),
             ncn.as_ref().to_vec(),
             operator.as_ref().to_vec(),
             slasher.as_ref().to_vec(),
+            id.to_le_bytes().to_vec(),
         ])
     }
 
@@ -151,8 +675,9 @@ impl SlashProposal {
         ncn: &Pubkey,
         operator: &Pubkey,
         slasher: &Pubkey,
+        id: u64,
     ) -> (Pubkey, u8, Vec<Vec<u8>>) {
-        let seeds = Self::seeds(ncn, operator, slasher);
+        let seeds = Self::seeds(ncn, operator, slasher, id);
         let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
         let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
         (pda, bump, seeds)
@@ -193,8 +718,9 @@ impl SlashProposal {
             return Err(ProgramError::InvalidAccountData);
         }
 
+        let id = Self::try_from_slice_unchecked(&slash_proposal.data.borrow())?.id();
         let expected_pubkey =
-            Self::find_program_address(program_id, ncn.key, operator.key, slasher.key).0;
+            Self::find_program_address(program_id, ncn.key, operator.key, slasher.key, id).0;
         if slash_proposal.key.ne(&expected_pubkey) {
             msg!("SlashProposal account is not at the correct PDA");
             return Err(ProgramError::InvalidAccountData);
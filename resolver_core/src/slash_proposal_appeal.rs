@@ -0,0 +1,141 @@
+use bytemuck::{Pod, Zeroable};
+use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
+use resolver_sdk::error::ResolverError;
+use shank::ShankAccount;
+use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+
+/// Records the counter-evidence submitted by an operator against a slash proposal.
+/// The PDA can only be created once per slash proposal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, AccountDeserialize, ShankAccount)]
+#[repr(C)]
+pub struct SlashProposalAppeal {
+    /// The slash proposal account
+    pub slash_proposal: Pubkey,
+
+    /// The operator account that appealed
+    pub operator: Pubkey,
+
+    /// The hash of the counter-evidence
+    evidence_hash: [u8; 32],
+
+    metadata_uri_len: u8,
+
+    /// A URI pointing to the counter-evidence
+    metadata_uri: [u8; 128],
+
+    /// The slot the appeal was submitted at
+    slot: PodU64,
+
+    /// The account that paid the rent, refunded when the appeal is closed
+    pub payer: Pubkey,
+
+    /// The bump seed for the PDA
+    pub bump: u8,
+}
+
+impl Discriminator for SlashProposalAppeal {
+    const DISCRIMINATOR: u8 = 10;
+}
+
+impl SlashProposalAppeal {
+    pub const MAX_METADATA_URI_LEN: usize = 128;
+
+    pub fn new(
+        slash_proposal: Pubkey,
+        operator: Pubkey,
+        evidence_hash: [u8; 32],
+        metadata_uri: &str,
+        slot: u64,
+        payer: Pubkey,
+        bump: u8,
+    ) -> Result<Self, ResolverError> {
+        if metadata_uri.len() > Self::MAX_METADATA_URI_LEN {
+            msg!("Metadata URI is too long");
+            return Err(ResolverError::SlashProposalMetadataUriTooLong);
+        }
+        let mut metadata_uri_bytes = [0; Self::MAX_METADATA_URI_LEN];
+        metadata_uri_bytes[..metadata_uri.len()].copy_from_slice(metadata_uri.as_bytes());
+
+        Ok(Self {
+            slash_proposal,
+            operator,
+            evidence_hash,
+            metadata_uri_len: metadata_uri.len() as u8,
+            metadata_uri: metadata_uri_bytes,
+            slot: PodU64::from(slot),
+            payer,
+            bump,
+        })
+    }
+
+    pub fn evidence_hash(&self) -> &[u8; 32] {
+        &self.evidence_hash
+    }
+
+    pub fn metadata_uri(&self) -> &str {
+        let len = (self.metadata_uri_len as usize).min(Self::MAX_METADATA_URI_LEN);
+        std::str::from_utf8(&self.metadata_uri[..len]).unwrap_or_default()
+    }
+
+    pub fn slot(&self) -> u64 {
+        self.slot.into()
+    }
+
+    pub fn seeds(slash_proposal: &Pubkey) -> Vec<Vec<u8>> {
+        Vec::from_iter([
+            b"slash_proposal_appeal".to_vec(),
+            slash_proposal.as_ref().to_vec(),
+        ])
+    }
+
+    pub fn find_program_address(
+        program_id: &Pubkey,
+        slash_proposal: &Pubkey,
+    ) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds = Self::seeds(slash_proposal);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
+        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
+        (pda, bump, seeds)
+    }
+
+    /// Loads the account as an [`SlashProposalAppeal`] account, returning an error if it is not.
+    ///
+    /// # Arguments
+    /// * `program_id` - The program ID
+    /// * `slash_proposal_appeal` - The account to load the SlashProposalAppeal from
+    /// * `slash_proposal` - The slash proposal that was appealed
+    /// * `expect_writable` - Whether the account should be writable
+    ///
+    /// # Returns
+    /// * `Result<(), ProgramError>` - The result of the operation
+    pub fn load(
+        program_id: &Pubkey,
+        slash_proposal_appeal: &AccountInfo,
+        slash_proposal: &AccountInfo,
+        expect_writable: bool,
+    ) -> Result<(), ProgramError> {
+        if slash_proposal_appeal.owner.ne(program_id) {
+            msg!("SlashProposalAppeal account has an invalid owner");
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        if slash_proposal_appeal.data_is_empty() {
+            msg!("SlashProposalAppeal account data is empty");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if expect_writable && !slash_proposal_appeal.is_writable {
+            msg!("SlashProposalAppeal account is not writable");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if slash_proposal_appeal.data.borrow()[0].ne(&Self::DISCRIMINATOR) {
+            msg!("SlashProposalAppeal account discriminator is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let expected_pubkey = Self::find_program_address(program_id, slash_proposal.key).0;
+        if slash_proposal_appeal.key.ne(&expected_pubkey) {
+            msg!("SlashProposalAppeal account is not at the correct PDA");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Ok(())
+    }
+}
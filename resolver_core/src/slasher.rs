@@ -10,6 +10,9 @@ pub struct Slasher {
     /// The base pubkey used as a seed for the PDA
     pub base: Pubkey,
 
+    /// The NCN the slasher proposes slashes for
+    pub ncn: Pubkey,
+
     /// The admin pubkey
     pub admin: Pubkey,
 
@@ -19,6 +22,9 @@ pub struct Slasher {
     /// The slasher index
     index: PodU64,
 
+    /// The number of slash proposals created by this slasher, used as the id of the next proposal
+    proposal_count: PodU64,
+
     /// The bump seed for the PDA
     pub bump: u8,
 }
@@ -28,12 +34,14 @@ impl Discriminator for Slasher {
 }
 
 impl Slasher {
-    pub fn new(base: Pubkey, admin: Pubkey, index: u64, bump: u8) -> Self {
+    pub fn new(base: Pubkey, ncn: Pubkey, admin: Pubkey, index: u64, bump: u8) -> Self {
         Self {
             base,
+            ncn,
             admin,
             delegate_admin: admin,
             index: PodU64::from(index),
+            proposal_count: PodU64::from(0),
             bump,
         }
     }
@@ -42,6 +50,19 @@ impl Slasher {
         self.index.into()
     }
 
+    pub fn proposal_count(&self) -> u64 {
+        self.proposal_count.into()
+    }
+
+    pub fn increment_proposal_count(&mut self) -> Result<(), ResolverError> {
+        let count = self
+            .proposal_count()
+            .checked_add(1)
+            .ok_or(ResolverError::ArithmeticOverflow)?;
+        self.proposal_count = PodU64::from(count);
+        Ok(())
+    }
+
     /// Replace all secondary admins that were equal to the old admin to the new admin
     ///
     /// # Arguments
@@ -63,6 +84,15 @@ impl Slasher {
         Ok(())
     }
 
+    pub fn check_ncn(&self, ncn: &Pubkey) -> Result<(), ResolverError> {
+        if self.ncn.ne(ncn) {
+            msg!("Slasher is not registered for the NCN");
+            return Err(ResolverError::SlasherNcnInvalid);
+        }
+
+        Ok(())
+    }
+
     /// Validates the delegate_admin account and ensures it matches the expected delegate_admin.
     ///
     /// # Arguments
@@ -0,0 +1,104 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_restaking_core::{ncn::Ncn, operator::Operator};
+use resolver_core::{
+    config::Config, ncn_resolver_program_config::NcnResolverProgramConfig,
+    ncn_resolver_ticket::NcnResolverTicket, ncn_slash_proposal_ticket::NcnSlashProposalTicket,
+    resolver::Resolver, slash_proposal::SlashProposal, slasher::Slasher,
+};
+use resolver_sdk::event::ResolverEvent;
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
+
+pub fn process_amend_slash_amount(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    amount: u64,
+) -> ProgramResult {
+    let [config_info, ncn_resolver_program_config_info, ncn_info, operator_info, slasher_info, resolver_info, slash_proposal_info, ncn_slash_proposal_ticket_info, resolver_veto_admin_info, ncn_resolver_ticket_info] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config_info, false)?;
+    let config_data = config_info.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+
+    NcnResolverProgramConfig::load(
+        program_id,
+        ncn_resolver_program_config_info,
+        ncn_info,
+        false,
+    )?;
+
+    Ncn::load(&config.jito_restaking_program, ncn_info, false)?;
+    Operator::load(&config.jito_restaking_program, operator_info, false)?;
+    Slasher::load(program_id, slasher_info, false)?;
+
+    Resolver::load(program_id, resolver_info, false)?;
+    let resolver_data = resolver_info.data.borrow();
+    let resolver = Resolver::try_from_slice_unchecked(&resolver_data)?;
+
+    NcnResolverTicket::load(
+        program_id,
+        ncn_resolver_ticket_info,
+        ncn_info,
+        resolver_info,
+        false,
+    )?;
+    let ncn_resolver_ticket_data = ncn_resolver_ticket_info.data.borrow();
+    let ncn_resolver_ticket =
+        NcnResolverTicket::try_from_slice_unchecked(&ncn_resolver_ticket_data)?;
+
+    SlashProposal::load(
+        program_id,
+        slash_proposal_info,
+        ncn_info,
+        operator_info,
+        slasher_info,
+        true,
+    )?;
+    let mut slash_proposal_data = slash_proposal_info.data.borrow_mut();
+    let slash_proposal = SlashProposal::try_from_slice_unchecked_mut(&mut slash_proposal_data)?;
+
+    NcnSlashProposalTicket::load(
+        program_id,
+        ncn_slash_proposal_ticket_info,
+        ncn_info,
+        slash_proposal_info,
+        false,
+    )?;
+    let ncn_slash_proposal_ticket_data = ncn_slash_proposal_ticket_info.data.borrow();
+    let ncn_slash_proposal_ticket =
+        NcnSlashProposalTicket::try_from_slice_unchecked(&ncn_slash_proposal_ticket_data)?;
+
+    load_signer(resolver_veto_admin_info, false)?;
+
+    resolver.check_veto_admin(resolver_veto_admin_info.key)?;
+    resolver.check_registered()?;
+
+    let current_slot = Clock::get()?.slot;
+
+    ncn_resolver_ticket.check_active(current_slot, config.epoch_length())?;
+    slash_proposal.check_veto_period_ended(current_slot)?;
+
+    ncn_slash_proposal_ticket.check_slash_proposal(slash_proposal_info.key)?;
+    ncn_slash_proposal_ticket.check_resolver(resolver_info.key)?;
+
+    slash_proposal.check_amendable()?;
+
+    slash_proposal.amend_amount(amount)?;
+
+    ResolverEvent::SlashAmended {
+        slash_proposal: *slash_proposal_info.key,
+        resolver: *resolver_info.key,
+        original_amount: slash_proposal.original_amount(),
+        amount,
+    }
+    .emit();
+
+    Ok(())
+}
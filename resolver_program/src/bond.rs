@@ -0,0 +1,266 @@
+use jito_jsm_core::loader::{load_token_account, load_token_mint};
+use resolver_sdk::error::ResolverError;
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    msg,
+    program::{invoke, invoke_signed},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    system_instruction,
+};
+use spl_token_2022::{
+    extension::{transfer_fee::TransferFeeAmount, BaseStateWithExtensions, StateWithExtensions},
+    state::{Account, Mint},
+};
+
+/// Moves a bond from the depositor into escrow on a program account, the SlashProposal or the
+/// Resolver. A SOL bond is held as lamports on the account, an SPL bond in a token account
+/// owned by it.
+///
+/// Returns the amount the escrow received, which is less than `bond_amount` for mints with a
+/// transfer fee, so only what is actually held gets recorded.
+#[allow(clippy::too_many_arguments)]
+pub fn deposit_bond<'info>(
+    escrow_info: &AccountInfo<'info>,
+    depositor_info: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    depositor_token_account_info: &AccountInfo<'info>,
+    escrow_token_account_info: &AccountInfo<'info>,
+    bond_mint_info: &AccountInfo<'info>,
+    token_program_info: &AccountInfo<'info>,
+    bond_mint: &Pubkey,
+    bond_amount: u64,
+) -> Result<u64, ProgramError> {
+    if bond_mint.eq(&Pubkey::default()) {
+        msg!("Depositing {} lamports of bond", bond_amount);
+        invoke(
+            &system_instruction::transfer(depositor_info.key, escrow_info.key, bond_amount),
+            &[
+                depositor_info.clone(),
+                escrow_info.clone(),
+                system_program.clone(),
+            ],
+        )?;
+        return Ok(bond_amount);
+    }
+
+    let decimals = load_bond_mint(bond_mint_info, token_program_info, bond_mint)?;
+    load_token_account(
+        depositor_token_account_info,
+        depositor_info.key,
+        bond_mint,
+        token_program_info,
+    )?;
+    load_token_account(
+        escrow_token_account_info,
+        escrow_info.key,
+        bond_mint,
+        token_program_info,
+    )?;
+
+    let escrow_balance = token_account_amount(escrow_token_account_info)?;
+
+    msg!("Depositing {} tokens of bond", bond_amount);
+    let ix = spl_token_2022::instruction::transfer_checked(
+        token_program_info.key,
+        depositor_token_account_info.key,
+        bond_mint,
+        escrow_token_account_info.key,
+        depositor_info.key,
+        &[],
+        bond_amount,
+        decimals,
+    )?;
+    invoke(
+        &ix,
+        &[
+            depositor_token_account_info.clone(),
+            bond_mint_info.clone(),
+            escrow_token_account_info.clone(),
+            depositor_info.clone(),
+            token_program_info.clone(),
+        ],
+    )?;
+
+    let received_amount = token_account_amount(escrow_token_account_info)?
+        .checked_sub(escrow_balance)
+        .ok_or(ResolverError::ArithmeticUnderflow)?;
+    if received_amount != bond_amount {
+        msg!("Bond escrow received {} tokens", received_amount);
+    }
+
+    Ok(received_amount)
+}
+
+/// Pays a bond held in escrow by a program account out to `recipient`. For a SOL bond the
+/// destination is the recipient itself, for an SPL bond a token account owned by the recipient.
+///
+/// The escrow account data must not be borrowed, it signs the token transfer with
+/// `escrow_seeds`.
+#[allow(clippy::too_many_arguments)]
+pub fn release_bond<'info>(
+    escrow_info: &AccountInfo<'info>,
+    escrow_seeds: &[Vec<u8>],
+    destination_info: &AccountInfo<'info>,
+    escrow_token_account_info: &AccountInfo<'info>,
+    bond_mint_info: &AccountInfo<'info>,
+    token_program_info: &AccountInfo<'info>,
+    bond_mint: &Pubkey,
+    bond_amount: u64,
+    recipient: &Pubkey,
+) -> ProgramResult {
+    if bond_amount == 0 {
+        return Ok(());
+    }
+
+    if bond_mint.eq(&Pubkey::default()) {
+        if destination_info.key.ne(recipient) {
+            msg!("Bond destination is not the bond recipient");
+            return Err(ResolverError::SlashProposalBondDestinationInvalid.into());
+        }
+
+        msg!(
+            "Releasing {} lamports of bond to {}",
+            bond_amount,
+            recipient
+        );
+        let mut escrow_lamports = escrow_info.try_borrow_mut_lamports()?;
+        **escrow_lamports = escrow_lamports
+            .checked_sub(bond_amount)
+            .ok_or(ResolverError::ArithmeticUnderflow)?;
+        let mut destination_lamports = destination_info.try_borrow_mut_lamports()?;
+        **destination_lamports = destination_lamports
+            .checked_add(bond_amount)
+            .ok_or(ResolverError::ArithmeticOverflow)?;
+        return Ok(());
+    }
+
+    let decimals = load_bond_mint(bond_mint_info, token_program_info, bond_mint)?;
+    load_token_account(
+        escrow_token_account_info,
+        escrow_info.key,
+        bond_mint,
+        token_program_info,
+    )?;
+    load_token_account(destination_info, recipient, bond_mint, token_program_info)?;
+
+    msg!("Releasing {} tokens of bond to {}", bond_amount, recipient);
+    let seed_slices: Vec<&[u8]> = escrow_seeds.iter().map(|seed| seed.as_slice()).collect();
+    let ix = spl_token_2022::instruction::transfer_checked(
+        token_program_info.key,
+        escrow_token_account_info.key,
+        bond_mint,
+        destination_info.key,
+        escrow_info.key,
+        &[],
+        bond_amount,
+        decimals,
+    )?;
+    invoke_signed(
+        &ix,
+        &[
+            escrow_token_account_info.clone(),
+            bond_mint_info.clone(),
+            destination_info.clone(),
+            escrow_info.clone(),
+            token_program_info.clone(),
+        ],
+        &[&seed_slices],
+    )
+}
+
+/// Closes the token account that held an SPL bond once it was paid out, sending its rent to
+/// `destination_info`. SOL bonds have no token account to close.
+///
+/// The account is left open if it still holds tokens or withheld transfer fees, so tokens sent
+/// to it can't block closing the escrow account. The escrow account data must not be borrowed,
+/// it signs the close with `escrow_seeds`.
+pub fn close_bond_token_account<'info>(
+    escrow_info: &AccountInfo<'info>,
+    escrow_seeds: &[Vec<u8>],
+    escrow_token_account_info: &AccountInfo<'info>,
+    bond_mint_info: &AccountInfo<'info>,
+    token_program_info: &AccountInfo<'info>,
+    destination_info: &AccountInfo<'info>,
+    bond_mint: &Pubkey,
+) -> ProgramResult {
+    if bond_mint.eq(&Pubkey::default()) {
+        return Ok(());
+    }
+
+    load_bond_mint(bond_mint_info, token_program_info, bond_mint)?;
+    load_token_account(
+        escrow_token_account_info,
+        escrow_info.key,
+        bond_mint,
+        token_program_info,
+    )?;
+
+    {
+        let escrow_token_account_data = escrow_token_account_info.data.borrow();
+        let escrow_token_account =
+            StateWithExtensions::<Account>::unpack(&escrow_token_account_data)?;
+        let withheld_amount = escrow_token_account
+            .get_extension::<TransferFeeAmount>()
+            .map_or(0, |transfer_fee_amount| {
+                u64::from(transfer_fee_amount.withheld_amount)
+            });
+        if escrow_token_account.base.amount > 0 || withheld_amount > 0 {
+            msg!("Bond token account is not empty, leaving it open");
+            return Ok(());
+        }
+    }
+
+    msg!(
+        "Closing bond token account {}",
+        escrow_token_account_info.key
+    );
+    let seed_slices: Vec<&[u8]> = escrow_seeds.iter().map(|seed| seed.as_slice()).collect();
+    let ix = spl_token_2022::instruction::close_account(
+        token_program_info.key,
+        escrow_token_account_info.key,
+        destination_info.key,
+        escrow_info.key,
+        &[],
+    )?;
+    invoke_signed(
+        &ix,
+        &[
+            escrow_token_account_info.clone(),
+            destination_info.clone(),
+            escrow_info.clone(),
+            token_program_info.clone(),
+        ],
+        &[&seed_slices],
+    )
+}
+
+/// Checks the bond mint and its token program, returning the mint decimals
+fn load_bond_mint(
+    bond_mint_info: &AccountInfo,
+    token_program_info: &AccountInfo,
+    bond_mint: &Pubkey,
+) -> Result<u8, ProgramError> {
+    if bond_mint_info.key.ne(bond_mint) {
+        msg!("Bond mint is not the mint of the bond");
+        return Err(ResolverError::SlashProposalBondMintInvalid.into());
+    }
+    load_token_mint(bond_mint_info)?;
+    spl_token_2022::check_spl_token_program_account(token_program_info.key)?;
+    if bond_mint_info.owner.ne(token_program_info.key) {
+        msg!("Bond mint is not owned by the token program");
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    let bond_mint_data = bond_mint_info.data.borrow();
+    let mint = StateWithExtensions::<Mint>::unpack(&bond_mint_data)?;
+    Ok(mint.base.decimals)
+}
+
+/// Returns the balance of an SPL Token or Token-2022 account
+fn token_account_amount(token_account_info: &AccountInfo) -> Result<u64, ProgramError> {
+    let token_account_data = token_account_info.data.borrow();
+    let token_account = StateWithExtensions::<Account>::unpack(&token_account_data)?;
+    Ok(token_account.base.amount)
+}
@@ -0,0 +1,115 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::{close_program_account, loader::load_signer};
+use jito_restaking_core::{ncn::Ncn, operator::Operator};
+use resolver_core::{
+    config::Config,
+    ncn_slash_proposal_ticket::NcnSlashProposalTicket,
+    slash_proposal::{SlashProposal, SlashProposalStatus},
+    slasher::Slasher,
+};
+use resolver_sdk::event::ResolverEvent;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{
+    bond::{close_bond_token_account, release_bond},
+    proposal_accounts::{close_proposal_accounts, release_assigned_resolver},
+};
+
+pub fn process_cancel_slash_proposal(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let [config_info, ncn_info, operator_info, slasher_info, slash_proposal_info, ncn_slash_proposal_ticket_info, slasher_admin_info, bond_destination_info, slash_proposal_bond_token_account_info, bond_mint_info, bond_token_program, resolver_info, closed_account_infos @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config_info, false)?;
+    let config_data = config_info.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+
+    Ncn::load(&config.jito_restaking_program, ncn_info, false)?;
+    Operator::load(&config.jito_restaking_program, operator_info, false)?;
+
+    Slasher::load(program_id, slasher_info, false)?;
+    let slasher_data = slasher_info.data.borrow();
+    let slasher = Slasher::try_from_slice_unchecked(&slasher_data)?;
+
+    SlashProposal::load(
+        program_id,
+        slash_proposal_info,
+        ncn_info,
+        operator_info,
+        slasher_info,
+        true,
+    )?;
+    let mut slash_proposal_data = slash_proposal_info.data.borrow_mut();
+    let slash_proposal = SlashProposal::try_from_slice_unchecked_mut(&mut slash_proposal_data)?;
+
+    NcnSlashProposalTicket::load(
+        program_id,
+        ncn_slash_proposal_ticket_info,
+        ncn_info,
+        slash_proposal_info,
+        true,
+    )?;
+
+    load_signer(slasher_admin_info, true)?;
+
+    slasher.check_admin(slasher_admin_info.key)?;
+
+    slash_proposal.transition(SlashProposalStatus::Cancelled)?;
+
+    let bond_mint = slash_proposal.bond_mint;
+    let bond_depositor = slash_proposal.bond_depositor;
+    let bond_amount = slash_proposal.take_bond();
+    let slash_proposal_seeds = slash_proposal.signing_seeds(ncn_info.key);
+
+    drop(slash_proposal_data);
+
+    release_bond(
+        slash_proposal_info,
+        &slash_proposal_seeds,
+        bond_destination_info,
+        slash_proposal_bond_token_account_info,
+        bond_mint_info,
+        bond_token_program,
+        &bond_mint,
+        bond_amount,
+        &bond_depositor,
+    )?;
+    close_bond_token_account(
+        slash_proposal_info,
+        &slash_proposal_seeds,
+        slash_proposal_bond_token_account_info,
+        bond_mint_info,
+        bond_token_program,
+        slasher_admin_info,
+        &bond_mint,
+    )?;
+
+    release_assigned_resolver(program_id, ncn_slash_proposal_ticket_info, resolver_info)?;
+    close_proposal_accounts(
+        program_id,
+        slash_proposal_info,
+        ncn_slash_proposal_ticket_info,
+        closed_account_infos,
+    )?;
+    close_program_account(program_id, slash_proposal_info, slasher_admin_info)?;
+    close_program_account(
+        program_id,
+        ncn_slash_proposal_ticket_info,
+        slasher_admin_info,
+    )?;
+
+    ResolverEvent::SlashCancelled {
+        slash_proposal: *slash_proposal_info.key,
+    }
+    .emit();
+
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use resolver_core::config::Config;
+use resolver_sdk::event::{AdminRole, ResolverEvent};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+pub fn process_config_set_admin(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let [config_info, old_admin, new_admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config_info, true)?;
+    let mut config_data = config_info.data.borrow_mut();
+    let config = Config::try_from_slice_unchecked_mut(&mut config_data)?;
+
+    load_signer(old_admin, false)?;
+    load_signer(new_admin, false)?;
+
+    config.check_admin(old_admin.key)?;
+
+    config.admin = *new_admin.key;
+
+    ResolverEvent::AdminChanged {
+        account: *config_info.key,
+        role: AdminRole::Admin,
+        old_admin: *old_admin.key,
+        new_admin: *new_admin.key,
+    }
+    .emit();
+
+    Ok(())
+}
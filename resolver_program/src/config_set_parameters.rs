@@ -0,0 +1,75 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use resolver_core::config::Config;
+use resolver_sdk::error::ResolverError;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+pub fn process_config_set_parameters(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    jito_restaking_program: Option<Pubkey>,
+    jito_vault_program: Option<Pubkey>,
+) -> ProgramResult {
+    let [config_info, admin, jito_restaking_config_info, jito_vault_config_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config_info, true)?;
+    let mut config_data = config_info.data.borrow_mut();
+    let config = Config::try_from_slice_unchecked_mut(&mut config_data)?;
+
+    load_signer(admin, false)?;
+
+    config.check_admin(admin.key)?;
+
+    if let Some(jito_restaking_program) = jito_restaking_program {
+        msg!(
+            "Updating jito_restaking_program to {}",
+            jito_restaking_program
+        );
+        config.jito_restaking_program = jito_restaking_program;
+    }
+
+    if let Some(jito_vault_program) = jito_vault_program {
+        msg!("Updating jito_vault_program to {}", jito_vault_program);
+        config.jito_vault_program = jito_vault_program;
+    }
+
+    // The programs must be deployed with their configs, which the epoch length is taken from so
+    // that epochs line up with the tickets and PDAs of both programs
+    jito_restaking_core::config::Config::load(
+        &config.jito_restaking_program,
+        jito_restaking_config_info,
+        false,
+    )?;
+    let jito_restaking_config_data = jito_restaking_config_info.data.borrow();
+    let jito_restaking_config =
+        jito_restaking_core::config::Config::try_from_slice_unchecked(&jito_restaking_config_data)?;
+
+    jito_vault_core::config::Config::load(
+        &config.jito_vault_program,
+        jito_vault_config_info,
+        false,
+    )?;
+    let jito_vault_config_data = jito_vault_config_info.data.borrow();
+    let jito_vault_config =
+        jito_vault_core::config::Config::try_from_slice_unchecked(&jito_vault_config_data)?;
+
+    let epoch_length = jito_vault_config.epoch_length();
+    if jito_restaking_config.epoch_length() != epoch_length {
+        msg!(
+            "Restaking epoch length {} does not match vault epoch length {}",
+            jito_restaking_config.epoch_length(),
+            epoch_length
+        );
+        return Err(ResolverError::ConfigEpochLengthInvalid.into());
+    }
+
+    msg!("Updating epoch_length to {}", epoch_length);
+    config.set_epoch_length(epoch_length)?;
+
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_restaking_core::ncn::Ncn;
+use resolver_core::{
+    config::Config, ncn_resolver_program_config::NcnResolverProgramConfig,
+    ncn_resolver_ticket::NcnResolverTicket, resolver::Resolver,
+};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
+
+pub fn process_cooldown_ncn_resolver_ticket(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let [config_info, ncn_resolver_program_config_info, ncn_info, resolver_info, ncn_resolver_ticket_info, resolver_admin] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config_info, false)?;
+    let config_data = config_info.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+
+    NcnResolverProgramConfig::load(program_id, ncn_resolver_program_config_info, ncn_info, true)?;
+    let mut ncn_resolver_program_config_data = ncn_resolver_program_config_info.data.borrow_mut();
+    let ncn_resolver_program_config = NcnResolverProgramConfig::try_from_slice_unchecked_mut(
+        &mut ncn_resolver_program_config_data,
+    )?;
+
+    Ncn::load(&config.jito_restaking_program, ncn_info, false)?;
+    Resolver::load(program_id, resolver_info, true)?;
+    let mut resolver_data = resolver_info.data.borrow_mut();
+    let resolver = Resolver::try_from_slice_unchecked_mut(&mut resolver_data)?;
+
+    NcnResolverTicket::load(
+        program_id,
+        ncn_resolver_ticket_info,
+        ncn_info,
+        resolver_info,
+        true,
+    )?;
+    let mut ncn_resolver_ticket_data = ncn_resolver_ticket_info.data.borrow_mut();
+    let ncn_resolver_ticket =
+        NcnResolverTicket::try_from_slice_unchecked_mut(&mut ncn_resolver_ticket_data)?;
+
+    load_signer(resolver_admin, false)?;
+
+    ncn_resolver_program_config.check_resolver_admin(resolver_admin.key)?;
+
+    ncn_resolver_program_config.cooldown_resolver(
+        ncn_resolver_ticket,
+        Clock::get()?.slot,
+        config.epoch_length(),
+    )?;
+    resolver.decrement_active_ncn_count()?;
+
+    Ok(())
+}
@@ -5,19 +5,27 @@ use jito_jsm_core::{
 };
 use jito_restaking_core::{ncn::Ncn, operator::Operator};
 use resolver_core::{
-    config::Config, ncn_slash_proposal_ticket::NcnSlashProposalTicket,
-    slash_proposal::SlashProposal, slasher::Slasher,
+    config::Config,
+    ncn_slash_proposal_ticket::NcnSlashProposalTicket,
+    slash_proposal::{SlashProposal, SlashProposalStatus},
+    slasher::Slasher,
 };
+use resolver_sdk::event::ResolverEvent;
 use solana_program::{
     account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
     program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
 };
 
+use crate::{
+    bond::{close_bond_token_account, release_bond},
+    proposal_accounts::{close_proposal_accounts, release_assigned_resolver},
+};
+
 pub fn process_delete_slash_proposal(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
 ) -> ProgramResult {
-    let [config_info, ncn_info, operator_info, slasher_info, slash_proposal_info, ncn_slash_proposal_ticket_info, payer, system_program] =
+    let [config_info, ncn_info, operator_info, slasher_info, slash_proposal_info, ncn_slash_proposal_ticket_info, payer, system_program, bond_destination_info, slash_proposal_bond_token_account_info, bond_mint_info, bond_token_program, resolver_info, closed_account_infos @ ..] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -40,8 +48,8 @@ pub fn process_delete_slash_proposal(
         slasher_info,
         true,
     )?;
-    let slash_proposal_data = slash_proposal_info.data.borrow();
-    let slash_proposal = SlashProposal::try_from_slice_unchecked(&slash_proposal_data)?;
+    let mut slash_proposal_data = slash_proposal_info.data.borrow_mut();
+    let slash_proposal = SlashProposal::try_from_slice_unchecked_mut(&mut slash_proposal_data)?;
 
     NcnSlashProposalTicket::load(
         program_id,
@@ -58,10 +66,56 @@ pub fn process_delete_slash_proposal(
 
     slash_proposal.check_delete_deadline_ended(current_slot)?;
 
+    // Proposals that never reached an outcome expire before being deleted
+    let expired = !slash_proposal.status()?.is_terminal();
+    if expired {
+        slash_proposal.transition(SlashProposalStatus::Expired)?;
+    }
+
+    // A bond still in escrow goes back to the slasher admin that deposited it
+    let bond_mint = slash_proposal.bond_mint;
+    let bond_depositor = slash_proposal.bond_depositor;
+    let bond_amount = slash_proposal.take_bond();
+    let slash_proposal_seeds = slash_proposal.signing_seeds(ncn_info.key);
+
     drop(slash_proposal_data);
 
+    release_bond(
+        slash_proposal_info,
+        &slash_proposal_seeds,
+        bond_destination_info,
+        slash_proposal_bond_token_account_info,
+        bond_mint_info,
+        bond_token_program,
+        &bond_mint,
+        bond_amount,
+        &bond_depositor,
+    )?;
+    close_bond_token_account(
+        slash_proposal_info,
+        &slash_proposal_seeds,
+        slash_proposal_bond_token_account_info,
+        bond_mint_info,
+        bond_token_program,
+        payer,
+        &bond_mint,
+    )?;
+
+    release_assigned_resolver(program_id, ncn_slash_proposal_ticket_info, resolver_info)?;
+    close_proposal_accounts(
+        program_id,
+        slash_proposal_info,
+        ncn_slash_proposal_ticket_info,
+        closed_account_infos,
+    )?;
     close_program_account(program_id, slash_proposal_info, payer)?;
     close_program_account(program_id, ncn_slash_proposal_ticket_info, payer)?;
 
+    ResolverEvent::ProposalDeleted {
+        slash_proposal: *slash_proposal_info.key,
+        expired,
+    }
+    .emit();
+
     Ok(())
 }
@@ -1,5 +1,7 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::{load_associated_token_account, load_signer, load_token_program};
+use jito_jsm_core::loader::{
+    load_associated_token_account, load_signer, load_token_account, load_token_program,
+};
 use jito_restaking_core::{
     ncn::Ncn, ncn_operator_state::NcnOperatorState,
     ncn_vault_slasher_ticket::NcnVaultSlasherTicket, ncn_vault_ticket::NcnVaultTicket,
@@ -13,16 +15,21 @@ use jito_vault_core::{
 use jito_vault_sdk::error::VaultError;
 use resolver_core::{
     ncn_resolver_program_config::NcnResolverProgramConfig,
-    ncn_slash_proposal_ticket::NcnSlashProposalTicket, resolver::Resolver,
-    slash_proposal::SlashProposal, slasher::Slasher,
+    ncn_slash_proposal_ticket::NcnSlashProposalTicket,
+    resolver::Resolver,
+    slash_proposal::{SlashProposal, SlashProposalStatus},
+    slasher::Slasher,
 };
+use resolver_sdk::{error::ResolverError, event::ResolverEvent};
 use solana_program::{
     account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
     program::invoke_signed, program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
 };
 
+use crate::bond::release_bond;
+
 pub fn process_execute_slash(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
-    let [config_info, ncn_resolver_program_config_info, vault_config_info, ncn_info, operator_info, slasher_info, vault_info, slasher_admin_info, ncn_operator_state_info, ncn_vault_ticket_info, operator_vault_ticket_info, vault_ncn_ticket_info, vault_operator_delegation_info, ncn_vault_slasher_ticket_info, vault_ncn_slasher_ticket_info, vault_ncn_slasher_operator_ticket_info, vault_token_account_info, slasher_token_account_info, resolver_info, slash_proposal_info, ncn_slash_proposal_ticket_info, token_program, jito_vault_program] =
+    let [config_info, ncn_resolver_program_config_info, vault_config_info, ncn_info, operator_info, slasher_info, vault_info, slasher_admin_info, ncn_operator_state_info, ncn_vault_ticket_info, operator_vault_ticket_info, vault_ncn_ticket_info, vault_operator_delegation_info, ncn_vault_slasher_ticket_info, vault_ncn_slasher_ticket_info, vault_ncn_slasher_operator_ticket_info, vault_token_account_info, slasher_token_account_info, resolver_info, slash_proposal_info, ncn_slash_proposal_ticket_info, token_program, jito_vault_program, bond_destination_info, slash_proposal_bond_token_account_info, bond_mint_info, bond_token_program, supported_mint_info, treasury_token_account_info, insurance_fund_token_account_info] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -133,17 +140,13 @@ pub fn process_execute_slash(program_id: &Pubkey, accounts: &[AccountInfo]) -> P
         &vault.supported_mint,
     )?;
 
-    Resolver::load(program_id, resolver_info, false)?;
-    let resolver_data = resolver_info.data.borrow();
-    let _resolver = Resolver::try_from_slice_unchecked(&resolver_data)?;
-
     SlashProposal::load(
         program_id,
         slash_proposal_info,
         ncn_info,
         operator_info,
         slasher_info,
-        false,
+        true,
     )?;
     let mut slash_proposal_data = slash_proposal_info.data.borrow_mut();
     let slash_proposal = SlashProposal::try_from_slice_unchecked_mut(&mut slash_proposal_data)?;
@@ -156,30 +159,54 @@ pub fn process_execute_slash(program_id: &Pubkey, accounts: &[AccountInfo]) -> P
         false,
     )?;
     let ncn_slash_proposal_ticket_data = ncn_slash_proposal_ticket_info.data.borrow();
-    let _ncn_slash_proposal_ticket =
+    let ncn_slash_proposal_ticket =
         NcnSlashProposalTicket::try_from_slice_unchecked(&ncn_slash_proposal_ticket_data)?;
 
+    ncn_slash_proposal_ticket.check_slash_proposal(slash_proposal_info.key)?;
+    ncn_slash_proposal_ticket.check_resolver(resolver_info.key)?;
+
+    // Without an assigned resolver the default pubkey is passed in place of the resolver
+    if ncn_slash_proposal_ticket.resolver_assigned() {
+        Resolver::load(program_id, resolver_info, false)?;
+    } else if ncn_resolver_program_config.require_resolver_assignment() {
+        msg!("Slash proposal has no resolver assigned");
+        return Err(ResolverError::SlashProposalResolverNotAssigned.into());
+    }
+
     load_token_program(token_program)?;
 
-    if jito_vault_program.key.ne(&jito_vault_program::id()) {
+    if jito_vault_program.key.ne(&config.jito_vault_program) {
         msg!("jito vault program account is incorrect");
         return Err(ProgramError::InvalidAccountData);
     }
 
     slasher.check_admin(slasher_admin_info.key)?;
+    slasher.check_ncn(ncn_info.key)?;
 
-    slash_proposal.check_veto_period_not_ended(Clock::get()?.slot)?;
-    slash_proposal.check_completed()?;
+    let current_slot = Clock::get()?.slot;
 
-    slash_proposal.set_completed(true);
-    slash_proposal.set_delete_deadline_slot(
-        slash_proposal.delete_deadline_slot()
-            + ncn_resolver_program_config.delete_slash_proposal_duration(),
-    );
+    // Resolvers can only vote until the veto deadline, so once it passed a quorum veto is
+    // no longer possible. Approved proposals can no longer be vetoed and skip the wait.
+    if slash_proposal.status()? != SlashProposalStatus::Approved {
+        slash_proposal.check_veto_period_not_ended(current_slot)?;
+    }
+
+    // Each call slashes the share of one vault, the proposal is executed with the last one
+    slash_proposal.check_transition(SlashProposalStatus::Executed)?;
+    let slash_amount = slash_proposal.record_vault_slash(vault_info.key)?;
+    let remaining_amount = slash_proposal.remaining_amount();
+    if remaining_amount == 0 {
+        slash_proposal.transition(SlashProposalStatus::Executed)?;
+        let delete_deadline_slot = current_slot
+            .checked_add(slash_proposal.delete_slash_proposal_duration())
+            .ok_or(ResolverError::ArithmeticOverflow)?;
+        slash_proposal.set_delete_deadline_slot(delete_deadline_slot);
+    }
 
     let slasher_seeds = slasher.signing_seeds();
     let seed_slices: Vec<&[u8]> = slasher_seeds.iter().map(|seed| seed.as_slice()).collect();
 
+    let supported_mint = vault.supported_mint;
     drop(vault_data);
     drop(slasher_data);
 
@@ -200,7 +227,7 @@ pub fn process_execute_slash(program_id: &Pubkey, accounts: &[AccountInfo]) -> P
         vault_ncn_slasher_operator_ticket_info.key,
         vault_token_account_info.key,
         slasher_token_account_info.key,
-        slash_proposal.amount(),
+        slash_amount,
     );
 
     invoke_signed(
@@ -226,5 +253,139 @@ pub fn process_execute_slash(program_id: &Pubkey, accounts: &[AccountInfo]) -> P
         &[&seed_slices],
     )?;
 
+    // The slashed funds land in the slasher token account, the NCN's shares are routed from there
+    let proceeds = ncn_resolver_program_config.split_proceeds(slash_amount)?;
+    if proceeds.burn > 0 {
+        if supported_mint_info.key.ne(&supported_mint) {
+            msg!("Mint is not the vault supported mint");
+            return Err(ResolverError::NcnResolverProgramConfigProceedsAccountInvalid.into());
+        }
+
+        msg!("Burning {} slashed tokens", proceeds.burn);
+        let ix = spl_token::instruction::burn(
+            token_program.key,
+            slasher_token_account_info.key,
+            supported_mint_info.key,
+            slasher_info.key,
+            &[],
+            proceeds.burn,
+        )?;
+        invoke_signed(
+            &ix,
+            &[
+                slasher_token_account_info.clone(),
+                supported_mint_info.clone(),
+                slasher_info.clone(),
+                token_program.clone(),
+            ],
+            &[&seed_slices],
+        )?;
+    }
+    transfer_proceeds(
+        slasher_info,
+        slasher_token_account_info,
+        treasury_token_account_info,
+        token_program,
+        &seed_slices,
+        &supported_mint,
+        &ncn_resolver_program_config.proceeds_treasury,
+        proceeds.treasury,
+    )?;
+    transfer_proceeds(
+        slasher_info,
+        slasher_token_account_info,
+        insurance_fund_token_account_info,
+        token_program,
+        &seed_slices,
+        &supported_mint,
+        &ncn_resolver_program_config.proceeds_insurance_fund,
+        proceeds.insurance_fund,
+    )?;
+
+    // The bond stays in escrow until every vault was slashed
+    let bond_mint = slash_proposal.bond_mint;
+    let bond_depositor = slash_proposal.bond_depositor;
+    let bond_amount = if remaining_amount == 0 {
+        slash_proposal.take_bond()
+    } else {
+        0
+    };
+    let slash_proposal_seeds = slash_proposal.signing_seeds(ncn_info.key);
+    drop(slash_proposal_data);
+
+    release_bond(
+        slash_proposal_info,
+        &slash_proposal_seeds,
+        bond_destination_info,
+        slash_proposal_bond_token_account_info,
+        bond_mint_info,
+        bond_token_program,
+        &bond_mint,
+        bond_amount,
+        &bond_depositor,
+    )?;
+
+    if proceeds.slasher != slash_amount {
+        ResolverEvent::SlashProceedsRouted {
+            slash_proposal: *slash_proposal_info.key,
+            vault: *vault_info.key,
+            burned: proceeds.burn,
+            treasury: ncn_resolver_program_config.proceeds_treasury,
+            treasury_amount: proceeds.treasury,
+            insurance_fund: ncn_resolver_program_config.proceeds_insurance_fund,
+            insurance_fund_amount: proceeds.insurance_fund,
+            slasher_amount: proceeds.slasher,
+        }
+        .emit();
+    }
+
+    ResolverEvent::SlashExecuted {
+        slash_proposal: *slash_proposal_info.key,
+        vault: *vault_info.key,
+        amount: slash_amount,
+        remaining_amount,
+    }
+    .emit();
+
     Ok(())
 }
+
+/// Moves `amount` slashed tokens from the slasher token account to a token account owned by
+/// `recipient`, signed by the slasher
+#[allow(clippy::too_many_arguments)]
+fn transfer_proceeds<'info>(
+    slasher_info: &AccountInfo<'info>,
+    slasher_token_account_info: &AccountInfo<'info>,
+    destination_info: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    slasher_seeds: &[&[u8]],
+    supported_mint: &Pubkey,
+    recipient: &Pubkey,
+    amount: u64,
+) -> ProgramResult {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    load_token_account(destination_info, recipient, supported_mint, token_program)?;
+
+    msg!("Routing {} slashed tokens to {}", amount, recipient);
+    let ix = spl_token::instruction::transfer(
+        token_program.key,
+        slasher_token_account_info.key,
+        destination_info.key,
+        slasher_info.key,
+        &[],
+        amount,
+    )?;
+    invoke_signed(
+        &ix,
+        &[
+            slasher_token_account_info.clone(),
+            destination_info.clone(),
+            slasher_info.clone(),
+            token_program.clone(),
+        ],
+        &[slasher_seeds],
+    )
+}
@@ -16,6 +16,8 @@ pub fn process_initialize_resolver_program_config(
     accounts: &[AccountInfo],
     veto_duration: u64,
     delete_slash_proposal_duration: u64,
+    veto_threshold: u64,
+    require_resolver_assignment: bool,
 ) -> ProgramResult {
     let [config, ncn, ncn_resolver_program_config, admin, system_program] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -30,6 +32,21 @@ pub fn process_initialize_resolver_program_config(
     load_signer(admin, true)?;
     load_system_program(system_program)?;
 
+    // Only the NCN admin or its slasher admin can set up the resolver program for the NCN
+    {
+        let ncn_data = ncn.data.borrow();
+        let ncn = Ncn::try_from_slice_unchecked(&ncn_data)?;
+        if ncn.admin.ne(admin.key) && ncn.slasher_admin.ne(admin.key) {
+            msg!("Admin is not the NCN admin or slasher admin");
+            return Err(ResolverError::NcnAdminInvalid.into());
+        }
+    }
+
+    if veto_threshold == 0 {
+        msg!("Veto threshold must be greater than zero");
+        return Err(ResolverError::NcnResolverProgramConfigVetoThresholdInvalid.into());
+    }
+
     let (
         ncn_resolver_program_config_pubkey,
         ncn_resolver_program_config_bump,
@@ -69,8 +86,11 @@ pub fn process_initialize_resolver_program_config(
         *admin.key,
         veto_duration,
         delete_slash_proposal_duration,
+        veto_threshold,
+        require_resolver_assignment,
         ncn_resolver_program_config_bump,
     );
+    ncn_resolver_program_config.check_durations()?;
 
     Ok(())
 }
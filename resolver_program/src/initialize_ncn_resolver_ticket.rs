@@ -0,0 +1,105 @@
+use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_jsm_core::{
+    create_account,
+    loader::{load_signer, load_system_account, load_system_program},
+};
+use jito_restaking_core::ncn::Ncn;
+use resolver_core::{
+    config::Config, ncn_resolver_program_config::NcnResolverProgramConfig,
+    ncn_resolver_ticket::NcnResolverTicket, resolver::Resolver,
+    resolver_ncn_ticket::ResolverNcnTicket,
+};
+use resolver_sdk::error::ResolverError;
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
+};
+
+pub fn process_initialize_ncn_resolver_ticket(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let [config_info, ncn_resolver_program_config_info, ncn_info, resolver_info, ncn_resolver_ticket_info, resolver_admin, system_program, resolver_ncn_ticket_info] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config_info, false)?;
+    let config_data = config_info.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+
+    NcnResolverProgramConfig::load(program_id, ncn_resolver_program_config_info, ncn_info, true)?;
+    let mut ncn_resolver_program_config_data = ncn_resolver_program_config_info.data.borrow_mut();
+    let ncn_resolver_program_config = NcnResolverProgramConfig::try_from_slice_unchecked_mut(
+        &mut ncn_resolver_program_config_data,
+    )?;
+
+    Ncn::load(&config.jito_restaking_program, ncn_info, false)?;
+
+    Resolver::load(program_id, resolver_info, false)?;
+    let resolver_data = resolver_info.data.borrow();
+    let resolver = Resolver::try_from_slice_unchecked(&resolver_data)?;
+
+    load_system_account(ncn_resolver_ticket_info, true)?;
+    load_signer(resolver_admin, true)?;
+    load_system_program(system_program)?;
+
+    ncn_resolver_program_config.check_resolver_admin(resolver_admin.key)?;
+
+    // Resolvers shared from another NCN are registered through their ResolverNcnTicket, the
+    // program id is passed in its place for resolvers initialized for this NCN
+    if resolver_ncn_ticket_info.key.eq(program_id) {
+        resolver.check_ncn(ncn_info.key)?;
+    } else {
+        ResolverNcnTicket::load(
+            program_id,
+            resolver_ncn_ticket_info,
+            resolver_info,
+            ncn_info,
+            false,
+        )?;
+    }
+
+    let (ncn_resolver_ticket_pubkey, ncn_resolver_ticket_bump, mut ncn_resolver_ticket_seeds) =
+        NcnResolverTicket::find_program_address(program_id, ncn_info.key, resolver_info.key);
+    ncn_resolver_ticket_seeds.push(vec![ncn_resolver_ticket_bump]);
+    if ncn_resolver_ticket_info.key.ne(&ncn_resolver_ticket_pubkey) {
+        msg!("NcnResolverTicket account is not at the correct PDA");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    msg!(
+        "Initializing ncn resolver ticket at address: {}",
+        ncn_resolver_ticket_info.key
+    );
+    create_account(
+        resolver_admin,
+        ncn_resolver_ticket_info,
+        system_program,
+        program_id,
+        &Rent::get()?,
+        8_u64
+            .checked_add(std::mem::size_of::<NcnResolverTicket>() as u64)
+            .ok_or(ResolverError::ArithmeticOverflow)?,
+        &ncn_resolver_ticket_seeds,
+    )?;
+
+    let mut ncn_resolver_ticket_data = ncn_resolver_ticket_info.try_borrow_mut_data()?;
+    ncn_resolver_ticket_data[0] = NcnResolverTicket::DISCRIMINATOR;
+    let ncn_resolver_ticket =
+        NcnResolverTicket::try_from_slice_unchecked_mut(&mut ncn_resolver_ticket_data)?;
+    // The resolver takes the next index of the NCN registry, only resolvers approved here can be
+    // picked by the assignment policies
+    *ncn_resolver_ticket = NcnResolverTicket::new(
+        *ncn_info.key,
+        *resolver_info.key,
+        ncn_resolver_program_config.resolver_count(),
+        Clock::get()?.slot,
+        ncn_resolver_ticket_bump,
+    );
+
+    ncn_resolver_program_config.increment_resolver_count();
+
+    Ok(())
+}
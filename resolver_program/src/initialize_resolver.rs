@@ -24,11 +24,7 @@ pub fn process_initialize_resolver(program_id: &Pubkey, accounts: &[AccountInfo]
     let config_data = config.data.borrow();
     let config = Config::try_from_slice_unchecked(&config_data)?;
 
-    NcnResolverProgramConfig::load(program_id, ncn_resolver_program_config, ncn, true)?;
-    let mut ncn_resolver_program_config_data = ncn_resolver_program_config.data.borrow_mut();
-    let ncn_resolver_program_config = NcnResolverProgramConfig::try_from_slice_unchecked_mut(
-        &mut ncn_resolver_program_config_data,
-    )?;
+    NcnResolverProgramConfig::load(program_id, ncn_resolver_program_config, ncn, false)?;
 
     Ncn::load(&config.jito_restaking_program, ncn, false)?;
     load_system_account(resolver_info, true)?;
@@ -61,14 +57,7 @@ pub fn process_initialize_resolver(program_id: &Pubkey, accounts: &[AccountInfo]
     resolver_data[0] = Resolver::DISCRIMINATOR;
     let resolver = Resolver::try_from_slice_unchecked_mut(&mut resolver_data)?;
 
-    *resolver = Resolver::new(
-        *base.key,
-        *admin.key,
-        ncn_resolver_program_config.resolver_count(),
-        resolver_bump,
-    );
-
-    ncn_resolver_program_config.increment_resolver_count();
+    *resolver = Resolver::new(*base.key, *admin.key, *ncn.key, resolver_bump);
 
     Ok(())
 }
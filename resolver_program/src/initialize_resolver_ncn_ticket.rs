@@ -0,0 +1,87 @@
+use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_jsm_core::{
+    create_account,
+    loader::{load_signer, load_system_account, load_system_program},
+};
+use jito_restaking_core::ncn::Ncn;
+use resolver_core::{
+    config::Config, ncn_resolver_program_config::NcnResolverProgramConfig, resolver::Resolver,
+    resolver_ncn_ticket::ResolverNcnTicket,
+};
+use resolver_sdk::error::ResolverError;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
+};
+
+pub fn process_initialize_resolver_ncn_ticket(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let [config_info, ncn_resolver_program_config_info, ncn_info, resolver_info, resolver_ncn_ticket_info, resolver_admin, system_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config_info, false)?;
+    let config_data = config_info.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+
+    NcnResolverProgramConfig::load(
+        program_id,
+        ncn_resolver_program_config_info,
+        ncn_info,
+        false,
+    )?;
+
+    Ncn::load(&config.jito_restaking_program, ncn_info, false)?;
+
+    Resolver::load(program_id, resolver_info, false)?;
+    let resolver_data = resolver_info.data.borrow();
+    let resolver = Resolver::try_from_slice_unchecked(&resolver_data)?;
+
+    load_system_account(resolver_ncn_ticket_info, true)?;
+    load_signer(resolver_admin, true)?;
+    load_system_program(system_program)?;
+
+    resolver.check_admin(resolver_admin.key)?;
+
+    if resolver.ncn.eq(ncn_info.key) {
+        msg!("Resolver was initialized for the NCN");
+        return Err(ResolverError::ResolverNcnAlreadyRegistered.into());
+    }
+
+    let (resolver_ncn_ticket_pubkey, resolver_ncn_ticket_bump, mut resolver_ncn_ticket_seeds) =
+        ResolverNcnTicket::find_program_address(program_id, resolver_info.key, ncn_info.key);
+    resolver_ncn_ticket_seeds.push(vec![resolver_ncn_ticket_bump]);
+    if resolver_ncn_ticket_info.key.ne(&resolver_ncn_ticket_pubkey) {
+        msg!("ResolverNcnTicket account is not at the correct PDA");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    msg!(
+        "Initializing resolver ncn ticket at address: {}",
+        resolver_ncn_ticket_info.key
+    );
+    create_account(
+        resolver_admin,
+        resolver_ncn_ticket_info,
+        system_program,
+        program_id,
+        &Rent::get()?,
+        8_u64
+            .checked_add(std::mem::size_of::<ResolverNcnTicket>() as u64)
+            .ok_or(ResolverError::ArithmeticOverflow)?,
+        &resolver_ncn_ticket_seeds,
+    )?;
+
+    let mut resolver_ncn_ticket_data = resolver_ncn_ticket_info.try_borrow_mut_data()?;
+    resolver_ncn_ticket_data[0] = ResolverNcnTicket::DISCRIMINATOR;
+    let resolver_ncn_ticket =
+        ResolverNcnTicket::try_from_slice_unchecked_mut(&mut resolver_ncn_ticket_data)?;
+    *resolver_ncn_ticket =
+        ResolverNcnTicket::new(*resolver_info.key, *ncn_info.key, resolver_ncn_ticket_bump);
+
+    Ok(())
+}
@@ -54,7 +54,13 @@ pub fn process_initialize_slasher(program_id: &Pubkey, accounts: &[AccountInfo])
     slasher_data[0] = Slasher::DISCRIMINATOR;
     let slasher = Slasher::try_from_slice_unchecked_mut(&mut slasher_data)?;
 
-    *slasher = Slasher::new(*base.key, *admin.key, ncn.slasher_count(), slasher_bump);
+    *slasher = Slasher::new(
+        *base.key,
+        *ncn_info.key,
+        *admin.key,
+        ncn.slasher_count(),
+        slasher_bump,
+    );
 
     Ok(())
 }
@@ -1,15 +1,40 @@
+mod amend_slash_amount;
+mod approve_slash;
+mod bond;
+mod cancel_slash_proposal;
+mod config_set_admin;
+mod config_set_parameters;
+mod cooldown_ncn_resolver_ticket;
 mod delete_slash_proposal;
 mod execute_slash;
 mod initialize_config;
 mod initialize_ncn_resolver_program_config;
+mod initialize_ncn_resolver_ticket;
 mod initialize_resolver;
+mod initialize_resolver_ncn_ticket;
 mod initialize_slasher;
+mod ncn_resolver_program_config_set_admin;
+mod ncn_resolver_program_config_set_assignment_policy;
+mod ncn_resolver_program_config_set_bond;
+mod ncn_resolver_program_config_set_parameters;
+mod ncn_resolver_program_config_set_proceeds;
+mod penalize_resolver;
+mod proposal_accounts;
 mod propose_slash;
+mod remove_ncn_resolver_ticket;
+mod require_resolver_decision;
+mod resolver_deposit_bond;
+mod resolver_deregister;
+mod resolver_set_admin;
+mod resolver_set_secondary_admin;
+mod resolver_withdraw_bond;
 mod set_resolver;
 mod slasher_delegate_token_account;
 mod slasher_set_admin;
 mod slasher_set_secondary_admin;
+mod submit_appeal;
 mod veto_slash;
+mod warmup_ncn_resolver_ticket;
 
 use borsh::BorshDeserialize;
 use delete_slash_proposal::process_delete_slash_proposal;
@@ -20,15 +45,35 @@ use solana_program::{
 };
 
 use crate::{
+    amend_slash_amount::process_amend_slash_amount, approve_slash::process_approve_slash,
+    cancel_slash_proposal::process_cancel_slash_proposal,
+    config_set_admin::process_config_set_admin,
+    config_set_parameters::process_config_set_parameters,
+    cooldown_ncn_resolver_ticket::process_cooldown_ncn_resolver_ticket,
     execute_slash::process_execute_slash, initialize_config::process_initialize_config,
     initialize_ncn_resolver_program_config::process_initialize_resolver_program_config,
+    initialize_ncn_resolver_ticket::process_initialize_ncn_resolver_ticket,
     initialize_resolver::process_initialize_resolver,
-    initialize_slasher::process_initialize_slasher, propose_slash::process_propose_slash,
-    set_resolver::process_set_resolver,
+    initialize_resolver_ncn_ticket::process_initialize_resolver_ncn_ticket,
+    initialize_slasher::process_initialize_slasher,
+    ncn_resolver_program_config_set_admin::process_ncn_resolver_program_config_set_admin,
+    ncn_resolver_program_config_set_assignment_policy::process_ncn_resolver_program_config_set_assignment_policy,
+    ncn_resolver_program_config_set_bond::process_ncn_resolver_program_config_set_bond,
+    ncn_resolver_program_config_set_parameters::process_ncn_resolver_program_config_set_parameters,
+    ncn_resolver_program_config_set_proceeds::process_ncn_resolver_program_config_set_proceeds,
+    penalize_resolver::process_penalize_resolver, propose_slash::process_propose_slash,
+    remove_ncn_resolver_ticket::process_remove_ncn_resolver_ticket,
+    require_resolver_decision::process_require_resolver_decision,
+    resolver_deposit_bond::process_resolver_deposit_bond,
+    resolver_deregister::process_resolver_deregister,
+    resolver_set_admin::process_resolver_set_admin,
+    resolver_set_secondary_admin::process_resolver_set_secondary_admin,
+    resolver_withdraw_bond::process_resolver_withdraw_bond, set_resolver::process_set_resolver,
     slasher_delegate_token_account::process_slasher_delegate_token_account,
     slasher_set_admin::process_slasher_set_admin,
     slasher_set_secondary_admin::process_slasher_set_secondary_admin,
-    veto_slash::process_veto_slash,
+    submit_appeal::process_submit_appeal, veto_slash::process_veto_slash,
+    warmup_ncn_resolver_ticket::process_warmup_ncn_resolver_ticket,
 };
 
 declare_id!("AE7fSUJSGxMzjNxSPpNTemrz9cr26RFue4GwoJ1cuR6f");
@@ -56,6 +101,8 @@ pub fn process_instruction(
         ResolverInstruction::InitializeNcnResolverProgramConfig {
             veto_duration,
             delete_slash_proposal_duration,
+            veto_threshold,
+            require_resolver_assignment,
         } => {
             msg!("Instruction: InitializeNcnResolverProgramConfig");
             process_initialize_resolver_program_config(
@@ -63,6 +110,8 @@ pub fn process_instruction(
                 accounts,
                 veto_duration,
                 delete_slash_proposal_duration,
+                veto_threshold,
+                require_resolver_assignment,
             )?;
         }
 
@@ -76,9 +125,23 @@ pub fn process_instruction(
             process_initialize_slasher(program_id, accounts)?;
         }
 
-        ResolverInstruction::ProposeSlash { slash_amount } => {
+        ResolverInstruction::ProposeSlash {
+            slash_amount,
+            reason,
+            evidence_hash,
+            metadata_uri,
+            allocation,
+        } => {
             msg!("Instruction: ProposeSlash");
-            process_propose_slash(program_id, accounts, slash_amount)?;
+            process_propose_slash(
+                program_id,
+                accounts,
+                slash_amount,
+                reason,
+                evidence_hash,
+                metadata_uri,
+                allocation,
+            )?;
         }
 
         ResolverInstruction::SetResolver => {
@@ -115,6 +178,172 @@ pub fn process_instruction(
             msg!("Instruction: DeleteSlashProposal");
             process_delete_slash_proposal(program_id, accounts)?;
         }
+
+        ResolverInstruction::ApproveSlash => {
+            msg!("Instruction: ApproveSlash");
+            process_approve_slash(program_id, accounts)?;
+        }
+
+        ResolverInstruction::CancelSlashProposal => {
+            msg!("Instruction: CancelSlashProposal");
+            process_cancel_slash_proposal(program_id, accounts)?;
+        }
+
+        ResolverInstruction::ConfigSetAdmin => {
+            msg!("Instruction: ConfigSetAdmin");
+            process_config_set_admin(program_id, accounts)?;
+        }
+
+        ResolverInstruction::ConfigSetParameters {
+            jito_restaking_program,
+            jito_vault_program,
+        } => {
+            msg!("Instruction: ConfigSetParameters");
+            process_config_set_parameters(
+                program_id,
+                accounts,
+                jito_restaking_program,
+                jito_vault_program,
+            )?;
+        }
+
+        ResolverInstruction::NcnResolverProgramConfigSetAdmin => {
+            msg!("Instruction: NcnResolverProgramConfigSetAdmin");
+            process_ncn_resolver_program_config_set_admin(program_id, accounts)?;
+        }
+
+        ResolverInstruction::NcnResolverProgramConfigSetParameters {
+            veto_duration,
+            delete_slash_proposal_duration,
+            resolver_penalty_bps,
+            appeal_extension_slots,
+            veto_threshold,
+        } => {
+            msg!("Instruction: NcnResolverProgramConfigSetParameters");
+            process_ncn_resolver_program_config_set_parameters(
+                program_id,
+                accounts,
+                veto_duration,
+                delete_slash_proposal_duration,
+                resolver_penalty_bps,
+                appeal_extension_slots,
+                veto_threshold,
+            )?;
+        }
+
+        ResolverInstruction::ResolverSetAdmin => {
+            msg!("Instruction: ResolverSetAdmin");
+            process_resolver_set_admin(program_id, accounts)?;
+        }
+
+        ResolverInstruction::ResolverSetSecondaryAdmin(role) => {
+            msg!("Instruction: ResolverSetSecondaryAdmin");
+            process_resolver_set_secondary_admin(program_id, accounts, role)?;
+        }
+
+        ResolverInstruction::InitializeNcnResolverTicket => {
+            msg!("Instruction: InitializeNcnResolverTicket");
+            process_initialize_ncn_resolver_ticket(program_id, accounts)?;
+        }
+
+        ResolverInstruction::WarmupNcnResolverTicket => {
+            msg!("Instruction: WarmupNcnResolverTicket");
+            process_warmup_ncn_resolver_ticket(program_id, accounts)?;
+        }
+
+        ResolverInstruction::CooldownNcnResolverTicket => {
+            msg!("Instruction: CooldownNcnResolverTicket");
+            process_cooldown_ncn_resolver_ticket(program_id, accounts)?;
+        }
+
+        ResolverInstruction::RemoveNcnResolverTicket => {
+            msg!("Instruction: RemoveNcnResolverTicket");
+            process_remove_ncn_resolver_ticket(program_id, accounts)?;
+        }
+
+        ResolverInstruction::InitializeResolverNcnTicket => {
+            msg!("Instruction: InitializeResolverNcnTicket");
+            process_initialize_resolver_ncn_ticket(program_id, accounts)?;
+        }
+
+        ResolverInstruction::NcnResolverProgramConfigSetAssignmentPolicy(assignment_policy) => {
+            msg!("Instruction: NcnResolverProgramConfigSetAssignmentPolicy");
+            process_ncn_resolver_program_config_set_assignment_policy(
+                program_id,
+                accounts,
+                assignment_policy,
+            )?;
+        }
+
+        ResolverInstruction::NcnResolverProgramConfigSetBond {
+            bond_amount,
+            bond_sink,
+        } => {
+            msg!("Instruction: NcnResolverProgramConfigSetBond");
+            process_ncn_resolver_program_config_set_bond(
+                program_id,
+                accounts,
+                bond_amount,
+                bond_sink,
+            )?;
+        }
+
+        ResolverInstruction::ResolverDepositBond { amount } => {
+            msg!("Instruction: ResolverDepositBond");
+            process_resolver_deposit_bond(program_id, accounts, amount)?;
+        }
+
+        ResolverInstruction::ResolverDeregister => {
+            msg!("Instruction: ResolverDeregister");
+            process_resolver_deregister(program_id, accounts)?;
+        }
+
+        ResolverInstruction::ResolverWithdrawBond => {
+            msg!("Instruction: ResolverWithdrawBond");
+            process_resolver_withdraw_bond(program_id, accounts)?;
+        }
+
+        ResolverInstruction::RequireResolverDecision => {
+            msg!("Instruction: RequireResolverDecision");
+            process_require_resolver_decision(program_id, accounts)?;
+        }
+
+        ResolverInstruction::PenalizeResolver => {
+            msg!("Instruction: PenalizeResolver");
+            process_penalize_resolver(program_id, accounts)?;
+        }
+
+        ResolverInstruction::AmendSlashAmount { amount } => {
+            msg!("Instruction: AmendSlashAmount");
+            process_amend_slash_amount(program_id, accounts, amount)?;
+        }
+
+        ResolverInstruction::NcnResolverProgramConfigSetProceeds {
+            burn_bps,
+            treasury,
+            treasury_bps,
+            insurance_fund,
+            insurance_fund_bps,
+        } => {
+            msg!("Instruction: NcnResolverProgramConfigSetProceeds");
+            process_ncn_resolver_program_config_set_proceeds(
+                program_id,
+                accounts,
+                burn_bps,
+                treasury,
+                treasury_bps,
+                insurance_fund,
+                insurance_fund_bps,
+            )?;
+        }
+
+        ResolverInstruction::SubmitAppeal {
+            evidence_hash,
+            metadata_uri,
+        } => {
+            msg!("Instruction: SubmitAppeal");
+            process_submit_appeal(program_id, accounts, evidence_hash, metadata_uri)?;
+        }
     }
 
     Ok(())
@@ -0,0 +1,48 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_restaking_core::ncn::Ncn;
+use resolver_core::{config::Config, ncn_resolver_program_config::NcnResolverProgramConfig};
+use resolver_sdk::event::{AdminRole, ResolverEvent};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+pub fn process_ncn_resolver_program_config_set_admin(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let [config_info, ncn_info, ncn_resolver_program_config_info, old_admin, new_admin] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config_info, false)?;
+    let config_data = config_info.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+
+    Ncn::load(&config.jito_restaking_program, ncn_info, false)?;
+
+    NcnResolverProgramConfig::load(program_id, ncn_resolver_program_config_info, ncn_info, true)?;
+    let mut ncn_resolver_program_config_data = ncn_resolver_program_config_info.data.borrow_mut();
+    let ncn_resolver_program_config = NcnResolverProgramConfig::try_from_slice_unchecked_mut(
+        &mut ncn_resolver_program_config_data,
+    )?;
+
+    load_signer(old_admin, false)?;
+    load_signer(new_admin, false)?;
+
+    ncn_resolver_program_config.check_resolver_admin(old_admin.key)?;
+
+    ncn_resolver_program_config.resolver_admin = *new_admin.key;
+
+    ResolverEvent::AdminChanged {
+        account: *ncn_resolver_program_config_info.key,
+        role: AdminRole::Admin,
+        old_admin: *old_admin.key,
+        new_admin: *new_admin.key,
+    }
+    .emit();
+
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_restaking_core::ncn::Ncn;
+use resolver_core::{
+    config::Config, ncn_resolver_program_config::NcnResolverProgramConfig, resolver::Resolver,
+};
+use resolver_sdk::{error::ResolverError, instruction::ResolverAssignmentPolicy};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+pub fn process_ncn_resolver_program_config_set_assignment_policy(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    assignment_policy: ResolverAssignmentPolicy,
+) -> ProgramResult {
+    let [config_info, ncn_info, ncn_resolver_program_config_info, resolver_admin, default_resolver_info] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config_info, false)?;
+    let config_data = config_info.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+
+    Ncn::load(&config.jito_restaking_program, ncn_info, false)?;
+
+    NcnResolverProgramConfig::load(program_id, ncn_resolver_program_config_info, ncn_info, true)?;
+    let mut ncn_resolver_program_config_data = ncn_resolver_program_config_info.data.borrow_mut();
+    let ncn_resolver_program_config = NcnResolverProgramConfig::try_from_slice_unchecked_mut(
+        &mut ncn_resolver_program_config_data,
+    )?;
+
+    load_signer(resolver_admin, false)?;
+
+    ncn_resolver_program_config.check_resolver_admin(resolver_admin.key)?;
+
+    // The default resolver is only used by the Fixed policy, the program id is passed in its
+    // place otherwise
+    let default_resolver = if assignment_policy == ResolverAssignmentPolicy::Fixed {
+        if default_resolver_info.key.eq(program_id) {
+            msg!("The Fixed assignment policy requires a default resolver");
+            return Err(ResolverError::NcnResolverProgramConfigAssignmentPolicyInvalid.into());
+        }
+        Resolver::load(program_id, default_resolver_info, false)?;
+        *default_resolver_info.key
+    } else {
+        Pubkey::default()
+    };
+
+    msg!("Updating assignment_policy to {:?}", assignment_policy);
+    ncn_resolver_program_config.set_assignment_policy(assignment_policy, default_resolver);
+
+    Ok(())
+}
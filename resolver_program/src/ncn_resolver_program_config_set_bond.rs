@@ -0,0 +1,63 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::{load_signer, load_token_mint};
+use jito_restaking_core::ncn::Ncn;
+use resolver_core::{config::Config, ncn_resolver_program_config::NcnResolverProgramConfig};
+use resolver_sdk::error::ResolverError;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+pub fn process_ncn_resolver_program_config_set_bond(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    bond_amount: u64,
+    bond_sink: Pubkey,
+) -> ProgramResult {
+    let [config_info, ncn_info, ncn_resolver_program_config_info, resolver_admin, bond_mint_info] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config_info, false)?;
+    let config_data = config_info.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+
+    Ncn::load(&config.jito_restaking_program, ncn_info, false)?;
+
+    NcnResolverProgramConfig::load(program_id, ncn_resolver_program_config_info, ncn_info, true)?;
+    let mut ncn_resolver_program_config_data = ncn_resolver_program_config_info.data.borrow_mut();
+    let ncn_resolver_program_config = NcnResolverProgramConfig::try_from_slice_unchecked_mut(
+        &mut ncn_resolver_program_config_data,
+    )?;
+
+    load_signer(resolver_admin, false)?;
+
+    ncn_resolver_program_config.check_resolver_admin(resolver_admin.key)?;
+
+    // Bonds are in SOL unless a mint is passed, the program id is passed in its place otherwise
+    let bond_mint = if bond_mint_info.key.eq(program_id) {
+        Pubkey::default()
+    } else {
+        load_token_mint(bond_mint_info)?;
+        *bond_mint_info.key
+    };
+
+    // Forfeited SOL bonds can't be credited to the operator, an account owned by the restaking
+    // program, so SOL bonds need a sink
+    if bond_mint.eq(&Pubkey::default()) && bond_amount > 0 && bond_sink.eq(&Pubkey::default()) {
+        msg!("SOL bonds require a bond sink");
+        return Err(ResolverError::NcnResolverProgramConfigBondSinkRequired.into());
+    }
+
+    msg!(
+        "Updating bond to {} of mint {} with sink {}",
+        bond_amount,
+        bond_mint,
+        bond_sink
+    );
+    ncn_resolver_program_config.set_bond(bond_mint, bond_amount, bond_sink);
+
+    Ok(())
+}
@@ -0,0 +1,77 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_restaking_core::ncn::Ncn;
+use resolver_core::{config::Config, ncn_resolver_program_config::NcnResolverProgramConfig};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+pub fn process_ncn_resolver_program_config_set_parameters(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    veto_duration: Option<u64>,
+    delete_slash_proposal_duration: Option<u64>,
+    resolver_penalty_bps: Option<u16>,
+    appeal_extension_slots: Option<u64>,
+    veto_threshold: Option<u64>,
+) -> ProgramResult {
+    let [config_info, ncn_info, ncn_resolver_program_config_info, resolver_admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config_info, false)?;
+    let config_data = config_info.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+
+    Ncn::load(&config.jito_restaking_program, ncn_info, false)?;
+
+    NcnResolverProgramConfig::load(program_id, ncn_resolver_program_config_info, ncn_info, true)?;
+    let mut ncn_resolver_program_config_data = ncn_resolver_program_config_info.data.borrow_mut();
+    let ncn_resolver_program_config = NcnResolverProgramConfig::try_from_slice_unchecked_mut(
+        &mut ncn_resolver_program_config_data,
+    )?;
+
+    load_signer(resolver_admin, false)?;
+
+    ncn_resolver_program_config.check_resolver_admin(resolver_admin.key)?;
+
+    if let Some(veto_duration) = veto_duration {
+        msg!("Updating veto_duration to {}", veto_duration);
+        ncn_resolver_program_config.set_veto_duration(veto_duration);
+    }
+
+    if let Some(delete_slash_proposal_duration) = delete_slash_proposal_duration {
+        msg!(
+            "Updating delete_slash_proposal_duration to {}",
+            delete_slash_proposal_duration
+        );
+        ncn_resolver_program_config
+            .set_delete_slash_proposal_duration(delete_slash_proposal_duration);
+    }
+
+    if let Some(resolver_penalty_bps) = resolver_penalty_bps {
+        msg!("Updating resolver_penalty_bps to {}", resolver_penalty_bps);
+        ncn_resolver_program_config.set_resolver_penalty_bps(resolver_penalty_bps)?;
+    }
+
+    if let Some(appeal_extension_slots) = appeal_extension_slots {
+        msg!(
+            "Updating appeal_extension_slots to {}",
+            appeal_extension_slots
+        );
+        ncn_resolver_program_config.set_appeal_extension_slots(appeal_extension_slots);
+    }
+
+    // Pending slash proposals count their vetoes against the updated threshold
+    if let Some(veto_threshold) = veto_threshold {
+        msg!("Updating veto_threshold to {}", veto_threshold);
+        ncn_resolver_program_config.set_veto_threshold(veto_threshold)?;
+    }
+
+    // Checked after all updates, a shorter veto duration can invalidate the current extension
+    ncn_resolver_program_config.check_durations()?;
+    ncn_resolver_program_config.check_appeal_extension_slots()?;
+
+    Ok(())
+}
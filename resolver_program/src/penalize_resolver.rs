@@ -0,0 +1,123 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_restaking_core::{ncn::Ncn, operator::Operator};
+use resolver_core::{
+    config::Config, ncn_resolver_program_config::NcnResolverProgramConfig,
+    ncn_slash_proposal_ticket::NcnSlashProposalTicket, resolver::Resolver,
+    resolver_vote::ResolverVote, slash_proposal::SlashProposal, slasher::Slasher,
+};
+use resolver_sdk::{error::ResolverError, event::ResolverEvent};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
+
+use crate::bond::release_bond;
+
+pub fn process_penalize_resolver(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let [config_info, ncn_resolver_program_config_info, ncn_info, operator_info, slasher_info, slash_proposal_info, ncn_slash_proposal_ticket_info, resolver_info, resolver_vote_info, bond_mint_info, resolver_bond_token_account_info, penalty_destination_info, token_program, ncn_resolver_admin] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config_info, false)?;
+    let config_data = config_info.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+
+    NcnResolverProgramConfig::load(
+        program_id,
+        ncn_resolver_program_config_info,
+        ncn_info,
+        false,
+    )?;
+    let ncn_resolver_program_config_data = ncn_resolver_program_config_info.data.borrow();
+    let ncn_resolver_program_config =
+        NcnResolverProgramConfig::try_from_slice_unchecked(&ncn_resolver_program_config_data)?;
+
+    Ncn::load(&config.jito_restaking_program, ncn_info, false)?;
+    Operator::load(&config.jito_restaking_program, operator_info, false)?;
+    Slasher::load(program_id, slasher_info, false)?;
+
+    SlashProposal::load(
+        program_id,
+        slash_proposal_info,
+        ncn_info,
+        operator_info,
+        slasher_info,
+        false,
+    )?;
+    let slash_proposal_data = slash_proposal_info.data.borrow();
+    let slash_proposal = SlashProposal::try_from_slice_unchecked(&slash_proposal_data)?;
+
+    NcnSlashProposalTicket::load(
+        program_id,
+        ncn_slash_proposal_ticket_info,
+        ncn_info,
+        slash_proposal_info,
+        true,
+    )?;
+    let mut ncn_slash_proposal_ticket_data = ncn_slash_proposal_ticket_info.data.borrow_mut();
+    let ncn_slash_proposal_ticket =
+        NcnSlashProposalTicket::try_from_slice_unchecked_mut(&mut ncn_slash_proposal_ticket_data)?;
+
+    Resolver::load(program_id, resolver_info, true)?;
+    let mut resolver_data = resolver_info.data.borrow_mut();
+    let resolver = Resolver::try_from_slice_unchecked_mut(&mut resolver_data)?;
+
+    load_signer(ncn_resolver_admin, false)?;
+
+    ncn_resolver_program_config.check_resolver_admin(ncn_resolver_admin.key)?;
+
+    ncn_slash_proposal_ticket.check_slash_proposal(slash_proposal_info.key)?;
+    ncn_slash_proposal_ticket.check_resolver(resolver_info.key)?;
+    slash_proposal.check_veto_period_not_ended(Clock::get()?.slot)?;
+
+    slash_proposal.check_resolver_undecided()?;
+
+    let (resolver_vote_pubkey, _, _) =
+        ResolverVote::find_program_address(program_id, slash_proposal_info.key, resolver_info.key);
+    if resolver_vote_info.key.ne(&resolver_vote_pubkey) {
+        msg!("ResolverVote account is not at the correct PDA");
+        return Err(ProgramError::InvalidAccountData);
+    }
+    if !resolver_vote_info.data_is_empty() {
+        msg!("Resolver voted on the slash proposal");
+        return Err(ResolverError::SlashProposalResolverDecided.into());
+    }
+
+    ncn_slash_proposal_ticket.penalize_resolver()?;
+
+    if bond_mint_info.key.ne(&resolver.bond_mint) {
+        msg!("Bond mint is not the resolver bond mint");
+        return Err(ResolverError::ResolverBondMintInvalid.into());
+    }
+
+    let penalty = ncn_resolver_program_config.resolver_penalty(resolver.bond_amount())?;
+    let penalty = resolver.forfeit_bond(penalty);
+    let penalty_recipient = ncn_resolver_program_config.bond_recipient(operator_info.key);
+    let bond_mint = resolver.bond_mint;
+    let resolver_seeds = resolver.signing_seeds();
+    drop(resolver_data);
+
+    release_bond(
+        resolver_info,
+        &resolver_seeds,
+        penalty_destination_info,
+        resolver_bond_token_account_info,
+        bond_mint_info,
+        token_program,
+        &bond_mint,
+        penalty,
+        &penalty_recipient,
+    )?;
+
+    ResolverEvent::ResolverPenalized {
+        resolver: *resolver_info.key,
+        slash_proposal: *slash_proposal_info.key,
+        amount: penalty,
+    }
+    .emit();
+
+    Ok(())
+}
@@ -0,0 +1,138 @@
+use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_jsm_core::close_program_account;
+use resolver_core::{
+    ncn_slash_proposal_ticket::NcnSlashProposalTicket, resolver::Resolver,
+    resolver_vote::ResolverVote, slash_proposal::SlashProposal,
+    slash_proposal_appeal::SlashProposalAppeal,
+};
+use resolver_sdk::error::ResolverError;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Closes the accounts created around a slash proposal when it's cancelled or deleted, refunding
+/// their rent to whoever paid it. They are passed as (account, rent destination) pairs, each
+/// account being a ResolverVote cast on the proposal or its SlashProposalAppeal. Every vote and
+/// the appeal must be passed, as they can't be closed once the proposal is gone.
+pub fn close_proposal_accounts(
+    program_id: &Pubkey,
+    slash_proposal_info: &AccountInfo,
+    ncn_slash_proposal_ticket_info: &AccountInfo,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    if accounts.len() % 2 != 0 {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+
+    let mut closed_vote_count: u64 = 0;
+    let mut appeal_closed = false;
+    for account_infos in accounts.chunks(2) {
+        let [account_info, destination_info] = account_infos else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        let payer = load_proposal_account_payer(program_id, account_info, slash_proposal_info)?;
+        if destination_info.key.ne(&payer) {
+            msg!("Rent destination is not the payer of {}", account_info.key);
+            return Err(ResolverError::SlashProposalRentDestinationInvalid.into());
+        }
+
+        if account_info.data.borrow()[0] == ResolverVote::DISCRIMINATOR {
+            closed_vote_count = closed_vote_count
+                .checked_add(1)
+                .ok_or(ResolverError::ArithmeticOverflow)?;
+        } else {
+            appeal_closed = true;
+        }
+
+        close_program_account(program_id, account_info, destination_info)?;
+    }
+
+    // A vote can only be closed once, so counting them is enough to know all were passed
+    let ncn_slash_proposal_ticket_data = ncn_slash_proposal_ticket_info.data.borrow();
+    let veto_count =
+        NcnSlashProposalTicket::try_from_slice_unchecked(&ncn_slash_proposal_ticket_data)?
+            .veto_count();
+    if closed_vote_count != veto_count {
+        msg!(
+            "Expected {} ResolverVote accounts, got {}",
+            veto_count,
+            closed_vote_count
+        );
+        return Err(ResolverError::SlashProposalAccountsMissing.into());
+    }
+
+    let slash_proposal_data = slash_proposal_info.data.borrow();
+    if SlashProposal::try_from_slice_unchecked(&slash_proposal_data)?.appealed() && !appeal_closed {
+        msg!("SlashProposalAppeal account is missing");
+        return Err(ResolverError::SlashProposalAccountsMissing.into());
+    }
+
+    Ok(())
+}
+
+/// Releases the assignment of the resolver assigned to a slash proposal that is being closed,
+/// which must then be passed as `resolver_info`
+pub fn release_assigned_resolver(
+    program_id: &Pubkey,
+    ncn_slash_proposal_ticket_info: &AccountInfo,
+    resolver_info: &AccountInfo,
+) -> ProgramResult {
+    let ncn_slash_proposal_ticket_data = ncn_slash_proposal_ticket_info.data.borrow();
+    let ncn_slash_proposal_ticket =
+        NcnSlashProposalTicket::try_from_slice_unchecked(&ncn_slash_proposal_ticket_data)?;
+    if !ncn_slash_proposal_ticket.resolver_assigned() {
+        return Ok(());
+    }
+
+    ncn_slash_proposal_ticket.check_resolver(resolver_info.key)?;
+    Resolver::load(program_id, resolver_info, true)?;
+    let mut resolver_data = resolver_info.data.borrow_mut();
+    Resolver::try_from_slice_unchecked_mut(&mut resolver_data)?.close_assignment()?;
+
+    Ok(())
+}
+
+/// Checks the account is a ResolverVote or SlashProposalAppeal of the slash proposal, returning
+/// its payer
+fn load_proposal_account_payer(
+    program_id: &Pubkey,
+    account_info: &AccountInfo,
+    slash_proposal_info: &AccountInfo,
+) -> Result<Pubkey, ProgramError> {
+    if account_info.owner.ne(program_id) || account_info.data_is_empty() {
+        msg!("{} is not a slash proposal account", account_info.key);
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let discriminator = account_info.data.borrow()[0];
+    match discriminator {
+        ResolverVote::DISCRIMINATOR => {
+            let resolver_vote_data = account_info.data.borrow();
+            let resolver_vote = ResolverVote::try_from_slice_unchecked(&resolver_vote_data)?;
+            let expected_pubkey = ResolverVote::find_program_address(
+                program_id,
+                slash_proposal_info.key,
+                &resolver_vote.resolver,
+            )
+            .0;
+            if account_info.key.ne(&expected_pubkey) {
+                msg!("ResolverVote account was not cast on the slash proposal");
+                return Err(ProgramError::InvalidAccountData);
+            }
+            Ok(resolver_vote.payer)
+        }
+        SlashProposalAppeal::DISCRIMINATOR => {
+            SlashProposalAppeal::load(program_id, account_info, slash_proposal_info, true)?;
+            let slash_proposal_appeal_data = account_info.data.borrow();
+            let slash_proposal_appeal =
+                SlashProposalAppeal::try_from_slice_unchecked(&slash_proposal_appeal_data)?;
+            Ok(slash_proposal_appeal.payer)
+        }
+        _ => {
+            msg!("{} is not a slash proposal account", account_info.key);
+            Err(ProgramError::InvalidAccountData)
+        }
+    }
+}
@@ -3,24 +3,48 @@ use jito_jsm_core::{
     create_account,
     loader::{load_signer, load_system_account, load_system_program},
 };
-use jito_restaking_core::{ncn::Ncn, operator::Operator};
+use jito_restaking_core::{
+    ncn::Ncn, ncn_vault_slasher_ticket::NcnVaultSlasherTicket, operator::Operator,
+};
+use jito_vault_core::{vault::Vault, vault_operator_delegation::VaultOperatorDelegation};
 use resolver_core::{
-    config::Config, ncn_resolver_program_config::NcnResolverProgramConfig,
-    ncn_slash_proposal_ticket::NcnSlashProposalTicket, slash_proposal::SlashProposal,
+    config::Config,
+    ncn_resolver_program_config::NcnResolverProgramConfig,
+    ncn_resolver_ticket::NcnResolverTicket,
+    ncn_slash_proposal_ticket::NcnSlashProposalTicket,
+    resolver::Resolver,
+    slash_proposal::{SlashProposal, SlashProposalStatus},
     slasher::Slasher,
 };
-use resolver_sdk::error::ResolverError;
+use resolver_sdk::{
+    error::ResolverError,
+    event::ResolverEvent,
+    instruction::{ResolverAssignmentPolicy, SlashAllocation, SlashReason},
+};
 use solana_program::{
-    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
-    program_error::ProgramError, pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
+    account_info::AccountInfo,
+    clock::Clock,
+    entrypoint::ProgramResult,
+    hash::hashv,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    rent::Rent,
+    sysvar::{self, Sysvar},
 };
 
+use crate::bond::deposit_bond;
+
 pub fn process_propose_slash(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     slash_amount: u64,
+    reason: SlashReason,
+    evidence_hash: [u8; 32],
+    metadata_uri: String,
+    allocation: SlashAllocation,
 ) -> ProgramResult {
-    let [config_info, ncn_resolver_program_config_info, ncn_info, operator_info, slasher_info, slash_proposal_info, ncn_slash_proposal_ticket_info, slasher_admin, system_program] =
+    let [config_info, ncn_resolver_program_config_info, ncn_info, operator_info, slasher_info, vault_info, ncn_vault_slasher_ticket_info, slash_proposal_info, ncn_slash_proposal_ticket_info, slasher_admin, system_program, vault_operator_delegation_info, slot_hashes_info, resolver_info, ncn_resolver_ticket_info, slasher_admin_bond_token_account_info, slash_proposal_bond_token_account_info, bond_mint_info, bond_token_program, other_vault_infos @ ..] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -30,22 +54,18 @@ pub fn process_propose_slash(
     let config_data = config_info.data.borrow();
     let config = Config::try_from_slice_unchecked(&config_data)?;
 
-    NcnResolverProgramConfig::load(
-        program_id,
-        ncn_resolver_program_config_info,
-        ncn_info,
-        false,
+    NcnResolverProgramConfig::load(program_id, ncn_resolver_program_config_info, ncn_info, true)?;
+    let mut ncn_resolver_program_config_data = ncn_resolver_program_config_info.data.borrow_mut();
+    let ncn_resolver_program_config = NcnResolverProgramConfig::try_from_slice_unchecked_mut(
+        &mut ncn_resolver_program_config_data,
     )?;
-    let ncn_resolver_program_config_data = ncn_resolver_program_config_info.data.borrow();
-    let ncn_resolver_program_config =
-        NcnResolverProgramConfig::try_from_slice_unchecked(&ncn_resolver_program_config_data)?;
 
     Ncn::load(&config.jito_restaking_program, ncn_info, false)?;
     Operator::load(&config.jito_restaking_program, operator_info, false)?;
 
-    Slasher::load(program_id, slasher_info, false)?;
-    let slasher_data = slasher_info.data.borrow();
-    let slasher = Slasher::try_from_slice_unchecked(&slasher_data)?;
+    Slasher::load(program_id, slasher_info, true)?;
+    let mut slasher_data = slasher_info.data.borrow_mut();
+    let slasher = Slasher::try_from_slice_unchecked_mut(&mut slasher_data)?;
 
     load_system_account(slash_proposal_info, true)?;
     load_system_account(ncn_slash_proposal_ticket_info, true)?;
@@ -55,6 +75,76 @@ pub fn process_propose_slash(
     let current_slot = Clock::get()?.slot;
 
     slasher.check_admin(slasher_admin.key)?;
+    slasher.check_ncn(ncn_info.key)?;
+
+    if slash_amount == 0 {
+        msg!("Slash amount must be greater than zero");
+        return Err(ResolverError::SlashProposalAmountZero.into());
+    }
+
+    // The other vaults delegating to the operator follow as (vault, ncn_vault_slasher_ticket,
+    // vault_operator_delegation) triples
+    if other_vault_infos.len() % 3 != 0 {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+
+    // The VaultOperatorDelegation of the first vault is optional, the program id is passed in
+    // its place to skip the delegated stake check
+    let mut vaults = vec![load_slashed_vault(
+        program_id,
+        &config,
+        ncn_info,
+        operator_info,
+        slasher_info,
+        vault_info,
+        ncn_vault_slasher_ticket_info,
+        vault_operator_delegation_info,
+        !other_vault_infos.is_empty(),
+        current_slot,
+    )?];
+    for vault_infos in other_vault_infos.chunks(3) {
+        vaults.push(load_slashed_vault(
+            program_id,
+            &config,
+            ncn_info,
+            operator_info,
+            slasher_info,
+            &vault_infos[0],
+            &vault_infos[1],
+            &vault_infos[2],
+            true,
+            current_slot,
+        )?);
+    }
+
+    let assigned_resolver = assign_resolver(
+        program_id,
+        &config,
+        ncn_resolver_program_config,
+        ncn_info,
+        slash_proposal_info,
+        slot_hashes_info,
+        resolver_info,
+        ncn_resolver_ticket_info,
+        current_slot,
+    )?;
+
+    let slash_proposal_id = slasher.proposal_count();
+    slasher.increment_proposal_count()?;
+
+    // Durations are snapshotted on the proposal, later config updates don't affect it
+    let veto_deadline_slot = current_slot
+        .checked_add(ncn_resolver_program_config.veto_duration())
+        .ok_or(ResolverError::ArithmeticOverflow)?;
+    let delete_slash_proposal_duration =
+        ncn_resolver_program_config.delete_slash_proposal_duration();
+    // Pending proposals can be expired once the delete duration passed after the veto deadline
+    let delete_deadline_slot = veto_deadline_slot
+        .checked_add(delete_slash_proposal_duration)
+        .ok_or(ResolverError::ArithmeticOverflow)?;
+    let bond_mint = ncn_resolver_program_config.bond_mint;
+    let bond_amount = ncn_resolver_program_config.bond_amount();
+    let bond_recipient = ncn_resolver_program_config.bond_recipient(operator_info.key);
 
     // Initialize SlashProposal
     {
@@ -64,6 +154,7 @@ pub fn process_propose_slash(
                 ncn_info.key,
                 operator_info.key,
                 slasher_info.key,
+                slash_proposal_id,
             );
         slash_proposal_seed.push(vec![slash_proposal_bump]);
         if slash_proposal_info.key.ne(&slash_proposal_pubkey) {
@@ -94,10 +185,76 @@ pub fn process_propose_slash(
         *slash_proposal = SlashProposal::new(
             *operator_info.key,
             *slasher_info.key,
+            slash_proposal_id,
             slash_amount,
             current_slot,
-            current_slot + ncn_resolver_program_config.veto_duration(),
+            veto_deadline_slot,
+            delete_deadline_slot,
+            delete_slash_proposal_duration,
+            reason,
+            evidence_hash,
+            &metadata_uri,
             slash_proposal_bump,
+        )?;
+
+        // Only a single vault proposal can skip its delegation, the whole amount lands on it
+        let vault_securities = match vaults.as_slice() {
+            [SlashedVault {
+                vault,
+                security: None,
+                ..
+            }] => vec![(*vault, slash_amount)],
+            vaults => vaults
+                .iter()
+                .map(|vault| {
+                    vault
+                        .security
+                        .map(|security| (vault.vault, security))
+                        .ok_or(ResolverError::SlashProposalVaultInvalid)
+                })
+                .collect::<Result<Vec<_>, _>>()?,
+        };
+        slash_proposal.set_vaults(allocation, &vault_securities)?;
+
+        for (vault, slash_proposal_vault) in vaults.iter().zip(slash_proposal.vaults()) {
+            if slash_proposal_vault.amount() > vault.max_slashable_per_epoch {
+                msg!(
+                    "Slash amount {} of vault {} exceeds the max slashable per epoch {}",
+                    slash_proposal_vault.amount(),
+                    vault.vault,
+                    vault.max_slashable_per_epoch
+                );
+                return Err(ResolverError::SlashProposalAmountExceedsMaxSlashable.into());
+            }
+        }
+
+        if assigned_resolver.is_some() {
+            slash_proposal.transition(SlashProposalStatus::ResolverAssigned)?;
+        }
+    }
+
+    if bond_amount > 0 {
+        let received_bond_amount = deposit_bond(
+            slash_proposal_info,
+            slasher_admin,
+            system_program,
+            slasher_admin_bond_token_account_info,
+            slash_proposal_bond_token_account_info,
+            bond_mint_info,
+            bond_token_program,
+            &bond_mint,
+            bond_amount,
+        )?;
+
+        // Mints with a transfer fee credit the escrow less than the configured bond, only what
+        // it received can be returned or forfeited
+        let mut slash_proposal_data = slash_proposal_info.try_borrow_mut_data()?;
+        let slash_proposal = SlashProposal::try_from_slice_unchecked_mut(&mut slash_proposal_data)?;
+        slash_proposal.set_bond(
+            bond_mint,
+            received_bond_amount,
+            *slasher_admin.key,
+            bond_recipient,
         );
     }
 
@@ -144,7 +301,238 @@ pub fn process_propose_slash(
             *slash_proposal_info.key,
             ncn_slash_proposal_bump,
         );
+
+        if let Some(assigned_resolver) = assigned_resolver {
+            ncn_slash_proposal.set_resolver(assigned_resolver);
+        }
+    }
+
+    ResolverEvent::SlashProposed {
+        ncn: *ncn_info.key,
+        operator: *operator_info.key,
+        slasher: *slasher_info.key,
+        slash_proposal: *slash_proposal_info.key,
+        id: slash_proposal_id,
+        amount: slash_amount,
+        veto_deadline_slot,
+    }
+    .emit();
+
+    if let Some(assigned_resolver) = assigned_resolver {
+        ResolverEvent::ResolverAssigned {
+            slash_proposal: *slash_proposal_info.key,
+            resolver: assigned_resolver,
+        }
+        .emit();
     }
 
     Ok(())
 }
+
+/// A vault slashed by a new slash proposal
+struct SlashedVault {
+    vault: Pubkey,
+    /// The stake delegated to the operator, `None` when the delegation is not checked
+    security: Option<u64>,
+    max_slashable_per_epoch: u64,
+}
+
+/// Checks that the slasher can slash the vault and reads the stake it delegated to the operator
+#[allow(clippy::too_many_arguments)]
+fn load_slashed_vault(
+    program_id: &Pubkey,
+    config: &Config,
+    ncn_info: &AccountInfo,
+    operator_info: &AccountInfo,
+    slasher_info: &AccountInfo,
+    vault_info: &AccountInfo,
+    ncn_vault_slasher_ticket_info: &AccountInfo,
+    vault_operator_delegation_info: &AccountInfo,
+    delegation_required: bool,
+    current_slot: u64,
+) -> Result<SlashedVault, ProgramError> {
+    Vault::load(&config.jito_vault_program, vault_info, false)?;
+    NcnVaultSlasherTicket::load(
+        &config.jito_restaking_program,
+        ncn_vault_slasher_ticket_info,
+        ncn_info,
+        vault_info,
+        slasher_info,
+        false,
+    )?;
+    let ncn_vault_slasher_ticket_data = ncn_vault_slasher_ticket_info.data.borrow();
+    let ncn_vault_slasher_ticket =
+        NcnVaultSlasherTicket::try_from_slice_unchecked(&ncn_vault_slasher_ticket_data)?;
+
+    // Only slashers the NCN currently allows to slash the vault can propose
+    if !ncn_vault_slasher_ticket
+        .state
+        .is_active(current_slot, config.epoch_length())
+    {
+        msg!("NcnVaultSlasherTicket is not active");
+        return Err(ResolverError::SlasherTicketInactive.into());
+    }
+
+    let security = if vault_operator_delegation_info.key.ne(program_id) {
+        VaultOperatorDelegation::load(
+            &config.jito_vault_program,
+            vault_operator_delegation_info,
+            vault_info,
+            operator_info,
+            false,
+        )?;
+        let vault_operator_delegation_data = vault_operator_delegation_info.data.borrow();
+        let vault_operator_delegation =
+            VaultOperatorDelegation::try_from_slice_unchecked(&vault_operator_delegation_data)?;
+
+        let security = vault_operator_delegation
+            .delegation_state
+            .total_security()?;
+
+        // A vault without stake delegated to the operator would get a share it can't cover
+        if delegation_required && security == 0 {
+            msg!(
+                "Vault {} has no stake delegated to the operator",
+                vault_info.key
+            );
+            return Err(ResolverError::SlashProposalVaultDelegationEmpty.into());
+        }
+
+        Some(security)
+    } else if delegation_required {
+        msg!("Slash proposals covering several vaults need the delegation of every vault");
+        return Err(ResolverError::SlashProposalVaultInvalid.into());
+    } else {
+        None
+    };
+
+    Ok(SlashedVault {
+        vault: *vault_info.key,
+        security,
+        max_slashable_per_epoch: ncn_vault_slasher_ticket.max_slashable_per_epoch(),
+    })
+}
+
+/// Picks the resolver of a new slash proposal with the NCN assignment policy. The RoundRobin and
+/// Random policies pick an index of the NCN registry, and the proposer passes the resolver whose
+/// NcnResolverTicket holds it. Returns `None` when the proposal is left for a manual SetResolver:
+/// with the Manual policy, when the NCN has no resolver to pick from, or when the picked resolver
+/// can't take it because its ticket isn't active, it deregistered or the NCN veto threshold
+/// can't be reached. The RoundRobin cursor still moves past an unusable resolver, so it can't
+/// hold up later proposals.
+///
+/// The Random draw only keeps the proposer from naming the resolver, it is not unbiased: the
+/// slot hash is known before the proposal is submitted, so the proposer can wait for a slot that
+/// draws a resolver it prefers.
+#[allow(clippy::too_many_arguments)]
+fn assign_resolver(
+    program_id: &Pubkey,
+    config: &Config,
+    ncn_resolver_program_config: &mut NcnResolverProgramConfig,
+    ncn_info: &AccountInfo,
+    slash_proposal_info: &AccountInfo,
+    slot_hashes_info: &AccountInfo,
+    resolver_info: &AccountInfo,
+    ncn_resolver_ticket_info: &AccountInfo,
+    current_slot: u64,
+) -> Result<Option<Pubkey>, ProgramError> {
+    let resolver_index = match ncn_resolver_program_config.assignment_policy()? {
+        ResolverAssignmentPolicy::Manual => return Ok(None),
+        ResolverAssignmentPolicy::Fixed => {
+            if resolver_info
+                .key
+                .ne(&ncn_resolver_program_config.default_resolver)
+            {
+                msg!("Resolver is not the default resolver of the NCN");
+                return Err(ResolverError::SlashProposalResolverInvalid.into());
+            }
+            None
+        }
+        ResolverAssignmentPolicy::RoundRobin => {
+            match ncn_resolver_program_config.next_round_robin_index() {
+                Some(index) => Some(index),
+                None => return Ok(None),
+            }
+        }
+        ResolverAssignmentPolicy::Random => {
+            let resolver_count = ncn_resolver_program_config.resolver_count();
+            if resolver_count == 0 {
+                return Ok(None);
+            }
+
+            if slot_hashes_info.key.ne(&sysvar::slot_hashes::id()) {
+                msg!("SlotHashes sysvar is invalid");
+                return Err(ProgramError::InvalidArgument);
+            }
+            // The most recent slot hash follows the entry count and its slot
+            let slot_hashes_data = slot_hashes_info.data.borrow();
+            let slot_hash = slot_hashes_data
+                .get(16..48)
+                .ok_or(ProgramError::InvalidAccountData)?;
+            let seed = hashv(&[slot_hash, slash_proposal_info.key.as_ref()]);
+            let mut draw = [0; 8];
+            draw.copy_from_slice(&seed.as_ref()[..8]);
+            Some(u64::from_le_bytes(draw) % resolver_count)
+        }
+    };
+
+    Resolver::load(program_id, resolver_info, true)?;
+
+    let ncn_resolver_ticket_pubkey =
+        NcnResolverTicket::find_program_address(program_id, ncn_info.key, resolver_info.key).0;
+    if ncn_resolver_ticket_info.key.ne(&ncn_resolver_ticket_pubkey) {
+        msg!("NcnResolverTicket account is not at the correct PDA");
+        return Err(ProgramError::InvalidAccountData);
+    }
+    // Every index of the registry is held by a ticket, only the Fixed default resolver can have
+    // been removed from it
+    if ncn_resolver_ticket_info.data_is_empty() {
+        if resolver_index.is_some() {
+            msg!("Resolver is not registered for the NCN");
+            return Err(ResolverError::SlashProposalResolverInvalid.into());
+        }
+        msg!("Default resolver is not registered for the NCN, leaving the proposal unassigned");
+        return Ok(None);
+    }
+
+    NcnResolverTicket::load(
+        program_id,
+        ncn_resolver_ticket_info,
+        ncn_info,
+        resolver_info,
+        false,
+    )?;
+    let ncn_resolver_ticket_data = ncn_resolver_ticket_info.data.borrow();
+    let ncn_resolver_ticket =
+        NcnResolverTicket::try_from_slice_unchecked(&ncn_resolver_ticket_data)?;
+
+    if let Some(resolver_index) = resolver_index {
+        if ncn_resolver_ticket.index() != resolver_index {
+            msg!(
+                "Resolver index {} is not the assigned index {}",
+                ncn_resolver_ticket.index(),
+                resolver_index
+            );
+            return Err(ResolverError::SlashProposalResolverInvalid.into());
+        }
+    }
+
+    let mut resolver_data = resolver_info.data.borrow_mut();
+    let resolver = Resolver::try_from_slice_unchecked_mut(&mut resolver_data)?;
+
+    if resolver.check_registered().is_err()
+        || ncn_resolver_ticket
+            .check_active(current_slot, config.epoch_length())
+            .is_err()
+        || ncn_resolver_program_config
+            .check_veto_threshold_reachable(current_slot, config.epoch_length())
+            .is_err()
+    {
+        msg!("Picked resolver can't be assigned, leaving the proposal unassigned");
+        return Ok(None);
+    }
+
+    resolver.open_assignment()?;
+
+    Ok(Some(*resolver_info.key))
+}
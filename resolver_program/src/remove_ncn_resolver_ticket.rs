@@ -0,0 +1,100 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::{close_program_account, loader::load_signer, slot_toggle::SlotToggleState};
+use jito_restaking_core::ncn::Ncn;
+use resolver_core::{
+    config::Config, ncn_resolver_program_config::NcnResolverProgramConfig,
+    ncn_resolver_ticket::NcnResolverTicket, resolver::Resolver,
+};
+use resolver_sdk::error::ResolverError;
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
+
+pub fn process_remove_ncn_resolver_ticket(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let [config_info, ncn_resolver_program_config_info, ncn_info, resolver_info, ncn_resolver_ticket_info, resolver_admin, last_resolver_info, last_ncn_resolver_ticket_info] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config_info, false)?;
+    let config_data = config_info.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+
+    NcnResolverProgramConfig::load(program_id, ncn_resolver_program_config_info, ncn_info, true)?;
+    let mut ncn_resolver_program_config_data = ncn_resolver_program_config_info.data.borrow_mut();
+    let ncn_resolver_program_config = NcnResolverProgramConfig::try_from_slice_unchecked_mut(
+        &mut ncn_resolver_program_config_data,
+    )?;
+
+    Ncn::load(&config.jito_restaking_program, ncn_info, false)?;
+    Resolver::load(program_id, resolver_info, false)?;
+
+    NcnResolverTicket::load(
+        program_id,
+        ncn_resolver_ticket_info,
+        ncn_info,
+        resolver_info,
+        true,
+    )?;
+
+    load_signer(resolver_admin, true)?;
+
+    ncn_resolver_program_config.check_resolver_admin(resolver_admin.key)?;
+
+    // Only resolvers that finished cooling down can be removed, a warming up ticket still counts
+    // as an active resolver of the NCN
+    let index = {
+        let ncn_resolver_ticket_data = ncn_resolver_ticket_info.data.borrow();
+        let ncn_resolver_ticket =
+            NcnResolverTicket::try_from_slice_unchecked(&ncn_resolver_ticket_data)?;
+        if ncn_resolver_ticket
+            .state
+            .state(Clock::get()?.slot, config.epoch_length())
+            .ne(&SlotToggleState::Inactive)
+        {
+            msg!("NcnResolverTicket is not inactive");
+            return Err(ResolverError::NcnResolverTicketNotInactive.into());
+        }
+        ncn_resolver_ticket.index()
+    };
+
+    // The ticket holding the last index of the registry moves into the freed index, so the
+    // assignment policies keep picking among approved resolvers. The program id is passed for
+    // the last resolver when the removed ticket holds the last index
+    let last_index = ncn_resolver_program_config
+        .resolver_count()
+        .checked_sub(1)
+        .ok_or(ResolverError::ArithmeticUnderflow)?;
+    if index != last_index {
+        Resolver::load(program_id, last_resolver_info, false)?;
+        NcnResolverTicket::load(
+            program_id,
+            last_ncn_resolver_ticket_info,
+            ncn_info,
+            last_resolver_info,
+            true,
+        )?;
+        let mut last_ncn_resolver_ticket_data = last_ncn_resolver_ticket_info.data.borrow_mut();
+        let last_ncn_resolver_ticket =
+            NcnResolverTicket::try_from_slice_unchecked_mut(&mut last_ncn_resolver_ticket_data)?;
+        if last_ncn_resolver_ticket.index() != last_index {
+            msg!(
+                "NcnResolverTicket index {} is not the last index {}",
+                last_ncn_resolver_ticket.index(),
+                last_index
+            );
+            return Err(ProgramError::InvalidAccountData);
+        }
+        last_ncn_resolver_ticket.set_index(index);
+    }
+    ncn_resolver_program_config.decrement_resolver_count()?;
+
+    close_program_account(program_id, ncn_resolver_ticket_info, resolver_admin)?;
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::{load_signer, load_token_mint};
+use resolver_core::resolver::Resolver;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::bond::deposit_bond;
+
+pub fn process_resolver_deposit_bond(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    amount: u64,
+) -> ProgramResult {
+    let [resolver_info, resolver_admin, bond_mint_info, resolver_admin_token_account_info, resolver_bond_token_account_info, token_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Resolver::load(program_id, resolver_info, true)?;
+    let mut resolver_data = resolver_info.data.borrow_mut();
+    let resolver = Resolver::try_from_slice_unchecked_mut(&mut resolver_data)?;
+
+    load_signer(resolver_admin, false)?;
+    load_token_mint(bond_mint_info)?;
+
+    resolver.check_admin(resolver_admin.key)?;
+    drop(resolver_data);
+
+    // Resolver bonds are always held in tokens, so the system program is never used
+    let received_amount = deposit_bond(
+        resolver_info,
+        resolver_admin,
+        token_program,
+        resolver_admin_token_account_info,
+        resolver_bond_token_account_info,
+        bond_mint_info,
+        token_program,
+        bond_mint_info.key,
+        amount,
+    )?;
+
+    // Mints with a transfer fee credit the escrow less than `amount`, only the received tokens
+    // back the bond
+    let mut resolver_data = resolver_info.data.borrow_mut();
+    let resolver = Resolver::try_from_slice_unchecked_mut(&mut resolver_data)?;
+    resolver.deposit_bond(bond_mint_info.key, received_amount)?;
+
+    Ok(())
+}
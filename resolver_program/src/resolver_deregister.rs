@@ -0,0 +1,79 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_restaking_core::ncn::Ncn;
+use resolver_core::{
+    config::Config, ncn_resolver_program_config::NcnResolverProgramConfig,
+    ncn_resolver_ticket::NcnResolverTicket, resolver::Resolver,
+};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
+
+pub fn process_resolver_deregister(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let [config_info, resolver_info, resolver_admin, ncn_resolver_ticket_infos @ ..] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config_info, false)?;
+    let config_data = config_info.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+
+    Resolver::load(program_id, resolver_info, true)?;
+    let mut resolver_data = resolver_info.data.borrow_mut();
+    let resolver = Resolver::try_from_slice_unchecked_mut(&mut resolver_data)?;
+
+    load_signer(resolver_admin, false)?;
+
+    resolver.check_admin(resolver_admin.key)?;
+
+    // The active NcnResolverTickets are cooled down, so the resolver stops counting towards the
+    // veto threshold of every NCN it was active for
+    let current_slot = Clock::get()?.slot;
+    for ncn_resolver_ticket_accounts in ncn_resolver_ticket_infos.chunks(3) {
+        let [ncn_info, ncn_resolver_program_config_info, ncn_resolver_ticket_info] =
+            ncn_resolver_ticket_accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        Ncn::load(&config.jito_restaking_program, ncn_info, false)?;
+
+        NcnResolverProgramConfig::load(
+            program_id,
+            ncn_resolver_program_config_info,
+            ncn_info,
+            true,
+        )?;
+        let mut ncn_resolver_program_config_data =
+            ncn_resolver_program_config_info.data.borrow_mut();
+        let ncn_resolver_program_config = NcnResolverProgramConfig::try_from_slice_unchecked_mut(
+            &mut ncn_resolver_program_config_data,
+        )?;
+
+        NcnResolverTicket::load(
+            program_id,
+            ncn_resolver_ticket_info,
+            ncn_info,
+            resolver_info,
+            true,
+        )?;
+        let mut ncn_resolver_ticket_data = ncn_resolver_ticket_info.data.borrow_mut();
+        let ncn_resolver_ticket =
+            NcnResolverTicket::try_from_slice_unchecked_mut(&mut ncn_resolver_ticket_data)?;
+
+        msg!("Cooling down resolver for NCN {}", ncn_info.key);
+        ncn_resolver_program_config.cooldown_resolver(
+            ncn_resolver_ticket,
+            current_slot,
+            config.epoch_length(),
+        )?;
+        resolver.decrement_active_ncn_count()?;
+    }
+
+    msg!("Deregistering resolver at slot {}", current_slot);
+    resolver.deregister(current_slot)?;
+
+    Ok(())
+}
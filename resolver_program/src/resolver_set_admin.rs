@@ -0,0 +1,37 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use resolver_core::resolver::Resolver;
+use resolver_sdk::event::{AdminRole, ResolverEvent};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+pub fn process_resolver_set_admin(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let [resolver_info, old_admin, new_admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Resolver::load(program_id, resolver_info, true)?;
+    let mut resolver_data = resolver_info.data.borrow_mut();
+    let resolver = Resolver::try_from_slice_unchecked_mut(&mut resolver_data)?;
+
+    load_signer(old_admin, false)?;
+    load_signer(new_admin, false)?;
+
+    resolver.check_admin(old_admin.key)?;
+
+    resolver.admin = *new_admin.key;
+
+    resolver.update_secondary_admin(old_admin.key, new_admin.key);
+
+    ResolverEvent::AdminChanged {
+        account: *resolver_info.key,
+        role: AdminRole::Admin,
+        old_admin: *old_admin.key,
+        new_admin: *new_admin.key,
+    }
+    .emit();
+
+    Ok(())
+}
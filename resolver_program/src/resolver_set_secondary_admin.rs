@@ -0,0 +1,56 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use resolver_core::resolver::Resolver;
+use resolver_sdk::{
+    event::{AdminRole, ResolverEvent},
+    instruction::ResolverAdminRole,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+pub fn process_resolver_set_secondary_admin(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    role: ResolverAdminRole,
+) -> ProgramResult {
+    let [resolver_info, admin, new_admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Resolver::load(program_id, resolver_info, true)?;
+    let mut resolver_data = resolver_info.data.borrow_mut();
+    let resolver = Resolver::try_from_slice_unchecked_mut(&mut resolver_data)?;
+
+    load_signer(admin, false)?;
+
+    resolver.check_admin(admin.key)?;
+
+    match role {
+        ResolverAdminRole::VetoAdmin => {
+            ResolverEvent::AdminChanged {
+                account: *resolver_info.key,
+                role: AdminRole::VetoAdmin,
+                old_admin: resolver.veto_admin,
+                new_admin: *new_admin.key,
+            }
+            .emit();
+
+            resolver.veto_admin = *new_admin.key;
+        }
+        ResolverAdminRole::MetadataAdmin => {
+            ResolverEvent::AdminChanged {
+                account: *resolver_info.key,
+                role: AdminRole::MetadataAdmin,
+                old_admin: resolver.metadata_admin,
+                new_admin: *new_admin.key,
+            }
+            .emit();
+
+            resolver.metadata_admin = *new_admin.key;
+        }
+    }
+
+    Ok(())
+}
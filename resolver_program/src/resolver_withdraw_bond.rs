@@ -0,0 +1,55 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use resolver_core::{config::Config, resolver::Resolver};
+use resolver_sdk::error::ResolverError;
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
+
+use crate::bond::release_bond;
+
+pub fn process_resolver_withdraw_bond(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let [config_info, resolver_info, resolver_admin, bond_mint_info, resolver_bond_token_account_info, resolver_admin_token_account_info, token_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config_info, false)?;
+    let config_data = config_info.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+
+    Resolver::load(program_id, resolver_info, true)?;
+    let mut resolver_data = resolver_info.data.borrow_mut();
+    let resolver = Resolver::try_from_slice_unchecked_mut(&mut resolver_data)?;
+
+    load_signer(resolver_admin, false)?;
+
+    resolver.check_admin(resolver_admin.key)?;
+
+    if bond_mint_info.key.ne(&resolver.bond_mint) {
+        msg!("Bond mint is not the resolver bond mint");
+        return Err(ResolverError::ResolverBondMintInvalid.into());
+    }
+
+    let bond_amount = resolver.withdraw_bond(Clock::get()?.slot, config.epoch_length())?;
+    let bond_mint = resolver.bond_mint;
+    let resolver_seeds = resolver.signing_seeds();
+    drop(resolver_data);
+
+    release_bond(
+        resolver_info,
+        &resolver_seeds,
+        resolver_admin_token_account_info,
+        resolver_bond_token_account_info,
+        bond_mint_info,
+        token_program,
+        &bond_mint,
+        bond_amount,
+        resolver_admin.key,
+    )
+}
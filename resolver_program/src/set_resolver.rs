@@ -2,17 +2,22 @@ use jito_bytemuck::AccountDeserialize;
 use jito_jsm_core::loader::load_signer;
 use jito_restaking_core::{ncn::Ncn, operator::Operator};
 use resolver_core::{
-    config::Config, ncn_resolver_program_config::NcnResolverProgramConfig,
-    ncn_slash_proposal_ticket::NcnSlashProposalTicket, slash_proposal::SlashProposal,
+    config::Config,
+    ncn_resolver_program_config::NcnResolverProgramConfig,
+    ncn_resolver_ticket::NcnResolverTicket,
+    ncn_slash_proposal_ticket::NcnSlashProposalTicket,
+    resolver::Resolver,
+    slash_proposal::{SlashProposal, SlashProposalStatus},
     slasher::Slasher,
 };
+use resolver_sdk::event::ResolverEvent;
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
-    pubkey::Pubkey,
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
 };
 
 pub fn process_set_resolver(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
-    let [config_info, ncn_resolver_program_config_info, ncn_info, operator_info, slasher_info, slash_proposal_info, ncn_slash_proposal_ticket_info, ncn_resolver_admin, new_resolver_info] =
+    let [config_info, ncn_resolver_program_config_info, ncn_info, operator_info, slasher_info, slash_proposal_info, ncn_slash_proposal_ticket_info, ncn_resolver_admin, new_resolver_info, ncn_resolver_ticket_info, old_resolver_info] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -42,8 +47,10 @@ pub fn process_set_resolver(program_id: &Pubkey, accounts: &[AccountInfo]) -> Pr
         ncn_info,
         operator_info,
         slasher_info,
-        false,
+        true,
     )?;
+    let mut slash_proposal_data = slash_proposal_info.data.borrow_mut();
+    let slash_proposal = SlashProposal::try_from_slice_unchecked_mut(&mut slash_proposal_data)?;
 
     NcnSlashProposalTicket::load(
         program_id,
@@ -56,17 +63,53 @@ pub fn process_set_resolver(program_id: &Pubkey, accounts: &[AccountInfo]) -> Pr
     let ncn_slash_proposal_ticket =
         NcnSlashProposalTicket::try_from_slice_unchecked_mut(&mut ncn_slash_proposal_ticket_data)?;
 
+    Resolver::load(program_id, new_resolver_info, true)?;
+    let mut new_resolver_data = new_resolver_info.data.borrow_mut();
+    let new_resolver = Resolver::try_from_slice_unchecked_mut(&mut new_resolver_data)?;
+
     load_signer(ncn_resolver_admin, true)?;
 
-    if ncn_resolver_program_config
-        .resolver_admin
-        .ne(ncn_resolver_admin.key)
-    {
-        msg!("Admin is not the resolver admin");
-        return Err(ProgramError::InvalidAccountData);
-    }
+    ncn_resolver_program_config.check_resolver_admin(ncn_resolver_admin.key)?;
+
+    let current_slot = Clock::get()?.slot;
+
+    // The NcnResolverTicket is derived from the NCN and the resolver, so it also proves the
+    // resolver is registered for the NCN
+    NcnResolverTicket::load(
+        program_id,
+        ncn_resolver_ticket_info,
+        ncn_info,
+        new_resolver_info,
+        false,
+    )?;
+    let ncn_resolver_ticket_data = ncn_resolver_ticket_info.data.borrow();
+    let ncn_resolver_ticket =
+        NcnResolverTicket::try_from_slice_unchecked(&ncn_resolver_ticket_data)?;
+    ncn_resolver_ticket.check_active(current_slot, config.epoch_length())?;
+    ncn_resolver_program_config
+        .check_veto_threshold_reachable(current_slot, config.epoch_length())?;
+    new_resolver.check_registered()?;
+    slash_proposal.check_veto_period_ended(current_slot)?;
+
+    slash_proposal.transition(SlashProposalStatus::ResolverAssigned)?;
 
+    // A replaced resolver is released, reassigning the same resolver leaves it unchanged
+    if ncn_slash_proposal_ticket.resolver.ne(new_resolver_info.key) {
+        if ncn_slash_proposal_ticket.resolver_assigned() {
+            ncn_slash_proposal_ticket.check_resolver(old_resolver_info.key)?;
+            Resolver::load(program_id, old_resolver_info, true)?;
+            let mut old_resolver_data = old_resolver_info.data.borrow_mut();
+            Resolver::try_from_slice_unchecked_mut(&mut old_resolver_data)?.close_assignment()?;
+        }
+        new_resolver.open_assignment()?;
+    }
     ncn_slash_proposal_ticket.set_resolver(*new_resolver_info.key);
 
+    ResolverEvent::ResolverAssigned {
+        slash_proposal: *slash_proposal_info.key,
+        resolver: *new_resolver_info.key,
+    }
+    .emit();
+
     Ok(())
 }
@@ -1,6 +1,7 @@
 use jito_bytemuck::AccountDeserialize;
 use jito_jsm_core::loader::load_signer;
 use resolver_core::slasher::Slasher;
+use resolver_sdk::event::{AdminRole, ResolverEvent};
 use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
     pubkey::Pubkey,
@@ -24,5 +25,13 @@ pub fn process_slasher_set_admin(program_id: &Pubkey, accounts: &[AccountInfo])
 
     slasher.update_secondary_admin(old_admin.key, new_admin.key);
 
+    ResolverEvent::AdminChanged {
+        account: *slasher_info.key,
+        role: AdminRole::Admin,
+        old_admin: *old_admin.key,
+        new_admin: *new_admin.key,
+    }
+    .emit();
+
     Ok(())
 }
@@ -1,7 +1,10 @@
 use jito_bytemuck::AccountDeserialize;
 use jito_jsm_core::loader::load_signer;
 use resolver_core::slasher::Slasher;
-use resolver_sdk::instruction::SlasherAdminRole;
+use resolver_sdk::{
+    event::{AdminRole, ResolverEvent},
+    instruction::SlasherAdminRole,
+};
 use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
     pubkey::Pubkey,
@@ -26,6 +29,14 @@ pub fn process_slasher_set_secondary_admin(
 
     match role {
         SlasherAdminRole::DelegateAdmin => {
+            ResolverEvent::AdminChanged {
+                account: *slasher_info.key,
+                role: AdminRole::DelegateAdmin,
+                old_admin: slasher.delegate_admin,
+                new_admin: *new_admin.key,
+            }
+            .emit();
+
             slasher.delegate_admin = *new_admin.key;
         }
     }
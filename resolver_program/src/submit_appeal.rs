@@ -0,0 +1,141 @@
+use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_jsm_core::{
+    create_account,
+    loader::{load_signer, load_system_account, load_system_program},
+};
+use jito_restaking_core::{ncn::Ncn, operator::Operator};
+use resolver_core::{
+    config::Config, ncn_resolver_program_config::NcnResolverProgramConfig,
+    slash_proposal::SlashProposal, slash_proposal_appeal::SlashProposalAppeal, slasher::Slasher,
+};
+use resolver_sdk::{error::ResolverError, event::ResolverEvent};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
+};
+
+pub fn process_submit_appeal(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    evidence_hash: [u8; 32],
+    metadata_uri: String,
+) -> ProgramResult {
+    let [config_info, ncn_resolver_program_config_info, ncn_info, operator_info, slasher_info, slash_proposal_info, slash_proposal_appeal_info, operator_admin_info, system_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config_info, false)?;
+    let config_data = config_info.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+
+    NcnResolverProgramConfig::load(
+        program_id,
+        ncn_resolver_program_config_info,
+        ncn_info,
+        false,
+    )?;
+    let ncn_resolver_program_config_data = ncn_resolver_program_config_info.data.borrow();
+    let ncn_resolver_program_config =
+        NcnResolverProgramConfig::try_from_slice_unchecked(&ncn_resolver_program_config_data)?;
+
+    Ncn::load(&config.jito_restaking_program, ncn_info, false)?;
+    Operator::load(&config.jito_restaking_program, operator_info, false)?;
+    let operator_data = operator_info.data.borrow();
+    let operator = Operator::try_from_slice_unchecked(&operator_data)?;
+
+    Slasher::load(program_id, slasher_info, false)?;
+
+    SlashProposal::load(
+        program_id,
+        slash_proposal_info,
+        ncn_info,
+        operator_info,
+        slasher_info,
+        true,
+    )?;
+    let mut slash_proposal_data = slash_proposal_info.data.borrow_mut();
+    let slash_proposal = SlashProposal::try_from_slice_unchecked_mut(&mut slash_proposal_data)?;
+
+    load_system_account(slash_proposal_appeal_info, true)?;
+    load_signer(operator_admin_info, true)?;
+    load_system_program(system_program)?;
+
+    if operator.admin.ne(operator_admin_info.key) {
+        msg!("Admin is not the operator admin");
+        return Err(ResolverError::OperatorAdminInvalid.into());
+    }
+
+    let current_slot = Clock::get()?.slot;
+
+    slash_proposal.check_appealable()?;
+    slash_proposal.check_veto_period_ended(current_slot)?;
+
+    // Initialize SlashProposalAppeal, which fails if the proposal was already appealed
+    {
+        let (
+            slash_proposal_appeal_pubkey,
+            slash_proposal_appeal_bump,
+            mut slash_proposal_appeal_seeds,
+        ) = SlashProposalAppeal::find_program_address(program_id, slash_proposal_info.key);
+        slash_proposal_appeal_seeds.push(vec![slash_proposal_appeal_bump]);
+        if slash_proposal_appeal_info
+            .key
+            .ne(&slash_proposal_appeal_pubkey)
+        {
+            msg!("SlashProposalAppeal account is not at the correct PDA");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let slash_proposal_appeal = SlashProposalAppeal::new(
+            *slash_proposal_info.key,
+            *operator_info.key,
+            evidence_hash,
+            &metadata_uri,
+            current_slot,
+            *operator_admin_info.key,
+            slash_proposal_appeal_bump,
+        )?;
+
+        msg!(
+            "Initializing slash proposal appeal at address: {}",
+            slash_proposal_appeal_info.key
+        );
+        create_account(
+            operator_admin_info,
+            slash_proposal_appeal_info,
+            system_program,
+            program_id,
+            &Rent::get()?,
+            8_u64
+                .checked_add(std::mem::size_of::<SlashProposalAppeal>() as u64)
+                .ok_or(ResolverError::ArithmeticOverflow)?,
+            &slash_proposal_appeal_seeds,
+        )?;
+
+        let mut slash_proposal_appeal_data = slash_proposal_appeal_info.try_borrow_mut_data()?;
+        slash_proposal_appeal_data[0] = SlashProposalAppeal::DISCRIMINATOR;
+        *SlashProposalAppeal::try_from_slice_unchecked_mut(&mut slash_proposal_appeal_data)? =
+            slash_proposal_appeal;
+    }
+
+    slash_proposal.record_appeal();
+    slash_proposal.extend_deadlines(ncn_resolver_program_config.appeal_extension_slots())?;
+
+    msg!(
+        "Veto deadline extended to slot {}, delete deadline to slot {}",
+        slash_proposal.veto_deadline_slot(),
+        slash_proposal.delete_deadline_slot()
+    );
+
+    ResolverEvent::AppealSubmitted {
+        slash_proposal: *slash_proposal_info.key,
+        operator: *operator_info.key,
+        evidence_hash,
+        veto_deadline_slot: slash_proposal.veto_deadline_slot(),
+    }
+    .emit();
+
+    Ok(())
+}
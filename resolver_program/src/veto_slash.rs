@@ -1,18 +1,29 @@
-use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::{load_signer, load_system_program};
+use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_jsm_core::{
+    create_account,
+    loader::{load_signer, load_system_account, load_system_program},
+};
 use jito_restaking_core::{ncn::Ncn, operator::Operator};
 use resolver_core::{
-    config::Config, ncn_resolver_program_config::NcnResolverProgramConfig,
-    ncn_slash_proposal_ticket::NcnSlashProposalTicket, resolver::Resolver,
-    slash_proposal::SlashProposal, slasher::Slasher,
+    config::Config,
+    ncn_resolver_program_config::NcnResolverProgramConfig,
+    ncn_resolver_ticket::NcnResolverTicket,
+    ncn_slash_proposal_ticket::NcnSlashProposalTicket,
+    resolver::Resolver,
+    resolver_vote::ResolverVote,
+    slash_proposal::{SlashProposal, SlashProposalStatus},
+    slasher::Slasher,
 };
+use resolver_sdk::{error::ResolverError, event::ResolverEvent};
 use solana_program::{
-    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
-    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
 };
 
+use crate::bond::release_bond;
+
 pub fn process_veto_slash(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
-    let [config_info, ncn_resolver_program_config_info, ncn_info, operator_info, slasher_info, resolver_info, slash_proposal_info, ncn_slash_proposal_ticket_info, resolver_admin_info, system_program] =
+    let [config_info, ncn_resolver_program_config_info, ncn_info, operator_info, slasher_info, resolver_info, slash_proposal_info, ncn_slash_proposal_ticket_info, resolver_vote_info, resolver_veto_admin_info, system_program, ncn_resolver_ticket_info, bond_destination_info, slash_proposal_bond_token_account_info, bond_mint_info, bond_token_program] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -40,13 +51,24 @@ pub fn process_veto_slash(program_id: &Pubkey, accounts: &[AccountInfo]) -> Prog
     let resolver_data = resolver_info.data.borrow();
     let resolver = Resolver::try_from_slice_unchecked(&resolver_data)?;
 
+    NcnResolverTicket::load(
+        program_id,
+        ncn_resolver_ticket_info,
+        ncn_info,
+        resolver_info,
+        false,
+    )?;
+    let ncn_resolver_ticket_data = ncn_resolver_ticket_info.data.borrow();
+    let ncn_resolver_ticket =
+        NcnResolverTicket::try_from_slice_unchecked(&ncn_resolver_ticket_data)?;
+
     SlashProposal::load(
         program_id,
         slash_proposal_info,
         ncn_info,
         operator_info,
         slasher_info,
-        false,
+        true,
     )?;
     let mut slash_proposal_data = slash_proposal_info.data.borrow_mut();
     let slash_proposal = SlashProposal::try_from_slice_unchecked_mut(&mut slash_proposal_data)?;
@@ -56,28 +78,111 @@ pub fn process_veto_slash(program_id: &Pubkey, accounts: &[AccountInfo]) -> Prog
         ncn_slash_proposal_ticket_info,
         ncn_info,
         slash_proposal_info,
-        false,
+        true,
     )?;
-    let ncn_slash_proposal_ticket_data = ncn_slash_proposal_ticket_info.data.borrow();
+    let mut ncn_slash_proposal_ticket_data = ncn_slash_proposal_ticket_info.data.borrow_mut();
     let ncn_slash_proposal_ticket =
-        NcnSlashProposalTicket::try_from_slice_unchecked(&ncn_slash_proposal_ticket_data)?;
+        NcnSlashProposalTicket::try_from_slice_unchecked_mut(&mut ncn_slash_proposal_ticket_data)?;
 
-    load_signer(resolver_admin_info, true)?;
+    load_system_account(resolver_vote_info, true)?;
+    load_signer(resolver_veto_admin_info, true)?;
     load_system_program(system_program)?;
 
-    resolver.check_admin(resolver_admin_info.key)?;
+    let current_slot = Clock::get()?.slot;
 
-    slash_proposal.check_veto_period_ended(Clock::get()?.slot)?;
-    slash_proposal.check_completed()?;
+    resolver.check_veto_admin(resolver_veto_admin_info.key)?;
+    resolver.check_registered()?;
+    ncn_resolver_ticket.check_active(current_slot, config.epoch_length())?;
+
+    slash_proposal.check_veto_period_ended(current_slot)?;
+    slash_proposal.check_transition(SlashProposalStatus::Vetoed)?;
 
-    ncn_slash_proposal_ticket.check_resolver(resolver_info.key)?;
     ncn_slash_proposal_ticket.check_slash_proposal(slash_proposal_info.key)?;
 
-    slash_proposal.set_completed(true);
-    slash_proposal.set_delete_deadline_slot(
-        slash_proposal.delete_deadline_slot()
-            + ncn_resolver_program_config.delete_slash_proposal_duration(),
+    // Initialize ResolverVote, which fails if the resolver already voted on this proposal
+    {
+        let (resolver_vote_pubkey, resolver_vote_bump, mut resolver_vote_seeds) =
+            ResolverVote::find_program_address(
+                program_id,
+                slash_proposal_info.key,
+                resolver_info.key,
+            );
+        resolver_vote_seeds.push(vec![resolver_vote_bump]);
+        if resolver_vote_info.key.ne(&resolver_vote_pubkey) {
+            msg!("ResolverVote account is not at the correct PDA");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        msg!(
+            "Initializing resolver vote at address: {}",
+            resolver_vote_info.key
+        );
+        create_account(
+            resolver_veto_admin_info,
+            resolver_vote_info,
+            system_program,
+            program_id,
+            &Rent::get()?,
+            8_u64
+                .checked_add(std::mem::size_of::<ResolverVote>() as u64)
+                .ok_or(ResolverError::ArithmeticOverflow)?,
+            &resolver_vote_seeds,
+        )?;
+
+        let mut resolver_vote_data = resolver_vote_info.try_borrow_mut_data()?;
+        resolver_vote_data[0] = ResolverVote::DISCRIMINATOR;
+        let resolver_vote = ResolverVote::try_from_slice_unchecked_mut(&mut resolver_vote_data)?;
+        *resolver_vote = ResolverVote::new(
+            *slash_proposal_info.key,
+            *resolver_info.key,
+            current_slot,
+            *resolver_veto_admin_info.key,
+            resolver_vote_bump,
+        );
+    }
+
+    let veto_threshold = ncn_resolver_program_config
+        .effective_veto_threshold(current_slot, config.epoch_length())?;
+    let veto_threshold_reached = ncn_slash_proposal_ticket.record_veto(veto_threshold)?;
+
+    msg!(
+        "Resolver vetoes: {}/{}",
+        ncn_slash_proposal_ticket.veto_count(),
+        veto_threshold
     );
 
+    if veto_threshold_reached {
+        slash_proposal.transition(SlashProposalStatus::Vetoed)?;
+        let delete_deadline_slot = current_slot
+            .checked_add(slash_proposal.delete_slash_proposal_duration())
+            .ok_or(ResolverError::ArithmeticOverflow)?;
+        slash_proposal.set_delete_deadline_slot(delete_deadline_slot);
+
+        // The slasher forfeits its bond once the proposal is vetoed
+        let bond_mint = slash_proposal.bond_mint;
+        let bond_recipient = slash_proposal.bond_recipient;
+        let bond_amount = slash_proposal.take_bond();
+        let slash_proposal_seeds = slash_proposal.signing_seeds(ncn_info.key);
+        drop(slash_proposal_data);
+
+        release_bond(
+            slash_proposal_info,
+            &slash_proposal_seeds,
+            bond_destination_info,
+            slash_proposal_bond_token_account_info,
+            bond_mint_info,
+            bond_token_program,
+            &bond_mint,
+            bond_amount,
+            &bond_recipient,
+        )?;
+
+        ResolverEvent::SlashVetoed {
+            slash_proposal: *slash_proposal_info.key,
+            veto_count: ncn_slash_proposal_ticket.veto_count(),
+        }
+        .emit();
+    }
+
     Ok(())
 }
@@ -1,6 +1,8 @@
 use solana_program::{decode_error::DecodeError, program_error::ProgramError};
 use thiserror::Error;
 
+/// Error codes are part of the program interface, new variants are only ever appended before
+/// the arithmetic errors so existing codes stay stable
 #[derive(Debug, Error)]
 pub enum ResolverError {
     #[error("ResolverAdminInvalid")]
@@ -15,6 +17,7 @@ pub enum ResolverError {
     SlashProposalVetoPeriodEnded,
     #[error("SlashProposalVetoPeriodNotEnded")]
     SlashProposalVetoPeriodNotEnded,
+    /// No longer returned, replaced by the transition errors below
     #[error("SlashProposalCompleted")]
     SlashProposalCompleted,
     #[error("SlashProposalInvalid")]
@@ -24,6 +27,132 @@ pub enum ResolverError {
     #[error("SlashProposalDeletePeriodNotEnded")]
     SlashProposalDeletePeriodNotEnded,
 
+    #[error("ConfigAdminInvalid")]
+    ConfigAdminInvalid,
+    #[error("ConfigEpochLengthInvalid")]
+    ConfigEpochLengthInvalid,
+
+    #[error("NcnAdminInvalid")]
+    NcnAdminInvalid,
+
+    #[error("OperatorAdminInvalid")]
+    OperatorAdminInvalid,
+
+    #[error("ResolverVetoAdminInvalid")]
+    ResolverVetoAdminInvalid,
+    #[error("ResolverNcnInvalid")]
+    ResolverNcnInvalid,
+    #[error("ResolverNcnAlreadyRegistered")]
+    ResolverNcnAlreadyRegistered,
+    #[error("ResolverBondMintInvalid")]
+    ResolverBondMintInvalid,
+    #[error("ResolverDeregistered")]
+    ResolverDeregistered,
+    #[error("ResolverNotDeregistered")]
+    ResolverNotDeregistered,
+    #[error("ResolverBondCooldownNotEnded")]
+    ResolverBondCooldownNotEnded,
+
+    #[error("NcnResolverProgramConfigAdminInvalid")]
+    NcnResolverProgramConfigAdminInvalid,
+    #[error("NcnResolverProgramConfigVetoThresholdInvalid")]
+    NcnResolverProgramConfigVetoThresholdInvalid,
+    #[error("NcnResolverProgramConfigAssignmentPolicyInvalid")]
+    NcnResolverProgramConfigAssignmentPolicyInvalid,
+    #[error("NcnResolverProgramConfigResolverPenaltyInvalid")]
+    NcnResolverProgramConfigResolverPenaltyInvalid,
+    #[error("NcnResolverProgramConfigProceedsInvalid")]
+    NcnResolverProgramConfigProceedsInvalid,
+    #[error("NcnResolverProgramConfigProceedsAccountInvalid")]
+    NcnResolverProgramConfigProceedsAccountInvalid,
+    #[error("NcnResolverProgramConfigAppealExtensionInvalid")]
+    NcnResolverProgramConfigAppealExtensionInvalid,
+
+    #[error("NcnResolverTicketFailedWarmup")]
+    NcnResolverTicketFailedWarmup,
+    #[error("NcnResolverTicketFailedCooldown")]
+    NcnResolverTicketFailedCooldown,
+    #[error("NcnResolverTicketInactive")]
+    NcnResolverTicketInactive,
+    #[error("NcnResolverTicketNotInactive")]
+    NcnResolverTicketNotInactive,
+
+    #[error("SlasherNcnInvalid")]
+    SlasherNcnInvalid,
+    #[error("SlasherTicketInactive")]
+    SlasherTicketInactive,
+
+    #[error("SlashProposalResolverNotAssigned")]
+    SlashProposalResolverNotAssigned,
+    #[error("SlashProposalAmountExceedsMaxSlashable")]
+    SlashProposalAmountExceedsMaxSlashable,
+    #[error("SlashProposalAmountExceedsDelegation")]
+    SlashProposalAmountExceedsDelegation,
+    #[error("SlashProposalMetadataUriTooLong")]
+    SlashProposalMetadataUriTooLong,
+    #[error("SlashProposalAmendedAmountInvalid")]
+    SlashProposalAmendedAmountInvalid,
+    #[error("SlashReasonInvalid")]
+    SlashReasonInvalid,
+    #[error("SlashAllocationInvalid")]
+    SlashAllocationInvalid,
+    #[error("SlashProposalVaultInvalid")]
+    SlashProposalVaultInvalid,
+    #[error("SlashProposalVaultCountInvalid")]
+    SlashProposalVaultCountInvalid,
+    #[error("SlashProposalVaultAlreadySlashed")]
+    SlashProposalVaultAlreadySlashed,
+    #[error("SlashProposalStatusInvalid")]
+    SlashProposalStatusInvalid,
+    #[error("SlashProposalCannotAssignResolver")]
+    SlashProposalCannotAssignResolver,
+    #[error("SlashProposalCannotVeto")]
+    SlashProposalCannotVeto,
+    #[error("SlashProposalCannotApprove")]
+    SlashProposalCannotApprove,
+    #[error("SlashProposalCannotExecute")]
+    SlashProposalCannotExecute,
+    #[error("SlashProposalCannotCancel")]
+    SlashProposalCannotCancel,
+    #[error("SlashProposalCannotExpire")]
+    SlashProposalCannotExpire,
+    #[error("SlashProposalCannotAppeal")]
+    SlashProposalCannotAppeal,
+    #[error("SlashProposalBondMintInvalid")]
+    SlashProposalBondMintInvalid,
+    #[error("SlashProposalBondDestinationInvalid")]
+    SlashProposalBondDestinationInvalid,
+    #[error("SlashProposalDecisionNotRequired")]
+    SlashProposalDecisionNotRequired,
+    #[error("SlashProposalResolverDecided")]
+    SlashProposalResolverDecided,
+    #[error("SlashProposalResolverAlreadyPenalized")]
+    SlashProposalResolverAlreadyPenalized,
+    #[error("SlashProposalVaultDelegationEmpty")]
+    SlashProposalVaultDelegationEmpty,
+    #[error("SlashProposalRentDestinationInvalid")]
+    SlashProposalRentDestinationInvalid,
+    #[error("SlashProposalHasVetoes")]
+    SlashProposalHasVetoes,
+    #[error("ResolverAssignmentsOpen")]
+    ResolverAssignmentsOpen,
+    #[error("NcnResolverProgramConfigVetoThresholdUnreachable")]
+    NcnResolverProgramConfigVetoThresholdUnreachable,
+    #[error("SlashProposalAmountZero")]
+    SlashProposalAmountZero,
+    #[error("ResolverNcnTicketsActive")]
+    ResolverNcnTicketsActive,
+    #[error("NcnResolverProgramConfigBondSinkRequired")]
+    NcnResolverProgramConfigBondSinkRequired,
+    #[error("SlashProposalAccountsMissing")]
+    SlashProposalAccountsMissing,
+    #[error("NcnResolverProgramConfigDurationInvalid")]
+    NcnResolverProgramConfigDurationInvalid,
+    #[error("SlashProposalCannotAmend")]
+    SlashProposalCannotAmend,
+    #[error("SlashProposalCannotRequireDecision")]
+    SlashProposalCannotRequireDecision,
+
     #[error("ArithmeticOverflow")]
     ArithmeticOverflow = 3000,
     #[error("ArithmeticUnderflow")]
@@ -0,0 +1,137 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{log::sol_log_data, pubkey::Pubkey};
+
+/// Prefix logged before every [`ResolverEvent`], used to tell them apart from other
+/// `Program data` logs
+pub const EVENT_DISCRIMINATOR: &[u8] = b"resolver_event";
+
+/// The log prefix the runtime adds to data logged through `sol_log_data`
+const PROGRAM_DATA_LOG_PREFIX: &str = "Program data: ";
+
+/// The admin role changed in a [`ResolverEvent::AdminChanged`] event
+#[derive(Debug, Clone, Copy, BorshSerialize, BorshDeserialize, PartialEq, Eq)]
+pub enum AdminRole {
+    Admin,
+    DelegateAdmin,
+    VetoAdmin,
+    MetadataAdmin,
+}
+
+/// Events emitted by the resolver program for every slash proposal lifecycle transition
+/// and admin change
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize, PartialEq, Eq)]
+pub enum ResolverEvent {
+    SlashProposed {
+        ncn: Pubkey,
+        operator: Pubkey,
+        slasher: Pubkey,
+        slash_proposal: Pubkey,
+        id: u64,
+        amount: u64,
+        veto_deadline_slot: u64,
+    },
+    ResolverAssigned {
+        slash_proposal: Pubkey,
+        resolver: Pubkey,
+    },
+    SlashVetoed {
+        slash_proposal: Pubkey,
+        veto_count: u64,
+    },
+    AppealSubmitted {
+        slash_proposal: Pubkey,
+        operator: Pubkey,
+        evidence_hash: [u8; 32],
+        /// The veto deadline after the appeal extension
+        veto_deadline_slot: u64,
+    },
+    SlashApproved {
+        slash_proposal: Pubkey,
+        resolver: Pubkey,
+    },
+    SlashAmended {
+        slash_proposal: Pubkey,
+        resolver: Pubkey,
+        original_amount: u64,
+        amount: u64,
+    },
+    SlashProceedsRouted {
+        slash_proposal: Pubkey,
+        vault: Pubkey,
+        /// The amount burned
+        burned: u64,
+        treasury: Pubkey,
+        /// The amount sent to the treasury
+        treasury_amount: u64,
+        insurance_fund: Pubkey,
+        /// The amount sent to the insurance fund
+        insurance_fund_amount: u64,
+        /// The amount kept by the slasher
+        slasher_amount: u64,
+    },
+    SlashExecuted {
+        slash_proposal: Pubkey,
+        vault: Pubkey,
+        /// The amount slashed from the vault
+        amount: u64,
+        /// The amount left to slash from the other vaults
+        remaining_amount: u64,
+    },
+    SlashCancelled {
+        slash_proposal: Pubkey,
+    },
+    ProposalDeleted {
+        slash_proposal: Pubkey,
+        /// Whether the proposal expired without reaching an outcome, the only record of the
+        /// Expired status as the proposal is closed
+        expired: bool,
+    },
+    ResolverPenalized {
+        resolver: Pubkey,
+        slash_proposal: Pubkey,
+        /// The amount forfeited from the resolver bond
+        amount: u64,
+    },
+    AdminChanged {
+        account: Pubkey,
+        role: AdminRole,
+        old_admin: Pubkey,
+        new_admin: Pubkey,
+    },
+}
+
+impl ResolverEvent {
+    /// Logs the event through `sol_log_data`
+    pub fn emit(&self) {
+        sol_log_data(&[EVENT_DISCRIMINATOR, &self.try_to_vec().unwrap()]);
+    }
+
+    /// Decodes an event from the fields logged through `sol_log_data`, returning `None` if
+    /// they are not a resolver event
+    pub fn decode(fields: &[&[u8]]) -> Option<Self> {
+        match fields {
+            [discriminator, data] if discriminator.eq(&EVENT_DISCRIMINATOR) => {
+                Self::try_from_slice(data).ok()
+            }
+            _ => None,
+        }
+    }
+
+    /// Decodes an event from a `Program data` transaction log
+    pub fn from_log(log: &str) -> Option<Self> {
+        let fields = log
+            .strip_prefix(PROGRAM_DATA_LOG_PREFIX)?
+            .split(' ')
+            .map(|field| STANDARD.decode(field).ok())
+            .collect::<Option<Vec<_>>>()?;
+        let fields: Vec<&[u8]> = fields.iter().map(|field| field.as_slice()).collect();
+
+        Self::decode(&fields)
+    }
+
+    /// Decodes all events from the logs of a transaction, in the order they were emitted
+    pub fn from_logs(logs: &[String]) -> Vec<Self> {
+        logs.iter().filter_map(|log| Self::from_log(log)).collect()
+    }
+}
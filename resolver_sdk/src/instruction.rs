@@ -1,12 +1,23 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use shank::ShankInstruction;
+use solana_program::pubkey::Pubkey;
+
+use crate::error::ResolverError;
 
 #[derive(Debug, BorshSerialize, BorshDeserialize, ShankInstruction)]
 pub enum ResolverInstruction {
     InitializeConfig,
+    /// Initializes the resolver settings for an NCN, signed by the NCN admin or slasher admin
+    #[account(0, name = "config")]
+    #[account(1, name = "ncn")]
+    #[account(2, writable, name = "ncn_resolver_program_config")]
+    #[account(3, writable, signer, name = "admin")]
+    #[account(4, name = "system_program")]
     InitializeNcnResolverProgramConfig {
         veto_duration: u64,
         delete_slash_proposal_duration: u64,
+        veto_threshold: u64,
+        require_resolver_assignment: bool,
     },
 
     #[account(0, name = "config")]
@@ -18,7 +29,7 @@ pub enum ResolverInstruction {
     InitializeSlasher,
 
     #[account(0, name = "config")]
-    #[account(1, writable, name = "ncn_resolver_program_config")]
+    #[account(1, name = "ncn_resolver_program_config")]
     #[account(2, name = "ncn")]
     #[account(3, writable, name = "resolver")]
     #[account(4, writable, signer, name = "admin")]
@@ -26,61 +37,103 @@ pub enum ResolverInstruction {
     #[account(6, name = "system_program")]
     InitializeResolver,
 
+    /// Proposes a slash of the operator across the vault and any other vaults delegating to it,
+    /// which follow the listed accounts as (vault, ncn_vault_slasher_ticket,
+    /// vault_operator_delegation) triples
     #[account(0, name = "config")]
-    #[account(1, name = "ncn_resolver_program_config")]
+    #[account(1, writable, name = "ncn_resolver_program_config")]
     #[account(2, name = "ncn")]
     #[account(3, name = "operator")]
-    #[account(4, name = "slasher")]
-    #[account(5, writable, name = "slash_proposal")]
-    #[account(6, writable, name = "ncn_slash_proposal_ticket")]
-    #[account(7, writable, signer, name = "slasher_admin")]
-    #[account(8, name = "system_program")]
+    #[account(4, writable, name = "slasher")]
+    #[account(5, name = "vault")]
+    #[account(6, name = "ncn_vault_slasher_ticket")]
+    #[account(7, writable, name = "slash_proposal")]
+    #[account(8, writable, name = "ncn_slash_proposal_ticket")]
+    #[account(9, writable, signer, name = "slasher_admin")]
+    #[account(10, name = "system_program")]
+    #[account(11, optional, name = "vault_operator_delegation")]
+    #[account(12, name = "slot_hashes")]
+    #[account(13, optional, writable, name = "resolver")]
+    #[account(14, optional, name = "ncn_resolver_ticket")]
+    #[account(15, optional, writable, name = "slasher_admin_bond_token_account")]
+    #[account(16, optional, writable, name = "slash_proposal_bond_token_account")]
+    #[account(17, optional, name = "bond_mint")]
+    #[account(18, optional, name = "bond_token_program")]
     ProposeSlash {
         slash_amount: u64,
+        reason: SlashReason,
+        evidence_hash: [u8; 32],
+        metadata_uri: String,
+        allocation: SlashAllocation,
     },
 
+    /// Assigns a resolver to a slash proposal. The resolver it replaces, if any, is passed as
+    /// `old_resolver` to release its assignment
     #[account(0, name = "config")]
-    #[account(1, name = "ncn")]
-    #[account(2, name = "operator")]
-    #[account(3, name = "slasher")]
-    #[account(4, name = "slash_proposal")]
-    #[account(5, writable, name = "ncn_slash_proposal_ticket")]
-    #[account(6, signer, name = "ncn_slasher_admin")]
-    #[account(7, name = "new_resolver_info")]
-    SetResolver,
-
-    #[account(0, name = "config")]
-    #[account(1, name = "ncn")]
-    #[account(2, name = "operator")]
-    #[account(3, name = "slasher")]
-    #[account(4, name = "resolver")]
+    #[account(1, name = "ncn_resolver_program_config")]
+    #[account(2, name = "ncn")]
+    #[account(3, name = "operator")]
+    #[account(4, name = "slasher")]
     #[account(5, writable, name = "slash_proposal")]
     #[account(6, writable, name = "ncn_slash_proposal_ticket")]
-    #[account(7, signer, name = "resolver_admin")]
-    #[account(8, name = "system_program")]
-    VetoSlash,
+    #[account(7, signer, name = "ncn_resolver_admin")]
+    #[account(8, writable, name = "new_resolver_info")]
+    #[account(9, name = "ncn_resolver_ticket")]
+    #[account(10, optional, writable, name = "old_resolver")]
+    SetResolver,
 
+    /// Casts a veto vote from a resolver registered for the NCN, the proposal is vetoed
+    /// once the NCN veto threshold is reached
     #[account(0, name = "config")]
-    #[account(1, name = "vault_config")]
+    #[account(1, name = "ncn_resolver_program_config")]
     #[account(2, name = "ncn")]
     #[account(3, name = "operator")]
     #[account(4, name = "slasher")]
-    #[account(5, writable, name = "vault")]
-    #[account(6, signer, name = "slasher_admin")]
-    #[account(7, name = "ncn_operator_state")]
-    #[account(8, name = "ncn_vault_ticket")]
-    #[account(9, name = "vault_ncn_ticket")]
-    #[account(10, writable, name = "vault_operator_delegation")]
-    #[account(11, name = "ncn_vault_slasher_ticket")]
-    #[account(12, name = "vault_ncn_slasker_ticket")]
-    #[account(13, writable, name = "vault_ncn_slasher_operator_ticket")]
-    #[account(14, writable, name = "vault_token_account")]
-    #[account(15, writable, name = "slasher_token_account")]
-    #[account(16, name = "resolver")]
-    #[account(17, writable, name = "slash_proposal")]
-    #[account(18, writable, name = "ncn_slash_proposal_ticket")]
-    #[account(19, name = "token_program")]
-    #[account(20, name = "jito_vault_program")]
+    #[account(5, name = "resolver")]
+    #[account(6, writable, name = "slash_proposal")]
+    #[account(7, writable, name = "ncn_slash_proposal_ticket")]
+    #[account(8, writable, name = "resolver_vote")]
+    #[account(9, writable, signer, name = "resolver_veto_admin")]
+    #[account(10, name = "system_program")]
+    #[account(11, name = "ncn_resolver_ticket")]
+    #[account(12, optional, writable, name = "bond_destination")]
+    #[account(13, optional, writable, name = "slash_proposal_bond_token_account")]
+    #[account(14, optional, name = "bond_mint")]
+    #[account(15, optional, name = "bond_token_program")]
+    VetoSlash,
+
+    /// Slashes the share of one vault of the slash proposal, the proposal is executed once every
+    /// vault it covers was slashed. The slashed funds are routed with the NCN's proceeds split.
+    #[account(0, name = "config")]
+    #[account(1, name = "ncn_resolver_program_config")]
+    #[account(2, name = "vault_config")]
+    #[account(3, name = "ncn")]
+    #[account(4, name = "operator")]
+    #[account(5, name = "slasher")]
+    #[account(6, writable, name = "vault")]
+    #[account(7, signer, name = "slasher_admin")]
+    #[account(8, name = "ncn_operator_state")]
+    #[account(9, name = "ncn_vault_ticket")]
+    #[account(10, name = "operator_vault_ticket")]
+    #[account(11, name = "vault_ncn_ticket")]
+    #[account(12, writable, name = "vault_operator_delegation")]
+    #[account(13, name = "ncn_vault_slasher_ticket")]
+    #[account(14, name = "vault_ncn_slasker_ticket")]
+    #[account(15, writable, name = "vault_ncn_slasher_operator_ticket")]
+    #[account(16, writable, name = "vault_token_account")]
+    #[account(17, writable, name = "slasher_token_account")]
+    #[account(18, name = "resolver")]
+    #[account(19, writable, name = "slash_proposal")]
+    #[account(20, writable, name = "ncn_slash_proposal_ticket")]
+    #[account(21, name = "token_program")]
+    #[account(22, name = "jito_vault_program")]
+    #[account(23, optional, writable, name = "bond_destination")]
+    #[account(24, optional, writable, name = "slash_proposal_bond_token_account")]
+    #[account(25, optional, name = "bond_mint")]
+    #[account(26, optional, name = "bond_token_program")]
+    #[account(27, optional, writable, name = "supported_mint")]
+    #[account(28, optional, writable, name = "treasury_token_account")]
+    #[account(29, optional, writable, name = "insurance_fund_token_account")]
     ExecuteSlash,
 
     #[account(0, name = "slasher")]
@@ -103,10 +156,361 @@ pub enum ResolverInstruction {
     #[account(2, name = "new_admin")]
     SlasherSetSecondaryAdmin(SlasherAdminRole),
 
+    /// Deletes a slash proposal once its delete deadline passed, expiring it if it never reached
+    /// an outcome, and releases its assigned resolver. The proposal is closed in the same
+    /// instruction, so the Expired status is only reported by the ProposalDeleted event. The
+    /// ResolverVote and SlashProposalAppeal accounts of the proposal follow as (account, payer)
+    /// pairs and are closed back to the admin that paid for them, every one of them must be passed
     DeleteSlashProposal,
+
+    /// Approves a slash proposal by the assigned resolver, allowing it to be executed
+    /// before the veto deadline
+    #[account(0, name = "config")]
+    #[account(1, name = "ncn_resolver_program_config")]
+    #[account(2, name = "ncn")]
+    #[account(3, name = "operator")]
+    #[account(4, name = "slasher")]
+    #[account(5, name = "resolver")]
+    #[account(6, writable, name = "slash_proposal")]
+    #[account(7, name = "ncn_slash_proposal_ticket")]
+    #[account(8, signer, name = "resolver_veto_admin")]
+    #[account(9, name = "ncn_resolver_ticket")]
+    ApproveSlash,
+
+    /// Cancels a pending slash proposal by the slasher admin, closing the proposal accounts and
+    /// releasing its assigned resolver.
+    /// The ResolverVote and SlashProposalAppeal accounts of the proposal follow as
+    /// (account, payer) pairs and are closed back to the admin that paid for them, every one of
+    /// them must be passed
+    #[account(0, name = "config")]
+    #[account(1, name = "ncn")]
+    #[account(2, name = "operator")]
+    #[account(3, name = "slasher")]
+    #[account(4, writable, name = "slash_proposal")]
+    #[account(5, writable, name = "ncn_slash_proposal_ticket")]
+    #[account(6, writable, signer, name = "slasher_admin")]
+    #[account(7, optional, writable, name = "bond_destination")]
+    #[account(8, optional, writable, name = "slash_proposal_bond_token_account")]
+    #[account(9, optional, name = "bond_mint")]
+    #[account(10, optional, name = "bond_token_program")]
+    #[account(11, optional, writable, name = "resolver")]
+    CancelSlashProposal,
+
+    /// Sets the admin for the global config
+    #[account(0, writable, name = "config")]
+    #[account(1, signer, name = "old_admin")]
+    #[account(2, signer, name = "new_admin")]
+    ConfigSetAdmin,
+
+    /// Updates the jito programs of the global config, fields left as `None` are unchanged. The
+    /// configs of both programs must be passed and share an epoch length, which is copied to the
+    /// global config
+    #[account(0, writable, name = "config")]
+    #[account(1, signer, name = "admin")]
+    #[account(2, name = "jito_restaking_config")]
+    #[account(3, name = "jito_vault_config")]
+    ConfigSetParameters {
+        jito_restaking_program: Option<Pubkey>,
+        jito_vault_program: Option<Pubkey>,
+    },
+
+    /// Sets the resolver admin of an NCN, takes effect immediately
+    #[account(0, name = "config")]
+    #[account(1, name = "ncn")]
+    #[account(2, writable, name = "ncn_resolver_program_config")]
+    #[account(3, signer, name = "old_admin")]
+    #[account(4, signer, name = "new_admin")]
+    NcnResolverProgramConfigSetAdmin,
+
+    /// Updates the durations, the resolver penalty, the appeal extension and the veto threshold of
+    /// an NCN, fields left as `None` are unchanged. Durations are snapshotted on a slash proposal
+    /// when it's created, so only later proposals are affected. The appeal extension can't exceed
+    /// the veto duration and the veto threshold can't be zero
+    #[account(0, name = "config")]
+    #[account(1, name = "ncn")]
+    #[account(2, writable, name = "ncn_resolver_program_config")]
+    #[account(3, signer, name = "resolver_admin")]
+    NcnResolverProgramConfigSetParameters {
+        veto_duration: Option<u64>,
+        delete_slash_proposal_duration: Option<u64>,
+        resolver_penalty_bps: Option<u16>,
+        appeal_extension_slots: Option<u64>,
+        veto_threshold: Option<u64>,
+    },
+
+    /// Sets the admin for a resolver
+    #[account(0, writable, name = "resolver")]
+    #[account(1, signer, name = "old_admin")]
+    #[account(2, signer, name = "new_admin")]
+    ResolverSetAdmin,
+
+    /// Sets a secondary admin for a resolver
+    #[account(0, writable, name = "resolver")]
+    #[account(1, signer, name = "admin")]
+    #[account(2, name = "new_admin")]
+    ResolverSetSecondaryAdmin(ResolverAdminRole),
+
+    /// Adds a resolver to the NCN registry at its next index, the ticket starts inactive
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "ncn_resolver_program_config")]
+    #[account(2, name = "ncn")]
+    #[account(3, name = "resolver")]
+    #[account(4, writable, name = "ncn_resolver_ticket")]
+    #[account(5, writable, signer, name = "resolver_admin")]
+    #[account(6, name = "system_program")]
+    #[account(7, optional, name = "resolver_ncn_ticket")]
+    InitializeNcnResolverTicket,
+
+    /// Warms up a resolver for the NCN, it becomes active at the next epoch
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "ncn_resolver_program_config")]
+    #[account(2, name = "ncn")]
+    #[account(3, writable, name = "resolver")]
+    #[account(4, writable, name = "ncn_resolver_ticket")]
+    #[account(5, signer, name = "resolver_admin")]
+    WarmupNcnResolverTicket,
+
+    /// Cools down a resolver for the NCN, it becomes inactive at the next epoch
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "ncn_resolver_program_config")]
+    #[account(2, name = "ncn")]
+    #[account(3, writable, name = "resolver")]
+    #[account(4, writable, name = "ncn_resolver_ticket")]
+    #[account(5, signer, name = "resolver_admin")]
+    CooldownNcnResolverTicket,
+
+    /// Removes an inactive resolver from the NCN registry, closing its ticket. The ticket holding
+    /// the last index of the registry is passed as `last_ncn_resolver_ticket` and moves into the
+    /// freed index
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "ncn_resolver_program_config")]
+    #[account(2, name = "ncn")]
+    #[account(3, name = "resolver")]
+    #[account(4, writable, name = "ncn_resolver_ticket")]
+    #[account(5, writable, signer, name = "resolver_admin")]
+    #[account(6, optional, name = "last_resolver")]
+    #[account(7, optional, writable, name = "last_ncn_resolver_ticket")]
+    RemoveNcnResolverTicket,
+
+    /// Opts a resolver into an NCN other than the one it was initialized for
+    #[account(0, name = "config")]
+    #[account(1, name = "ncn_resolver_program_config")]
+    #[account(2, name = "ncn")]
+    #[account(3, name = "resolver")]
+    #[account(4, writable, name = "resolver_ncn_ticket")]
+    #[account(5, writable, signer, name = "resolver_admin")]
+    #[account(6, name = "system_program")]
+    InitializeResolverNcnTicket,
+
+    /// Sets how a resolver is assigned to new slash proposals of the NCN
+    #[account(0, name = "config")]
+    #[account(1, name = "ncn")]
+    #[account(2, writable, name = "ncn_resolver_program_config")]
+    #[account(3, signer, name = "resolver_admin")]
+    #[account(4, optional, name = "default_resolver")]
+    NcnResolverProgramConfigSetAssignmentPolicy(ResolverAssignmentPolicy),
+
+    /// Sets the bond slashers deposit when proposing a slash against the NCN, in SOL when no
+    /// bond mint is passed
+    #[account(0, name = "config")]
+    #[account(1, name = "ncn")]
+    #[account(2, writable, name = "ncn_resolver_program_config")]
+    #[account(3, signer, name = "resolver_admin")]
+    #[account(4, optional, name = "bond_mint")]
+    NcnResolverProgramConfigSetBond {
+        bond_amount: u64,
+        bond_sink: Pubkey,
+    },
+
+    /// Locks tokens in the resolver bond, held in a token account owned by the resolver
+    #[account(0, writable, name = "resolver")]
+    #[account(1, signer, name = "resolver_admin")]
+    #[account(2, name = "bond_mint")]
+    #[account(3, writable, name = "resolver_admin_token_account")]
+    #[account(4, writable, name = "resolver_bond_token_account")]
+    #[account(5, name = "token_program")]
+    ResolverDepositBond {
+        amount: u64,
+    },
+
+    /// Deregisters a resolver, starting the cooldown before its bond can be withdrawn. Every
+    /// active NcnResolverTicket of the resolver follows as an (ncn, ncn_resolver_program_config,
+    /// ncn_resolver_ticket) triple and is cooled down
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "resolver")]
+    #[account(2, signer, name = "resolver_admin")]
+    ResolverDeregister,
+
+    /// Withdraws the whole bond of a deregistered resolver once its cooldown ended
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "resolver")]
+    #[account(2, signer, name = "resolver_admin")]
+    #[account(3, name = "bond_mint")]
+    #[account(4, writable, name = "resolver_bond_token_account")]
+    #[account(5, writable, name = "resolver_admin_token_account")]
+    #[account(6, name = "token_program")]
+    ResolverWithdrawBond,
+
+    /// Requires the resolver assigned to a slash proposal to veto or approve it before the veto
+    /// deadline, or be penalized
+    #[account(0, name = "config")]
+    #[account(1, name = "ncn_resolver_program_config")]
+    #[account(2, name = "ncn")]
+    #[account(3, name = "operator")]
+    #[account(4, name = "slasher")]
+    #[account(5, name = "slash_proposal")]
+    #[account(6, writable, name = "ncn_slash_proposal_ticket")]
+    #[account(7, signer, name = "ncn_resolver_admin")]
+    RequireResolverDecision,
+
+    /// Forfeits part of the bond of a resolver that missed a decision required by the NCN to
+    /// the NCN bond sink, or the operator if there is none
+    #[account(0, name = "config")]
+    #[account(1, name = "ncn_resolver_program_config")]
+    #[account(2, name = "ncn")]
+    #[account(3, name = "operator")]
+    #[account(4, name = "slasher")]
+    #[account(5, name = "slash_proposal")]
+    #[account(6, writable, name = "ncn_slash_proposal_ticket")]
+    #[account(7, writable, name = "resolver")]
+    #[account(8, name = "resolver_vote")]
+    #[account(9, name = "bond_mint")]
+    #[account(10, writable, name = "resolver_bond_token_account")]
+    #[account(11, writable, name = "penalty_destination")]
+    #[account(12, name = "token_program")]
+    #[account(13, signer, name = "ncn_resolver_admin")]
+    PenalizeResolver,
+
+    /// Lowers the amount of a slash proposal by the assigned resolver before the veto deadline,
+    /// the amended amount is the one transferred on execution
+    #[account(0, name = "config")]
+    #[account(1, name = "ncn_resolver_program_config")]
+    #[account(2, name = "ncn")]
+    #[account(3, name = "operator")]
+    #[account(4, name = "slasher")]
+    #[account(5, name = "resolver")]
+    #[account(6, writable, name = "slash_proposal")]
+    #[account(7, name = "ncn_slash_proposal_ticket")]
+    #[account(8, signer, name = "resolver_veto_admin")]
+    #[account(9, name = "ncn_resolver_ticket")]
+    AmendSlashAmount {
+        amount: u64,
+    },
+
+    /// Sets how funds slashed from the NCN's operators are split between a burn, the NCN
+    /// treasury and an insurance fund, in bps. The slasher keeps the rest.
+    #[account(0, name = "config")]
+    #[account(1, name = "ncn")]
+    #[account(2, writable, name = "ncn_resolver_program_config")]
+    #[account(3, signer, name = "resolver_admin")]
+    NcnResolverProgramConfigSetProceeds {
+        burn_bps: u16,
+        treasury: Pubkey,
+        treasury_bps: u16,
+        insurance_fund: Pubkey,
+        insurance_fund_bps: u16,
+    },
+
+    /// Appeals a slash proposal by the operator admin with counter-evidence, before the veto
+    /// deadline. The veto deadline is extended by the NCN appeal extension, once per proposal
+    #[account(0, name = "config")]
+    #[account(1, name = "ncn_resolver_program_config")]
+    #[account(2, name = "ncn")]
+    #[account(3, name = "operator")]
+    #[account(4, name = "slasher")]
+    #[account(5, writable, name = "slash_proposal")]
+    #[account(6, writable, name = "slash_proposal_appeal")]
+    #[account(7, writable, signer, name = "operator_admin")]
+    #[account(8, name = "system_program")]
+    SubmitAppeal {
+        evidence_hash: [u8; 32],
+        metadata_uri: String,
+    },
 }
 
 #[derive(Debug, BorshSerialize, BorshDeserialize, PartialEq, Eq)]
 pub enum SlasherAdminRole {
     DelegateAdmin,
 }
+
+#[derive(Debug, BorshSerialize, BorshDeserialize, PartialEq, Eq)]
+pub enum ResolverAdminRole {
+    /// Signs vetoes, approvals and amendments of slash proposals
+    VetoAdmin,
+    /// Placeholder for managing the resolver metadata, no instruction checks it yet
+    MetadataAdmin,
+}
+
+/// How a resolver is assigned to a slash proposal when it's proposed
+#[derive(Debug, Clone, Copy, BorshSerialize, BorshDeserialize, PartialEq, Eq)]
+#[repr(u8)]
+pub enum ResolverAssignmentPolicy {
+    /// The NCN resolver admin assigns resolvers with SetResolver
+    Manual,
+    /// The default resolver of the NCN is assigned
+    Fixed,
+    /// Resolvers are assigned in turn by their index in the NCN registry
+    RoundRobin,
+    /// A resolver index is drawn from the most recent slot hash
+    Random,
+}
+
+impl TryFrom<u8> for ResolverAssignmentPolicy {
+    type Error = ResolverError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Manual),
+            1 => Ok(Self::Fixed),
+            2 => Ok(Self::RoundRobin),
+            3 => Ok(Self::Random),
+            _ => Err(ResolverError::NcnResolverProgramConfigAssignmentPolicyInvalid),
+        }
+    }
+}
+
+/// The reason a slash was proposed
+#[derive(Debug, Clone, Copy, BorshSerialize, BorshDeserialize, PartialEq, Eq)]
+#[repr(u8)]
+pub enum SlashReason {
+    Downtime,
+    Equivocation,
+    InvalidResult,
+    Custom,
+}
+
+impl TryFrom<u8> for SlashReason {
+    type Error = ResolverError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Downtime),
+            1 => Ok(Self::Equivocation),
+            2 => Ok(Self::InvalidResult),
+            3 => Ok(Self::Custom),
+            _ => Err(ResolverError::SlashReasonInvalid),
+        }
+    }
+}
+
+/// How the amount of a slash proposal is split across the vaults delegating to the operator
+#[derive(Debug, Clone, Copy, BorshSerialize, BorshDeserialize, PartialEq, Eq)]
+#[repr(u8)]
+pub enum SlashAllocation {
+    /// Each vault is slashed in proportion to the stake it delegated to the operator
+    ProRata,
+    /// Vaults are slashed up to their delegated stake in the order they are passed
+    Ordered,
+}
+
+impl TryFrom<u8> for SlashAllocation {
+    type Error = ResolverError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::ProRata),
+            1 => Ok(Self::Ordered),
+            _ => Err(ResolverError::SlashAllocationInvalid),
+        }
+    }
+}
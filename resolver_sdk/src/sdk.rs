@@ -2,10 +2,13 @@ use borsh::BorshSerialize;
 use solana_program::{
     instruction::{AccountMeta, Instruction},
     pubkey::Pubkey,
-    system_program,
+    system_program, sysvar,
 };
 
-use crate::instruction::{ResolverInstruction, SlasherAdminRole};
+use crate::instruction::{
+    ResolverAdminRole, ResolverAssignmentPolicy, ResolverInstruction, SlashAllocation, SlashReason,
+    SlasherAdminRole,
+};
 
 pub fn initialize_config(
     program_id: &Pubkey,
@@ -29,6 +32,7 @@ pub fn initialize_config(
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn initialize_ncn_resolver_program_config(
     program_id: &Pubkey,
     config: &Pubkey,
@@ -37,6 +41,8 @@ pub fn initialize_ncn_resolver_program_config(
     admin: &Pubkey,
     veto_duration: u64,
     delete_slash_proposal_duration: u64,
+    veto_threshold: u64,
+    require_resolver_assignment: bool,
 ) -> Instruction {
     let accounts = vec![
         AccountMeta::new_readonly(*config, false),
@@ -52,6 +58,8 @@ pub fn initialize_ncn_resolver_program_config(
         data: ResolverInstruction::InitializeNcnResolverProgramConfig {
             veto_duration,
             delete_slash_proposal_duration,
+            veto_threshold,
+            require_resolver_assignment,
         }
         .try_to_vec()
         .unwrap(),
@@ -70,7 +78,7 @@ pub fn initialize_resolver(
 ) -> Instruction {
     let accounts = vec![
         AccountMeta::new_readonly(*config, false),
-        AccountMeta::new(*ncn_resolver_program_config, false),
+        AccountMeta::new_readonly(*ncn_resolver_program_config, false),
         AccountMeta::new_readonly(*ncn, false),
         AccountMeta::new(*resolver, false),
         AccountMeta::new(*admin, true),
@@ -111,6 +119,8 @@ pub fn initialize_slasher(
     }
 }
 
+/// `other_vaults` are the (vault, ncn_vault_slasher_ticket, vault_operator_delegation) of the
+/// other vaults slashed by the proposal
 #[allow(clippy::too_many_arguments)]
 pub fn propose_slash(
     program_id: &Pubkey,
@@ -119,29 +129,70 @@ pub fn propose_slash(
     ncn: &Pubkey,
     operator: &Pubkey,
     slasher: &Pubkey,
+    vault: &Pubkey,
+    ncn_vault_slasher_ticket: &Pubkey,
     slash_proposal: &Pubkey,
     ncn_slash_proposal_ticket: &Pubkey,
     slasher_admin: &Pubkey,
+    vault_operator_delegation: Option<&Pubkey>,
+    resolver: Option<&Pubkey>,
+    ncn_resolver_ticket: Option<&Pubkey>,
+    slasher_admin_bond_token_account: Option<&Pubkey>,
+    slash_proposal_bond_token_account: Option<&Pubkey>,
+    bond_mint: Option<&Pubkey>,
+    bond_token_program: Option<&Pubkey>,
     slash_amount: u64,
+    reason: SlashReason,
+    evidence_hash: [u8; 32],
+    metadata_uri: String,
+    allocation: SlashAllocation,
+    other_vaults: &[(Pubkey, Pubkey, Pubkey)],
 ) -> Instruction {
-    let accounts = vec![
+    let mut accounts = vec![
         AccountMeta::new_readonly(*config, false),
-        AccountMeta::new_readonly(*ncn_resolver_program_config, false),
+        AccountMeta::new(*ncn_resolver_program_config, false),
         AccountMeta::new_readonly(*ncn, false),
         AccountMeta::new_readonly(*operator, false),
-        AccountMeta::new_readonly(*slasher, false),
+        AccountMeta::new(*slasher, false),
+        AccountMeta::new_readonly(*vault, false),
+        AccountMeta::new_readonly(*ncn_vault_slasher_ticket, false),
         AccountMeta::new(*slash_proposal, false),
         AccountMeta::new(*ncn_slash_proposal_ticket, false),
         AccountMeta::new(*slasher_admin, true),
         AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(*vault_operator_delegation.unwrap_or(program_id), false),
+        AccountMeta::new_readonly(sysvar::slot_hashes::id(), false),
+        AccountMeta::new(*resolver.unwrap_or(program_id), false),
+        AccountMeta::new_readonly(*ncn_resolver_ticket.unwrap_or(program_id), false),
+        AccountMeta::new(
+            *slasher_admin_bond_token_account.unwrap_or(program_id),
+            false,
+        ),
+        AccountMeta::new(
+            *slash_proposal_bond_token_account.unwrap_or(program_id),
+            false,
+        ),
+        AccountMeta::new_readonly(*bond_mint.unwrap_or(program_id), false),
+        AccountMeta::new_readonly(*bond_token_program.unwrap_or(program_id), false),
     ];
+    for (vault, ncn_vault_slasher_ticket, vault_operator_delegation) in other_vaults {
+        accounts.push(AccountMeta::new_readonly(*vault, false));
+        accounts.push(AccountMeta::new_readonly(*ncn_vault_slasher_ticket, false));
+        accounts.push(AccountMeta::new_readonly(*vault_operator_delegation, false));
+    }
 
     Instruction {
         program_id: *program_id,
         accounts,
-        data: ResolverInstruction::ProposeSlash { slash_amount }
-            .try_to_vec()
-            .unwrap(),
+        data: ResolverInstruction::ProposeSlash {
+            slash_amount,
+            reason,
+            evidence_hash,
+            metadata_uri,
+            allocation,
+        }
+        .try_to_vec()
+        .unwrap(),
     }
 }
 
@@ -157,6 +208,8 @@ pub fn set_resolver(
     ncn_slash_proposal_ticket: &Pubkey,
     ncn_resolver_admin: &Pubkey,
     new_resolver_info: &Pubkey,
+    ncn_resolver_ticket: &Pubkey,
+    old_resolver: Option<&Pubkey>,
 ) -> Instruction {
     let accounts = vec![
         AccountMeta::new_readonly(*config, false),
@@ -164,10 +217,12 @@ pub fn set_resolver(
         AccountMeta::new_readonly(*ncn, false),
         AccountMeta::new_readonly(*operator, false),
         AccountMeta::new_readonly(*slasher, false),
-        AccountMeta::new_readonly(*slash_proposal, false),
+        AccountMeta::new(*slash_proposal, false),
         AccountMeta::new(*ncn_slash_proposal_ticket, false),
         AccountMeta::new_readonly(*ncn_resolver_admin, true),
-        AccountMeta::new_readonly(*new_resolver_info, false),
+        AccountMeta::new(*new_resolver_info, false),
+        AccountMeta::new_readonly(*ncn_resolver_ticket, false),
+        AccountMeta::new(*old_resolver.unwrap_or(program_id), false),
     ];
 
     Instruction {
@@ -188,7 +243,13 @@ pub fn veto_slash(
     resolver: &Pubkey,
     slash_proposal: &Pubkey,
     ncn_slash_proposal_ticket: &Pubkey,
-    resolver_admin: &Pubkey,
+    resolver_vote: &Pubkey,
+    resolver_veto_admin: &Pubkey,
+    ncn_resolver_ticket: &Pubkey,
+    bond_destination: Option<&Pubkey>,
+    slash_proposal_bond_token_account: Option<&Pubkey>,
+    bond_mint: Option<&Pubkey>,
+    bond_token_program: Option<&Pubkey>,
 ) -> Instruction {
     let accounts = vec![
         AccountMeta::new_readonly(*config, false),
@@ -199,8 +260,17 @@ pub fn veto_slash(
         AccountMeta::new_readonly(*resolver, false),
         AccountMeta::new(*slash_proposal, false),
         AccountMeta::new(*ncn_slash_proposal_ticket, false),
-        AccountMeta::new(*resolver_admin, true),
+        AccountMeta::new(*resolver_vote, false),
+        AccountMeta::new(*resolver_veto_admin, true),
         AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(*ncn_resolver_ticket, false),
+        AccountMeta::new(*bond_destination.unwrap_or(program_id), false),
+        AccountMeta::new(
+            *slash_proposal_bond_token_account.unwrap_or(program_id),
+            false,
+        ),
+        AccountMeta::new_readonly(*bond_mint.unwrap_or(program_id), false),
+        AccountMeta::new_readonly(*bond_token_program.unwrap_or(program_id), false),
     ];
 
     Instruction {
@@ -234,6 +304,13 @@ pub fn execute_slash(
     resolver: &Pubkey,
     slash_proposal: &Pubkey,
     ncn_slash_proposal_ticket: &Pubkey,
+    bond_destination: Option<&Pubkey>,
+    slash_proposal_bond_token_account: Option<&Pubkey>,
+    bond_mint: Option<&Pubkey>,
+    bond_token_program: Option<&Pubkey>,
+    supported_mint: Option<&Pubkey>,
+    treasury_token_account: Option<&Pubkey>,
+    insurance_fund_token_account: Option<&Pubkey>,
 ) -> Instruction {
     let accounts = vec![
         AccountMeta::new_readonly(*config, false),
@@ -259,6 +336,16 @@ pub fn execute_slash(
         AccountMeta::new(*ncn_slash_proposal_ticket, false),
         AccountMeta::new_readonly(spl_token::id(), false),
         AccountMeta::new_readonly(jito_vault_program::id(), false),
+        AccountMeta::new(*bond_destination.unwrap_or(program_id), false),
+        AccountMeta::new(
+            *slash_proposal_bond_token_account.unwrap_or(program_id),
+            false,
+        ),
+        AccountMeta::new_readonly(*bond_mint.unwrap_or(program_id), false),
+        AccountMeta::new_readonly(*bond_token_program.unwrap_or(program_id), false),
+        AccountMeta::new(*supported_mint.unwrap_or(program_id), false),
+        AccountMeta::new(*treasury_token_account.unwrap_or(program_id), false),
+        AccountMeta::new(*insurance_fund_token_account.unwrap_or(program_id), false),
     ];
 
     Instruction {
@@ -333,6 +420,8 @@ pub fn slasher_set_secondary_admin(
     }
 }
 
+/// `resolver` is the resolver assigned to the proposal, if any, released once it is closed.
+/// `closed_accounts` are (account, rent destination) pairs closed along with the proposal
 #[allow(clippy::too_many_arguments)]
 pub fn delete_slash_proposal(
     program_id: &Pubkey,
@@ -343,8 +432,14 @@ pub fn delete_slash_proposal(
     slash_proposal: &Pubkey,
     ncn_slash_proposal_ticket: &Pubkey,
     payer: &Pubkey,
+    bond_destination: Option<&Pubkey>,
+    slash_proposal_bond_token_account: Option<&Pubkey>,
+    bond_mint: Option<&Pubkey>,
+    bond_token_program: Option<&Pubkey>,
+    resolver: Option<&Pubkey>,
+    closed_accounts: &[(Pubkey, Pubkey)],
 ) -> Instruction {
-    let accounts = vec![
+    let mut accounts = vec![
         AccountMeta::new_readonly(*config, false),
         AccountMeta::new_readonly(*ncn, false),
         AccountMeta::new_readonly(*operator, false),
@@ -353,7 +448,19 @@ pub fn delete_slash_proposal(
         AccountMeta::new(*ncn_slash_proposal_ticket, false),
         AccountMeta::new(*payer, true),
         AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new(*bond_destination.unwrap_or(program_id), false),
+        AccountMeta::new(
+            *slash_proposal_bond_token_account.unwrap_or(program_id),
+            false,
+        ),
+        AccountMeta::new_readonly(*bond_mint.unwrap_or(program_id), false),
+        AccountMeta::new_readonly(*bond_token_program.unwrap_or(program_id), false),
+        AccountMeta::new(*resolver.unwrap_or(program_id), false),
     ];
+    for (account, destination) in closed_accounts {
+        accounts.push(AccountMeta::new(*account, false));
+        accounts.push(AccountMeta::new(*destination, false));
+    }
     Instruction {
         program_id: *program_id,
         accounts,
@@ -362,3 +469,685 @@ pub fn delete_slash_proposal(
             .unwrap(),
     }
 }
+
+#[allow(clippy::too_many_arguments)]
+pub fn approve_slash(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    ncn_resolver_program_config: &Pubkey,
+    ncn: &Pubkey,
+    operator: &Pubkey,
+    slasher: &Pubkey,
+    resolver: &Pubkey,
+    slash_proposal: &Pubkey,
+    ncn_slash_proposal_ticket: &Pubkey,
+    resolver_veto_admin: &Pubkey,
+    ncn_resolver_ticket: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*ncn_resolver_program_config, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new_readonly(*operator, false),
+        AccountMeta::new_readonly(*slasher, false),
+        AccountMeta::new_readonly(*resolver, false),
+        AccountMeta::new(*slash_proposal, false),
+        AccountMeta::new_readonly(*ncn_slash_proposal_ticket, false),
+        AccountMeta::new_readonly(*resolver_veto_admin, true),
+        AccountMeta::new_readonly(*ncn_resolver_ticket, false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: ResolverInstruction::ApproveSlash.try_to_vec().unwrap(),
+    }
+}
+
+/// `resolver` is the resolver assigned to the proposal, if any, released once it is closed.
+/// `closed_accounts` are (account, rent destination) pairs closed along with the proposal
+#[allow(clippy::too_many_arguments)]
+pub fn cancel_slash_proposal(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    ncn: &Pubkey,
+    operator: &Pubkey,
+    slasher: &Pubkey,
+    slash_proposal: &Pubkey,
+    ncn_slash_proposal_ticket: &Pubkey,
+    slasher_admin: &Pubkey,
+    bond_destination: Option<&Pubkey>,
+    slash_proposal_bond_token_account: Option<&Pubkey>,
+    bond_mint: Option<&Pubkey>,
+    bond_token_program: Option<&Pubkey>,
+    resolver: Option<&Pubkey>,
+    closed_accounts: &[(Pubkey, Pubkey)],
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new_readonly(*operator, false),
+        AccountMeta::new_readonly(*slasher, false),
+        AccountMeta::new(*slash_proposal, false),
+        AccountMeta::new(*ncn_slash_proposal_ticket, false),
+        AccountMeta::new(*slasher_admin, true),
+        AccountMeta::new(*bond_destination.unwrap_or(program_id), false),
+        AccountMeta::new(
+            *slash_proposal_bond_token_account.unwrap_or(program_id),
+            false,
+        ),
+        AccountMeta::new_readonly(*bond_mint.unwrap_or(program_id), false),
+        AccountMeta::new_readonly(*bond_token_program.unwrap_or(program_id), false),
+        AccountMeta::new(*resolver.unwrap_or(program_id), false),
+    ];
+    for (account, destination) in closed_accounts {
+        accounts.push(AccountMeta::new(*account, false));
+        accounts.push(AccountMeta::new(*destination, false));
+    }
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: ResolverInstruction::CancelSlashProposal
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn config_set_admin(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    old_admin: &Pubkey,
+    new_admin: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*config, false),
+        AccountMeta::new_readonly(*old_admin, true),
+        AccountMeta::new_readonly(*new_admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: ResolverInstruction::ConfigSetAdmin.try_to_vec().unwrap(),
+    }
+}
+
+pub fn config_set_parameters(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    admin: &Pubkey,
+    jito_restaking_config: &Pubkey,
+    jito_vault_config: &Pubkey,
+    jito_restaking_program: Option<Pubkey>,
+    jito_vault_program: Option<Pubkey>,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*config, false),
+        AccountMeta::new_readonly(*admin, true),
+        AccountMeta::new_readonly(*jito_restaking_config, false),
+        AccountMeta::new_readonly(*jito_vault_config, false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: ResolverInstruction::ConfigSetParameters {
+            jito_restaking_program,
+            jito_vault_program,
+        }
+        .try_to_vec()
+        .unwrap(),
+    }
+}
+
+pub fn ncn_resolver_program_config_set_admin(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    ncn: &Pubkey,
+    ncn_resolver_program_config: &Pubkey,
+    old_admin: &Pubkey,
+    new_admin: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new(*ncn_resolver_program_config, false),
+        AccountMeta::new_readonly(*old_admin, true),
+        AccountMeta::new_readonly(*new_admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: ResolverInstruction::NcnResolverProgramConfigSetAdmin
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn ncn_resolver_program_config_set_parameters(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    ncn: &Pubkey,
+    ncn_resolver_program_config: &Pubkey,
+    resolver_admin: &Pubkey,
+    veto_duration: Option<u64>,
+    delete_slash_proposal_duration: Option<u64>,
+    resolver_penalty_bps: Option<u16>,
+    appeal_extension_slots: Option<u64>,
+    veto_threshold: Option<u64>,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new(*ncn_resolver_program_config, false),
+        AccountMeta::new_readonly(*resolver_admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: ResolverInstruction::NcnResolverProgramConfigSetParameters {
+            veto_duration,
+            delete_slash_proposal_duration,
+            resolver_penalty_bps,
+            appeal_extension_slots,
+            veto_threshold,
+        }
+        .try_to_vec()
+        .unwrap(),
+    }
+}
+
+pub fn resolver_set_admin(
+    program_id: &Pubkey,
+    resolver: &Pubkey,
+    old_admin: &Pubkey,
+    new_admin: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*resolver, false),
+        AccountMeta::new_readonly(*old_admin, true),
+        AccountMeta::new_readonly(*new_admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: ResolverInstruction::ResolverSetAdmin.try_to_vec().unwrap(),
+    }
+}
+
+pub fn resolver_set_secondary_admin(
+    program_id: &Pubkey,
+    resolver: &Pubkey,
+    admin: &Pubkey,
+    new_admin: &Pubkey,
+    resolver_admin_role: ResolverAdminRole,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*resolver, false),
+        AccountMeta::new_readonly(*admin, true),
+        AccountMeta::new_readonly(*new_admin, false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: ResolverInstruction::ResolverSetSecondaryAdmin(resolver_admin_role)
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn initialize_ncn_resolver_ticket(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    ncn_resolver_program_config: &Pubkey,
+    ncn: &Pubkey,
+    resolver: &Pubkey,
+    ncn_resolver_ticket: &Pubkey,
+    resolver_admin: &Pubkey,
+    resolver_ncn_ticket: Option<&Pubkey>,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*ncn_resolver_program_config, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new_readonly(*resolver, false),
+        AccountMeta::new(*ncn_resolver_ticket, false),
+        AccountMeta::new(*resolver_admin, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(*resolver_ncn_ticket.unwrap_or(program_id), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: ResolverInstruction::InitializeNcnResolverTicket
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn warmup_ncn_resolver_ticket(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    ncn_resolver_program_config: &Pubkey,
+    ncn: &Pubkey,
+    resolver: &Pubkey,
+    ncn_resolver_ticket: &Pubkey,
+    resolver_admin: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*ncn_resolver_program_config, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new(*resolver, false),
+        AccountMeta::new(*ncn_resolver_ticket, false),
+        AccountMeta::new_readonly(*resolver_admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: ResolverInstruction::WarmupNcnResolverTicket
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn cooldown_ncn_resolver_ticket(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    ncn_resolver_program_config: &Pubkey,
+    ncn: &Pubkey,
+    resolver: &Pubkey,
+    ncn_resolver_ticket: &Pubkey,
+    resolver_admin: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*ncn_resolver_program_config, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new(*resolver, false),
+        AccountMeta::new(*ncn_resolver_ticket, false),
+        AccountMeta::new_readonly(*resolver_admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: ResolverInstruction::CooldownNcnResolverTicket
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn remove_ncn_resolver_ticket(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    ncn_resolver_program_config: &Pubkey,
+    ncn: &Pubkey,
+    resolver: &Pubkey,
+    ncn_resolver_ticket: &Pubkey,
+    resolver_admin: &Pubkey,
+    last_resolver: Option<&Pubkey>,
+    last_ncn_resolver_ticket: Option<&Pubkey>,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*ncn_resolver_program_config, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new_readonly(*resolver, false),
+        AccountMeta::new(*ncn_resolver_ticket, false),
+        AccountMeta::new(*resolver_admin, true),
+        AccountMeta::new_readonly(*last_resolver.unwrap_or(program_id), false),
+        AccountMeta::new(*last_ncn_resolver_ticket.unwrap_or(program_id), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: ResolverInstruction::RemoveNcnResolverTicket
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn initialize_resolver_ncn_ticket(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    ncn_resolver_program_config: &Pubkey,
+    ncn: &Pubkey,
+    resolver: &Pubkey,
+    resolver_ncn_ticket: &Pubkey,
+    resolver_admin: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*ncn_resolver_program_config, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new_readonly(*resolver, false),
+        AccountMeta::new(*resolver_ncn_ticket, false),
+        AccountMeta::new(*resolver_admin, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: ResolverInstruction::InitializeResolverNcnTicket
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn ncn_resolver_program_config_set_assignment_policy(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    ncn: &Pubkey,
+    ncn_resolver_program_config: &Pubkey,
+    resolver_admin: &Pubkey,
+    default_resolver: Option<&Pubkey>,
+    assignment_policy: ResolverAssignmentPolicy,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new(*ncn_resolver_program_config, false),
+        AccountMeta::new_readonly(*resolver_admin, true),
+        AccountMeta::new_readonly(*default_resolver.unwrap_or(program_id), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: ResolverInstruction::NcnResolverProgramConfigSetAssignmentPolicy(assignment_policy)
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn ncn_resolver_program_config_set_bond(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    ncn: &Pubkey,
+    ncn_resolver_program_config: &Pubkey,
+    resolver_admin: &Pubkey,
+    bond_mint: Option<&Pubkey>,
+    bond_amount: u64,
+    bond_sink: Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new(*ncn_resolver_program_config, false),
+        AccountMeta::new_readonly(*resolver_admin, true),
+        AccountMeta::new_readonly(*bond_mint.unwrap_or(program_id), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: ResolverInstruction::NcnResolverProgramConfigSetBond {
+            bond_amount,
+            bond_sink,
+        }
+        .try_to_vec()
+        .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn ncn_resolver_program_config_set_proceeds(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    ncn: &Pubkey,
+    ncn_resolver_program_config: &Pubkey,
+    resolver_admin: &Pubkey,
+    burn_bps: u16,
+    treasury: Pubkey,
+    treasury_bps: u16,
+    insurance_fund: Pubkey,
+    insurance_fund_bps: u16,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new(*ncn_resolver_program_config, false),
+        AccountMeta::new_readonly(*resolver_admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: ResolverInstruction::NcnResolverProgramConfigSetProceeds {
+            burn_bps,
+            treasury,
+            treasury_bps,
+            insurance_fund,
+            insurance_fund_bps,
+        }
+        .try_to_vec()
+        .unwrap(),
+    }
+}
+
+pub fn resolver_deposit_bond(
+    program_id: &Pubkey,
+    resolver: &Pubkey,
+    resolver_admin: &Pubkey,
+    bond_mint: &Pubkey,
+    resolver_admin_token_account: &Pubkey,
+    resolver_bond_token_account: &Pubkey,
+    token_program: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*resolver, false),
+        AccountMeta::new_readonly(*resolver_admin, true),
+        AccountMeta::new_readonly(*bond_mint, false),
+        AccountMeta::new(*resolver_admin_token_account, false),
+        AccountMeta::new(*resolver_bond_token_account, false),
+        AccountMeta::new_readonly(*token_program, false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: ResolverInstruction::ResolverDepositBond { amount }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+/// `ncn_resolver_tickets` are the (ncn, ncn_resolver_program_config, ncn_resolver_ticket) of the
+/// NCNs the resolver is active for
+pub fn resolver_deregister(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    resolver: &Pubkey,
+    resolver_admin: &Pubkey,
+    ncn_resolver_tickets: &[(Pubkey, Pubkey, Pubkey)],
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*resolver, false),
+        AccountMeta::new_readonly(*resolver_admin, true),
+    ];
+    for (ncn, ncn_resolver_program_config, ncn_resolver_ticket) in ncn_resolver_tickets {
+        accounts.push(AccountMeta::new_readonly(*ncn, false));
+        accounts.push(AccountMeta::new(*ncn_resolver_program_config, false));
+        accounts.push(AccountMeta::new(*ncn_resolver_ticket, false));
+    }
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: ResolverInstruction::ResolverDeregister
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn resolver_withdraw_bond(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    resolver: &Pubkey,
+    resolver_admin: &Pubkey,
+    bond_mint: &Pubkey,
+    resolver_bond_token_account: &Pubkey,
+    resolver_admin_token_account: &Pubkey,
+    token_program: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*resolver, false),
+        AccountMeta::new_readonly(*resolver_admin, true),
+        AccountMeta::new_readonly(*bond_mint, false),
+        AccountMeta::new(*resolver_bond_token_account, false),
+        AccountMeta::new(*resolver_admin_token_account, false),
+        AccountMeta::new_readonly(*token_program, false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: ResolverInstruction::ResolverWithdrawBond
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn require_resolver_decision(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    ncn_resolver_program_config: &Pubkey,
+    ncn: &Pubkey,
+    operator: &Pubkey,
+    slasher: &Pubkey,
+    slash_proposal: &Pubkey,
+    ncn_slash_proposal_ticket: &Pubkey,
+    ncn_resolver_admin: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*ncn_resolver_program_config, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new_readonly(*operator, false),
+        AccountMeta::new_readonly(*slasher, false),
+        AccountMeta::new_readonly(*slash_proposal, false),
+        AccountMeta::new(*ncn_slash_proposal_ticket, false),
+        AccountMeta::new_readonly(*ncn_resolver_admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: ResolverInstruction::RequireResolverDecision
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn penalize_resolver(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    ncn_resolver_program_config: &Pubkey,
+    ncn: &Pubkey,
+    operator: &Pubkey,
+    slasher: &Pubkey,
+    slash_proposal: &Pubkey,
+    ncn_slash_proposal_ticket: &Pubkey,
+    resolver: &Pubkey,
+    resolver_vote: &Pubkey,
+    bond_mint: &Pubkey,
+    resolver_bond_token_account: &Pubkey,
+    penalty_destination: &Pubkey,
+    token_program: &Pubkey,
+    ncn_resolver_admin: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*ncn_resolver_program_config, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new_readonly(*operator, false),
+        AccountMeta::new_readonly(*slasher, false),
+        AccountMeta::new_readonly(*slash_proposal, false),
+        AccountMeta::new(*ncn_slash_proposal_ticket, false),
+        AccountMeta::new(*resolver, false),
+        AccountMeta::new_readonly(*resolver_vote, false),
+        AccountMeta::new_readonly(*bond_mint, false),
+        AccountMeta::new(*resolver_bond_token_account, false),
+        AccountMeta::new(*penalty_destination, false),
+        AccountMeta::new_readonly(*token_program, false),
+        AccountMeta::new_readonly(*ncn_resolver_admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: ResolverInstruction::PenalizeResolver.try_to_vec().unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn amend_slash_amount(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    ncn_resolver_program_config: &Pubkey,
+    ncn: &Pubkey,
+    operator: &Pubkey,
+    slasher: &Pubkey,
+    resolver: &Pubkey,
+    slash_proposal: &Pubkey,
+    ncn_slash_proposal_ticket: &Pubkey,
+    resolver_veto_admin: &Pubkey,
+    ncn_resolver_ticket: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*ncn_resolver_program_config, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new_readonly(*operator, false),
+        AccountMeta::new_readonly(*slasher, false),
+        AccountMeta::new_readonly(*resolver, false),
+        AccountMeta::new(*slash_proposal, false),
+        AccountMeta::new_readonly(*ncn_slash_proposal_ticket, false),
+        AccountMeta::new_readonly(*resolver_veto_admin, true),
+        AccountMeta::new_readonly(*ncn_resolver_ticket, false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: ResolverInstruction::AmendSlashAmount { amount }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn submit_appeal(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    ncn_resolver_program_config: &Pubkey,
+    ncn: &Pubkey,
+    operator: &Pubkey,
+    slasher: &Pubkey,
+    slash_proposal: &Pubkey,
+    slash_proposal_appeal: &Pubkey,
+    operator_admin: &Pubkey,
+    evidence_hash: [u8; 32],
+    metadata_uri: String,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*ncn_resolver_program_config, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new_readonly(*operator, false),
+        AccountMeta::new_readonly(*slasher, false),
+        AccountMeta::new(*slash_proposal, false),
+        AccountMeta::new(*slash_proposal_appeal, false),
+        AccountMeta::new(*operator_admin, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: ResolverInstruction::SubmitAppeal {
+            evidence_hash,
+            metadata_uri,
+        }
+        .try_to_vec()
+        .unwrap(),
+    }
+}